@@ -0,0 +1,84 @@
+use std::fmt;
+
+/// The result of solving one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+    /// The part has not been solved yet.
+    Unsolved,
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+            Answer::Unsolved => write!(f, "unsolved"),
+        }
+    }
+}
+
+macro_rules! impl_from_lossless {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::Number(i64::from(n))
+                }
+            }
+        )*
+    };
+}
+
+// Answers too wide for `i64` are kept as their digits
+macro_rules! impl_from_checked {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    i64::try_from(n).map_or_else(|_| Answer::Text(n.to_string()), Answer::Number)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_lossless!(i16, i32, i64, u8, u16, u32);
+impl_from_checked!(u64, usize, i128, u128);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::from(42_usize).to_string(), "42");
+        assert_eq!(Answer::from(-7_i32).to_string(), "-7");
+        assert_eq!(Answer::from("4,6,3").to_string(), "4,6,3");
+        assert_eq!(Answer::Unsolved.to_string(), "unsolved");
+    }
+
+    #[test]
+    fn test_wide_answers() {
+        assert_eq!(Answer::from(i64::MAX as u64), Answer::Number(i64::MAX));
+        assert_eq!(
+            Answer::from(u64::MAX),
+            Answer::Text("18446744073709551615".to_string())
+        );
+        let wide = i64::MIN as i128 - 1;
+        assert_eq!(Answer::from(wide).to_string(), wide.to_string());
+    }
+}
@@ -0,0 +1,102 @@
+use crate::{Part, Solution};
+use std::fs;
+use std::path::Path;
+
+/// Runs a day against the example fixtures in `dir`, as written by
+/// `aoc examples`, and panics on the first wrong answer.
+///
+/// For each part, `partN.txt` holds the example input and `partN.answer` the
+/// expected answer. A part is skipped when it has no answer file, or when a
+/// `partN.skip` file explains why the example can't be checked (for
+/// instance because it uses a smaller grid than the real puzzle).
+pub fn check_examples<S: Solution>(dir: impl AsRef<Path>) {
+    let dir = dir.as_ref();
+
+    for part in Part::BOTH {
+        let file = |ext: &str| dir.join(format!("part{}.{}", part, ext));
+        if file("skip").exists() {
+            continue;
+        }
+        let Ok(expected) = fs::read_to_string(file("answer")) else {
+            continue;
+        };
+        let input = fs::read_to_string(file("txt"))
+            .unwrap_or_else(|err| panic!("{}: {}", file("txt").display(), err));
+
+        let parsed = S::parse(&input).unwrap_or_else(|err| {
+            panic!("day {} part {} example: {}", S::DAY, part, err.diagnostic())
+        });
+        let answer = match part {
+            Part::One => S::part1(&parsed),
+            Part::Two => S::part2(&parsed),
+        };
+        assert_eq!(
+            answer.to_string(),
+            expected.trim(),
+            "day {} part {} example",
+            S::DAY,
+            part
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Answer, ParseError, Source};
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 0;
+        const INPUT: &'static str = "";
+        type Input = Vec<i64>;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            Source::new(input)?.numbers(input.trim(), ",")
+        }
+
+        fn part1(input: &Self::Input) -> Answer {
+            input.iter().sum::<i64>().into()
+        }
+
+        fn part2(input: &Self::Input) -> Answer {
+            input.iter().product::<i64>().into()
+        }
+    }
+
+    fn fixtures(name: &str, files: &[(&str, &str)]) -> std::path::PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-{}-{}", name, std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        for (file, contents) in files {
+            fs::write(dir.join(file), contents).unwrap();
+        }
+        dir
+    }
+
+    #[test]
+    fn test_matching_and_skipped_parts() {
+        let dir = fixtures(
+            "examples-ok",
+            &[
+                ("part1.txt", "1,2,3\n"),
+                ("part1.answer", "6\n"),
+                ("part2.txt", "1,2,3\n"),
+                ("part2.answer", "7\n"),
+                ("part2.skip", "uses different rules\n"),
+            ],
+        );
+        check_examples::<Sum>(&dir);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "day 0 part 2 example")]
+    fn test_wrong_answer() {
+        let dir = fixtures(
+            "examples-wrong",
+            &[("part2.txt", "2,3\n"), ("part2.answer", "5\n")],
+        );
+        check_examples::<Sum>(&dir);
+    }
+}
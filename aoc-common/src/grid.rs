@@ -0,0 +1,326 @@
+use crate::{Direction, ParseError, ParseErrorKind, Point, Source};
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// Offsets of the eight cells surrounding a point, clockwise from north.
+pub const NEIGHBORS_8: [(isize, isize); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+/// A rectangular grid of tiles stored in row-major order.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from rows, which must all have the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        assert!(
+            rows.iter().all(|row| row.len() == width),
+            "Grid rows must all have the same length"
+        );
+
+        Self {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    /// Parses one row per line, mapping each character to a tile. A
+    /// character `tile` returns `None` for is reported as invalid, as is a
+    /// row of the wrong width.
+    pub fn parse(input: &str, mut tile: impl FnMut(char) -> Option<T>) -> Result<Self, ParseError> {
+        let source = Source::new(input)?;
+        let mut rows: Vec<Vec<T>> = Vec::new();
+
+        for line in source.lines() {
+            let row = line
+                .char_indices()
+                .map(|(i, c)| tile(c).ok_or_else(|| source.invalid_char(line, i)))
+                .collect::<Result<Vec<_>, _>>()?;
+            if let Some(first) = rows.first() {
+                if row.len() != first.len() {
+                    let at = line
+                        .char_indices()
+                        .nth(first.len())
+                        .map_or(line.len(), |(i, _)| i);
+                    return Err(source.error(
+                        &line[at..],
+                        ParseErrorKind::Expected(format!("a row of {} tiles", first.len())),
+                    ));
+                }
+            }
+            rows.push(row);
+        }
+
+        Ok(Self::from_rows(rows))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: Point) -> bool {
+        pos.x < self.width && pos.y < self.height
+    }
+
+    pub fn get(&self, pos: Point) -> Option<&T> {
+        self.contains(pos).then(|| &self.cells[self.index(pos)])
+    }
+
+    pub fn get_mut(&mut self, pos: Point) -> Option<&mut T> {
+        if self.contains(pos) {
+            let index = self.index(pos);
+            Some(&mut self.cells[index])
+        } else {
+            None
+        }
+    }
+
+    /// The neighbouring position in `dir`, if it is inside the grid.
+    pub fn step(&self, pos: Point, dir: Direction) -> Option<Point> {
+        pos.step(dir).filter(|&next| self.contains(next))
+    }
+
+    /// In-bounds orthogonal neighbours, in north, east, south, west order.
+    pub fn neighbors4(&self, pos: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |dir| self.step(pos, dir))
+    }
+
+    /// In-bounds orthogonal and diagonal neighbours, clockwise from north.
+    pub fn neighbors8(&self, pos: Point) -> impl Iterator<Item = Point> + '_ {
+        NEIGHBORS_8
+            .into_iter()
+            .filter_map(move |(dx, dy)| pos.offset(dx, dy))
+            .filter(|&next| self.contains(next))
+    }
+
+    /// Every position in reading order.
+    pub fn positions(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    /// Every position together with its tile, in reading order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// The first position, in reading order, whose tile matches.
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Point> {
+        self.iter()
+            .find(|(_, tile)| predicate(tile))
+            .map(|(pos, _)| pos)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(move |y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> Ray<'_, T> {
+        self.ray(Point::new(x, 0), (0, 1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = Ray<'_, T>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+
+    /// Tiles from `start` onwards in steps of `(dx, dy)` until leaving the
+    /// grid.
+    pub fn ray(&self, start: Point, (dx, dy): (isize, isize)) -> Ray<'_, T> {
+        Ray {
+            grid: self,
+            next: Some(start).filter(|&p| self.contains(p)),
+            delta: (dx, dy),
+        }
+    }
+
+    /// Down-right diagonals, starting from each cell of the top row and then
+    /// from each remaining cell of the left column.
+    pub fn diagonals(&self) -> impl Iterator<Item = Ray<'_, T>> {
+        let top = (0..self.width).map(|x| Point::new(x, 0));
+        let left = (1..self.height).map(|y| Point::new(0, y));
+        top.chain(left).map(move |start| self.ray(start, (1, 1)))
+    }
+
+    /// Down-left diagonals, starting from each cell of the top row and then
+    /// from each remaining cell of the right column.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = Ray<'_, T>> {
+        let right = self.width.saturating_sub(1);
+        let top = (0..self.width).map(|x| Point::new(x, 0));
+        let side = (1..self.height).map(move |y| Point::new(right, y));
+        top.chain(side).map(move |start| self.ray(start, (-1, 1)))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    fn index(&self, pos: Point) -> usize {
+        pos.y * self.width + pos.x
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Point) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", pos))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, pos: Point) -> &mut T {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", pos))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for tile in row {
+                write!(f, "{}", tile)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Iterator over the tiles along a straight line through a [`Grid`].
+#[derive(Debug, Clone)]
+pub struct Ray<'a, T> {
+    grid: &'a Grid<T>,
+    next: Option<Point>,
+    delta: (isize, isize),
+}
+
+impl<'a, T> Iterator for Ray<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        let pos = self.next?;
+        let (dx, dy) = self.delta;
+        self.next = pos.offset(dx, dy).filter(|&p| self.grid.contains(p));
+        Some(&self.grid[pos])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "\
+abc
+def";
+
+    fn grid() -> Grid<char> {
+        Grid::parse(INPUT, Some).unwrap()
+    }
+
+    fn collect<'a>(lines: impl Iterator<Item = Ray<'a, char>>) -> Vec<String> {
+        lines.map(|line| line.collect()).collect()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = grid();
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid[Point::new(2, 1)], 'f');
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.to_string(), INPUT);
+    }
+
+    #[test]
+    #[should_panic(expected = "same length")]
+    fn test_ragged_rows() {
+        Grid::from_rows(vec![vec!['a', 'b'], vec!['c']]);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let short = Grid::parse("ab\nc", Some).unwrap_err();
+        assert_eq!((short.line, short.column), (2, 2));
+
+        let long = Grid::parse("ab\ncde", Some).unwrap_err();
+        assert_eq!((long.line, long.column), (2, 3));
+
+        let digit = |c: char| c.to_digit(10);
+        let invalid = Grid::parse("12\n3x", digit).unwrap_err();
+        assert_eq!(invalid.kind, ParseErrorKind::InvalidChar('x'));
+        assert_eq!((invalid.line, invalid.column), (2, 2));
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = grid();
+        let corner: Vec<_> = grid.neighbors4(Point::new(0, 0)).collect();
+        assert_eq!(corner, vec![Point::new(1, 0), Point::new(0, 1)]);
+        assert_eq!(grid.neighbors4(Point::new(1, 1)).count(), 3);
+        assert_eq!(grid.neighbors8(Point::new(1, 1)).count(), 5);
+    }
+
+    #[test]
+    fn test_lines() {
+        let grid = grid();
+        let rows: Vec<String> = grid.rows().map(|row| row.iter().collect()).collect();
+        assert_eq!(rows, vec!["abc", "def"]);
+        assert_eq!(collect(grid.columns()), vec!["ad", "be", "cf"]);
+        assert_eq!(collect(grid.diagonals()), vec!["ae", "bf", "c", "d"]);
+        assert_eq!(collect(grid.anti_diagonals()), vec!["a", "bd", "ce", "f"]);
+    }
+
+    #[test]
+    fn test_position_and_mutation() {
+        let mut grid = grid();
+        assert_eq!(grid.position(|&c| c == 'e'), Some(Point::new(1, 1)));
+        grid[Point::new(1, 1)] = '#';
+        assert_eq!(grid.position(|&c| c == 'e'), None);
+        assert_eq!(
+            grid.map(|&c| c == '#').iter().filter(|(_, &b)| b).count(),
+            1
+        );
+    }
+}
@@ -0,0 +1,211 @@
+use crate::RenderFormat;
+use std::borrow::Cow;
+use std::convert::Infallible;
+use std::fs;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::PathBuf;
+use std::str::FromStr;
+
+/// Where a day reads its puzzle input from.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum InputSource {
+    /// The input file compiled into the binary.
+    #[default]
+    Bundled,
+    Stdin,
+    File(PathBuf),
+}
+
+impl InputSource {
+    /// Picks the source from `--input <path>`, `--input=<path>` or
+    /// `-i <path>`, where a path of `-` means stdin. Without the flag the
+    /// bundled input is used.
+    pub fn from_args(args: impl IntoIterator<Item = String>) -> io::Result<Self> {
+        Ok(parse_args(args, false)?.input)
+    }
+
+    pub fn read(&self, bundled: &'static str) -> io::Result<Cow<'static, str>> {
+        match self {
+            InputSource::Bundled => Ok(Cow::Borrowed(bundled)),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(Cow::Owned(input))
+            }
+            InputSource::File(path) => fs::read_to_string(path)
+                .map(Cow::Owned)
+                .map_err(|err| io::Error::new(err.kind(), format!("{}: {}", path.display(), err))),
+        }
+    }
+
+    /// Opens the input to be read a piece at a time, for inputs too large to
+    /// read into memory whole.
+    pub fn reader(&self, bundled: &'static str) -> io::Result<Box<dyn BufRead>> {
+        Ok(match self {
+            InputSource::Bundled => Box::new(bundled.as_bytes()),
+            InputSource::Stdin => Box::new(io::stdin().lock()),
+            InputSource::File(path) => {
+                Box::new(BufReader::new(fs::File::open(path).map_err(|err| {
+                    io::Error::new(err.kind(), format!("{}: {}", path.display(), err))
+                })?))
+            }
+        })
+    }
+}
+
+impl FromStr for InputSource {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "-" => InputSource::Stdin,
+            path => InputSource::File(PathBuf::from(path)),
+        })
+    }
+}
+
+/// Command line options of a day binary that can draw or trace its puzzle.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Args {
+    pub input: InputSource,
+    /// Draw the puzzle in this format instead of printing the answers.
+    pub render: Option<RenderFormat>,
+    /// Write each step of the simulation as JSON Lines to this path, or
+    /// stdout for `-`, instead of printing the answers.
+    pub trace: Option<PathBuf>,
+}
+
+impl Args {
+    /// Parses `--input` as in [`InputSource::from_args`], `--render
+    /// <format>` and `--trace <path>`.
+    pub fn parse(args: impl IntoIterator<Item = String>) -> io::Result<Self> {
+        parse_args(args, true)
+    }
+
+    pub fn from_env() -> io::Result<Self> {
+        Self::parse(std::env::args().skip(1))
+    }
+}
+
+fn parse_args(args: impl IntoIterator<Item = String>, day_options: bool) -> io::Result<Args> {
+    let mut parsed = Args::default();
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        let (flag, inline) = match arg.split_once('=') {
+            Some((flag, value)) if flag.starts_with("--") => (flag, Some(value.to_string())),
+            _ => (arg.as_str(), None),
+        };
+        let value = match flag {
+            "--input" | "-i" => "a path, or - for stdin",
+            "--render" if day_options => "a format: ansi, text, ppm or png",
+            "--trace" if day_options => "a path, or - for stdout",
+            _ => return Err(usage_error(format!("Unexpected argument: {}", arg))),
+        };
+        let value = inline
+            .or_else(|| args.next())
+            .ok_or_else(|| usage_error(format!("{} needs {}", flag, value)))?;
+
+        match flag {
+            "--render" => parsed.render = Some(value.parse().map_err(usage_error)?),
+            "--trace" => parsed.trace = Some(PathBuf::from(value)),
+            _ => {
+                let Ok(source) = value.parse();
+                parsed.input = source;
+            }
+        }
+    }
+
+    Ok(parsed)
+}
+
+/// Reads the input chosen on this process's command line, falling back to
+/// `bundled`.
+pub fn read_input(bundled: &'static str) -> io::Result<Cow<'static, str>> {
+    InputSource::from_args(std::env::args().skip(1))?.read(bundled)
+}
+
+fn usage_error(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn source(args: &[&str]) -> io::Result<InputSource> {
+        InputSource::from_args(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn test_from_args() {
+        assert_eq!(source(&[]).unwrap(), InputSource::Bundled);
+        assert_eq!(source(&["--input", "-"]).unwrap(), InputSource::Stdin);
+        assert_eq!(
+            source(&["-i", "mine.txt"]).unwrap(),
+            InputSource::File(PathBuf::from("mine.txt"))
+        );
+        assert_eq!(
+            source(&["--input=mine.txt"]).unwrap(),
+            InputSource::File(PathBuf::from("mine.txt"))
+        );
+    }
+
+    #[test]
+    fn test_bad_args() {
+        assert!(source(&["--input"]).is_err());
+        assert!(source(&["mine.txt"]).is_err());
+    }
+
+    #[test]
+    fn test_render_args() {
+        let args = |args: &[&str]| Args::parse(args.iter().map(|arg| arg.to_string()));
+        assert_eq!(
+            args(&["--render", "png", "-i", "-"]).unwrap(),
+            Args {
+                input: InputSource::Stdin,
+                render: Some(RenderFormat::Png),
+                trace: None,
+            }
+        );
+        assert_eq!(
+            args(&["--trace", "steps.jsonl"]).unwrap().trace,
+            Some(PathBuf::from("steps.jsonl"))
+        );
+        assert_eq!(
+            args(&["--render=ansi"]).unwrap().render,
+            Some(RenderFormat::Ansi)
+        );
+        assert!(args(&["--render", "gif"]).is_err());
+        assert!(args(&["--render"]).is_err());
+        // Days that can't draw their puzzle don't accept the flag
+        assert!(source(&["--render", "png"]).is_err());
+        assert!(source(&["--trace", "-"]).is_err());
+    }
+
+    #[test]
+    fn test_read() {
+        assert_eq!(InputSource::Bundled.read("1 2").unwrap(), "1 2");
+
+        let path = std::env::temp_dir().join(format!("aoc-input-{}.txt", std::process::id()));
+        fs::write(&path, "3 4").unwrap();
+        let read = InputSource::File(path.clone()).read("1 2");
+        fs::remove_file(&path).unwrap();
+        assert_eq!(read.unwrap(), "3 4");
+
+        let missing = InputSource::File(path.clone()).read("1 2").unwrap_err();
+        assert!(missing.to_string().contains("aoc-input-"));
+        let Err(missing) = InputSource::File(path).reader("1 2") else {
+            panic!("opened a missing file");
+        };
+        assert!(missing.to_string().contains("aoc-input-"));
+
+        let mut line = String::new();
+        InputSource::Bundled
+            .reader("1 2\n3 4\n")
+            .unwrap()
+            .read_line(&mut line)
+            .unwrap();
+        assert_eq!(line, "1 2\n");
+    }
+}
@@ -0,0 +1,45 @@
+//! Shared building blocks for the Advent of Code day crates.
+//!
+//! Every day implements [`Solution`], which lets the `aoc` runner drive it
+//! through the object-safe [`DynSolution`] wrapper. Grid puzzles build on
+//! [`Grid`], [`Point`] and [`Direction`], and binaries pick their input with
+//! [`read_input`]. Parsers report bad input as a [`ParseError`] that points
+//! at the offending line and column, and a [`Lint`] collects every problem
+//! with an input rather than the first. Days that simulate something draw
+//! it as a [`Frame`], chosen with `--render` through [`Args`], and can record
+//! each step to a [`Tracer`] with `--trace`. Path finding puzzles describe
+//! their moves to the searches in [`search`], and random inputs for stress
+//! tests come from a seeded [`Rng`]. Days that split their work across
+//! threads do it through [`par`], which the `parallel` feature backs with
+//! rayon. The `testing` feature adds the property test and fuzzing helpers
+//! in `testing`.
+
+mod answer;
+mod direction;
+mod examples;
+mod grid;
+mod input;
+mod lint;
+pub mod par;
+mod parse;
+mod point;
+mod render;
+mod rng;
+pub mod search;
+mod solution;
+#[cfg(feature = "testing")]
+pub mod testing;
+mod trace;
+
+pub use answer::Answer;
+pub use direction::Direction;
+pub use examples::check_examples;
+pub use grid::{Grid, Ray, NEIGHBORS_8};
+pub use input::{read_input, Args, InputSource};
+pub use lint::Lint;
+pub use parse::{ParseError, ParseErrorKind, Source};
+pub use point::Point;
+pub use render::{Cell, Color, Frame, RenderFormat};
+pub use rng::Rng;
+pub use solution::{DynSolution, Part, PartRun, Run, Solution};
+pub use trace::{JsonLines, NoTrace, Tracer};
@@ -0,0 +1,164 @@
+//! Checking the shape of an input without solving it.
+//!
+//! A parser stops at the first problem. A day's [`Solution::lint`] runs its
+//! checks through a [`Lint`] instead, which keeps going and collects every
+//! problem, so a hand-edited or truncated input can be fixed in one pass.
+//!
+//! [`Solution::lint`]: crate::Solution::lint
+
+use crate::{ParseError, ParseErrorKind, Source};
+
+/// Collects the problems with one input.
+#[derive(Debug)]
+pub struct Lint<'a> {
+    source: Source<'a>,
+    problems: Vec<ParseError>,
+}
+
+impl<'a> Lint<'a> {
+    /// Runs `checks` on `input` and returns every problem found, in the
+    /// order they appear in the input. An empty input is the only problem
+    /// reported for it.
+    pub fn run(input: &'a str, checks: impl FnOnce(&mut Lint<'a>)) -> Vec<ParseError> {
+        let source = match Source::new(input) {
+            Ok(source) => source,
+            Err(err) => return vec![err],
+        };
+        let mut lint = Lint {
+            source,
+            problems: Vec::new(),
+        };
+        checks(&mut lint);
+
+        let mut problems = lint.problems;
+        problems.sort_by_key(|err| (err.line, err.column));
+        problems.dedup();
+        problems
+    }
+
+    pub fn source(&self) -> Source<'a> {
+        self.source
+    }
+
+    pub fn lines(&self) -> std::str::Lines<'a> {
+        self.source.lines()
+    }
+
+    pub fn report(&mut self, err: ParseError) {
+        self.problems.push(err);
+    }
+
+    /// The value, or `None` after reporting the error.
+    pub fn check<T>(&mut self, result: Result<T, ParseError>) -> Option<T> {
+        result.map_err(|err| self.report(err)).ok()
+    }
+
+    /// Parses each item on its own, reporting every item that fails rather
+    /// than the first.
+    pub fn each<T>(
+        &mut self,
+        items: impl IntoIterator<Item = &'a str>,
+        mut parse: impl FnMut(&'a str) -> Result<T, ParseError>,
+    ) -> Vec<T> {
+        items
+            .into_iter()
+            .filter_map(|item| self.check(parse(item)))
+            .collect()
+    }
+
+    /// Checks that `rows` form a rectangle of tiles `tile` accepts, as
+    /// [`Grid::parse`](crate::Grid::parse) requires. Every character it
+    /// doesn't accept and every row that is the wrong length is reported.
+    pub fn grid(&mut self, rows: impl IntoIterator<Item = &'a str>, tile: impl Fn(char) -> bool) {
+        let mut width = None;
+        for row in rows {
+            for (i, c) in row.char_indices() {
+                if !tile(c) {
+                    self.report(self.source.invalid_char(row, i));
+                }
+            }
+
+            let length = row.chars().count();
+            let &mut width = width.get_or_insert(length);
+            if length != width {
+                let at = row.char_indices().nth(width).map_or(row.len(), |(i, _)| i);
+                self.report(self.source.error(
+                    &row[at..],
+                    ParseErrorKind::Expected(format!("a row of {} tiles", width)),
+                ));
+            }
+        }
+    }
+
+    /// Checks that `c` appears exactly once in `rows`, reporting it as
+    /// missing at the end of the input, or every time it appears again.
+    pub fn exactly_one(&mut self, rows: impl IntoIterator<Item = &'a str>, c: char, what: &str) {
+        let mut found = false;
+        for row in rows {
+            for (i, _) in row.match_indices(c) {
+                if found {
+                    self.report(self.source.error(
+                        &row[i..],
+                        ParseErrorKind::Expected(format!("only one {}", what)),
+                    ));
+                }
+                found = true;
+            }
+        }
+        if !found {
+            let text = self.source.text();
+            self.report(self.source.expected(text.trim_end(), format!("a {}", what)));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lint_map(input: &str) -> Vec<(usize, usize, ParseErrorKind)> {
+        Lint::run(input, |lint| {
+            lint.grid(lint.lines(), |c| matches!(c, '.' | '#' | '^'));
+            lint.exactly_one(lint.lines(), '^', "guard '^'");
+        })
+        .into_iter()
+        .map(|err| (err.line, err.column, err.kind))
+        .collect()
+    }
+
+    #[test]
+    fn test_every_problem_in_order() {
+        let problems = lint_map("..#.\n.x^\n#.^.\n..?.\n");
+        assert_eq!(
+            problems,
+            [
+                (2, 2, ParseErrorKind::InvalidChar('x')),
+                (2, 4, ParseErrorKind::Expected("a row of 4 tiles".into())),
+                (3, 3, ParseErrorKind::Expected("only one guard '^'".into())),
+                (4, 3, ParseErrorKind::InvalidChar('?')),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_missing_and_empty() {
+        assert_eq!(
+            lint_map("..\n..\n"),
+            [(2, 3, ParseErrorKind::Expected("a guard '^'".into()))]
+        );
+        assert_eq!(lint_map("\n"), [(1, 1, ParseErrorKind::EmptyInput)]);
+        assert!(lint_map(".^\n..\n").is_empty());
+    }
+
+    #[test]
+    fn test_each() {
+        let mut numbers = Vec::new();
+        let problems = Lint::run("1\nx\n3\ny\n", |lint| {
+            let source = lint.source();
+            numbers = lint.each(lint.lines(), |line| source.number::<i32>(line));
+        });
+        assert_eq!(numbers, [1, 3]);
+        let lines: Vec<usize> = problems.iter().map(|err| err.line).collect();
+        assert_eq!(lines, [2, 4]);
+    }
+}
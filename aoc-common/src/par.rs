@@ -0,0 +1,112 @@
+//! Data parallelism that can be compiled out.
+//!
+//! Days import [`prelude`] instead of rayon's and call `par_iter()` as
+//! usual. With the `parallel` feature, on by default, that is rayon. Without
+//! it, rayon isn't built at all and `par_iter()` is an ordinary iterator, so
+//! every chain a day writes must give the same answer either way: sums,
+//! counts and collects that keep their order, never a result that depends
+//! on which thread got there first.
+//!
+//! ```text
+//! cargo build -p aoc --no-default-features
+//! ```
+
+#[cfg(feature = "parallel")]
+pub mod prelude {
+    pub use rayon::prelude::{IntoParallelRefIterator, ParallelIterator};
+}
+
+#[cfg(not(feature = "parallel"))]
+pub mod prelude {
+    /// The sequential stand-in for rayon's trait of the same name.
+    pub trait IntoParallelRefIterator<'data> {
+        type Iter: Iterator;
+
+        fn par_iter(&'data self) -> Self::Iter;
+    }
+
+    impl<'data, T: 'data> IntoParallelRefIterator<'data> for [T] {
+        type Iter = std::slice::Iter<'data, T>;
+
+        fn par_iter(&'data self) -> Self::Iter {
+            self.iter()
+        }
+    }
+}
+
+/// Whether this build runs anything in parallel.
+pub const ENABLED: bool = cfg!(feature = "parallel");
+
+/// Sizes the pool every `par_iter()` runs on. Only the first call has an
+/// effect, and it has to come before anything has run in parallel. Without
+/// the `parallel` feature there is nothing to size.
+pub fn set_threads(threads: usize) -> Result<(), String> {
+    #[cfg(feature = "parallel")]
+    {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build_global()
+            .map_err(|err| err.to_string())
+    }
+    #[cfg(not(feature = "parallel"))]
+    {
+        let _ = threads;
+        Ok(())
+    }
+}
+
+/// The number of threads `par_iter()` runs on, which is 1 without the
+/// `parallel` feature.
+pub fn threads() -> usize {
+    #[cfg(feature = "parallel")]
+    {
+        rayon::current_num_threads()
+    }
+    #[cfg(not(feature = "parallel"))]
+    {
+        1
+    }
+}
+
+/// Runs `f` with its `par_iter()` calls on a pool of its own with `threads`
+/// threads, for tests that compare thread counts.
+pub fn with_threads<T: Send>(threads: usize, f: impl FnOnce() -> T + Send) -> T {
+    #[cfg(feature = "parallel")]
+    {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .expect("thread pool")
+            .install(f)
+    }
+    #[cfg(not(feature = "parallel"))]
+    {
+        let _ = threads;
+        f()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::prelude::*;
+    use super::*;
+
+    #[test]
+    fn test_same_results_on_any_pool() {
+        let numbers: Vec<u64> = (1..=1000).collect();
+        let run = || {
+            let sum: u64 = numbers.par_iter().map(|n| n * n).sum();
+            let odd: Vec<u64> = numbers
+                .par_iter()
+                .filter_map(|&n| (n % 2 == 1).then_some(n))
+                .collect();
+            (sum, odd)
+        };
+        let expected = run();
+        assert_eq!(expected.1.len(), 500);
+        assert!(expected.1.windows(2).all(|pair| pair[0] < pair[1]));
+        for threads in [1, 2, 7] {
+            assert_eq!(with_threads(threads, run), expected);
+        }
+    }
+}
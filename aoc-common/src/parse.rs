@@ -0,0 +1,247 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// Characters of context kept either side of the error in a snippet.
+const CONTEXT: usize = 30;
+
+/// What went wrong while parsing a puzzle input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    EmptyInput,
+    InvalidChar(char),
+    InvalidNumber(String),
+    /// Something required is missing, described as in "expected ...".
+    Expected(String),
+}
+
+impl fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseErrorKind::EmptyInput => write!(f, "Input is empty"),
+            ParseErrorKind::InvalidChar(c) => write!(f, "Invalid character {:?}", c),
+            ParseErrorKind::InvalidNumber(s) => write!(f, "Invalid number {:?}", s),
+            ParseErrorKind::Expected(what) => write!(f, "Expected {}", what),
+        }
+    }
+}
+
+/// A parse failure, located by 1-based line and column (in characters) with
+/// the text around it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub kind: ParseErrorKind,
+    pub line: usize,
+    pub column: usize,
+    /// The offending line, cut down to the text around the column.
+    pub snippet: String,
+    // Character offset of the column within the snippet
+    caret: usize,
+}
+
+impl ParseError {
+    /// An error at the start of `at`, which should be a slice of `input`.
+    /// Anything else is reported at the end of the input.
+    pub fn at(input: &str, at: &str, kind: ParseErrorKind) -> Self {
+        let offset = (at.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|&offset| offset <= input.len())
+            .unwrap_or(input.len());
+
+        let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |i| offset + i);
+        let before: Vec<char> = input[line_start..offset].chars().collect();
+        let after = &input[offset..line_end];
+
+        let skipped = before.len().saturating_sub(CONTEXT);
+        let mut snippet: String = before[skipped..].iter().collect();
+        snippet.extend(after.chars().take(CONTEXT));
+
+        ParseError {
+            kind,
+            line: input[..line_start].matches('\n').count() + 1,
+            column: before.len() + 1,
+            snippet: snippet.trim_end_matches('\r').to_string(),
+            caret: before.len() - skipped,
+        }
+    }
+
+    pub fn empty() -> Self {
+        Self::at("", "", ParseErrorKind::EmptyInput)
+    }
+
+    /// The error followed by the snippet with a caret under the column, for
+    /// printing to a terminal.
+    pub fn diagnostic(&self) -> String {
+        format!(
+            "{}\n  {} | {}\n  {} | {}^",
+            self,
+            self.line,
+            self.snippet,
+            " ".repeat(self.line.to_string().len()),
+            " ".repeat(self.caret)
+        )
+    }
+
+    /// Prints the [`diagnostic`](Self::diagnostic) to stderr and exits with
+    /// a failure status, for a day's binary given an input it can't parse.
+    pub fn exit(&self) -> ! {
+        eprintln!("{}", self.diagnostic());
+        std::process::exit(1)
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} at line {}, column {}",
+            self.kind, self.line, self.column
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// The input being parsed, so errors about any slice of it can say where
+/// they happened.
+#[derive(Debug, Clone, Copy)]
+pub struct Source<'a> {
+    text: &'a str,
+}
+
+impl<'a> Source<'a> {
+    /// Wraps an input, rejecting one that is only whitespace. Blank lines at
+    /// the end are dropped, so every day treats a trailing blank line alike.
+    pub fn new(text: &'a str) -> Result<Self, ParseError> {
+        let content = text.trim_end().len();
+        if content == 0 {
+            return Err(ParseError::empty());
+        }
+        let end = text[content..]
+            .find('\n')
+            .map_or(text.len(), |i| content + i + 1);
+        Ok(Source { text: &text[..end] })
+    }
+
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
+
+    pub fn lines(&self) -> std::str::Lines<'a> {
+        self.text.lines()
+    }
+
+    pub fn error(&self, at: &str, kind: ParseErrorKind) -> ParseError {
+        ParseError::at(self.text, at, kind)
+    }
+
+    /// An [`ParseErrorKind::Expected`] error at the end of `after`.
+    pub fn expected(&self, after: &str, what: impl Into<String>) -> ParseError {
+        self.error(&after[after.len()..], ParseErrorKind::Expected(what.into()))
+    }
+
+    /// Parses a number, ignoring surrounding whitespace.
+    pub fn number<T: FromStr>(&self, field: &str) -> Result<T, ParseError> {
+        let trimmed = field.trim();
+        if trimmed.is_empty() {
+            return Err(self.expected(field, "a number"));
+        }
+        trimmed
+            .parse()
+            .map_err(|_| self.error(trimmed, ParseErrorKind::InvalidNumber(trimmed.to_string())))
+    }
+
+    /// Parses a list of numbers separated by `separator`.
+    pub fn numbers<T: FromStr>(&self, line: &str, separator: &str) -> Result<Vec<T>, ParseError> {
+        line.split(separator)
+            .map(|field| self.number(field))
+            .collect()
+    }
+
+    pub fn split_once(
+        &self,
+        s: &'a str,
+        delimiter: &str,
+    ) -> Result<(&'a str, &'a str), ParseError> {
+        s.split_once(delimiter)
+            .ok_or_else(|| self.expected(s, format!("{:?}", delimiter)))
+    }
+
+    pub fn strip_prefix(&self, s: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+        s.strip_prefix(prefix)
+            .ok_or_else(|| self.error(s, ParseErrorKind::Expected(format!("{:?}", prefix))))
+    }
+
+    /// An [`ParseErrorKind::InvalidChar`] error for the character at byte
+    /// `index` of `s`.
+    pub fn invalid_char(&self, s: &str, index: usize) -> ParseError {
+        let c = s[index..].chars().next().unwrap_or('\n');
+        self.error(&s[index..], ParseErrorKind::InvalidChar(c))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "3   4\n4   x\n";
+
+    #[test]
+    fn test_position() {
+        let source = Source::new(INPUT).unwrap();
+        let line = source.lines().nth(1).unwrap();
+        let err = source.number::<i32>(&line[4..]).unwrap_err();
+        assert_eq!(err.kind, ParseErrorKind::InvalidNumber("x".into()));
+        assert_eq!((err.line, err.column), (2, 5));
+        assert_eq!(err.snippet, "4   x");
+        assert_eq!(err.to_string(), "Invalid number \"x\" at line 2, column 5");
+        assert_eq!(
+            err.diagnostic(),
+            "Invalid number \"x\" at line 2, column 5\n  2 | 4   x\n    |     ^"
+        );
+    }
+
+    #[test]
+    fn test_expected() {
+        let source = Source::new("47|53\n97 13\n").unwrap();
+        let line = source.lines().nth(1).unwrap();
+        let err = source.split_once(line, "|").unwrap_err();
+        assert_eq!(err.kind, ParseErrorKind::Expected("\"|\"".into()));
+        assert_eq!((err.line, err.column), (2, 6));
+    }
+
+    #[test]
+    fn test_long_line_is_cut() {
+        let input = format!("{}?{}", "1".repeat(100), "2".repeat(100));
+        let source = Source::new(&input).unwrap();
+        let err = source.invalid_char(&input, 100);
+        assert_eq!(err.kind, ParseErrorKind::InvalidChar('?'));
+        assert_eq!(err.column, 101);
+        assert_eq!(err.snippet.len(), 2 * CONTEXT);
+        assert_eq!(err.snippet.chars().nth(err.caret), Some('?'));
+    }
+
+    #[test]
+    fn test_foreign_slice_is_at_end() {
+        let err = ParseError::at("ab\ncd", "elsewhere", ParseErrorKind::EmptyInput);
+        assert_eq!((err.line, err.column), (2, 3));
+    }
+
+    #[test]
+    fn test_trailing_blank_lines() {
+        let source = Source::new("1 2  \r\n3\r\n\r\n \n").unwrap();
+        assert_eq!(source.text(), "1 2  \r\n3\r\n");
+        assert_eq!(source.lines().collect::<Vec<_>>(), ["1 2  ", "3"]);
+        assert_eq!(Source::new("1\n\n2").unwrap().text(), "1\n\n2");
+    }
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(
+            Source::new(" \n").unwrap_err().kind,
+            ParseErrorKind::EmptyInput
+        );
+    }
+}
@@ -0,0 +1,143 @@
+use crate::Direction;
+use serde::Serialize;
+use std::cmp::Ordering;
+use std::ops::{Add, Sub};
+
+/// A 2D coordinate. Grids index with the default `Point<usize>`; use a
+/// signed coordinate type for positions that may leave the grid.
+///
+/// Points order in reading order: by row, then by column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize)]
+pub struct Point<T = usize> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T: Ord> Ord for Point<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.y.cmp(&other.y).then_with(|| self.x.cmp(&other.x))
+    }
+}
+
+impl<T: Ord> PartialOrd for Point<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Point<usize> {
+    /// Moves by `(dx, dy)`, or `None` if either coordinate would go negative.
+    pub fn offset(self, dx: isize, dy: isize) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_add_signed(dx)?,
+            self.y.checked_add_signed(dy)?,
+        ))
+    }
+
+    pub fn step(self, dir: Direction) -> Option<Self> {
+        let (dx, dy) = dir.delta();
+        self.offset(dx, dy)
+    }
+
+    pub fn manhattan(self, other: Self) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    pub fn to_signed(self) -> Point<isize> {
+        Point::new(self.x as isize, self.y as isize)
+    }
+}
+
+macro_rules! impl_signed_point {
+    ($($t:ty),*) => {
+        $(
+            impl Point<$t> {
+                pub fn step(self, dir: Direction) -> Self {
+                    let (dx, dy) = dir.delta();
+                    Self::new(self.x + dx as $t, self.y + dy as $t)
+                }
+
+                pub fn manhattan(self, other: Self) -> $t {
+                    (self.x - other.x).abs() + (self.y - other.y).abs()
+                }
+
+                /// Converts to a grid index, or `None` if either coordinate
+                /// is negative.
+                pub fn to_unsigned(self) -> Option<Point<usize>> {
+                    Some(Point::new(
+                        usize::try_from(self.x).ok()?,
+                        usize::try_from(self.y).ok()?,
+                    ))
+                }
+            }
+        )*
+    };
+}
+
+impl_signed_point!(i32, i64, isize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unsigned_step() {
+        let p: Point = Point::new(0, 1);
+        assert_eq!(p.step(Direction::North), Some(Point::new(0, 0)));
+        assert_eq!(p.step(Direction::West), None);
+        assert_eq!(p.offset(2, 3), Some(Point::new(2, 4)));
+    }
+
+    #[test]
+    fn test_signed_step() {
+        let p: Point<i32> = Point::new(0, 0);
+        assert_eq!(p.step(Direction::West), Point::new(-1, 0));
+        assert_eq!(p.step(Direction::West).to_unsigned(), None);
+        assert_eq!(
+            Point::<isize>::new(3, 4).to_unsigned(),
+            Some(Point::new(3, 4))
+        );
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let a: Point<i64> = Point::new(1, 2);
+        let b = Point::new(4, -2);
+        assert_eq!(a + b, Point::new(5, 0));
+        assert_eq!(b - a, Point::new(3, -4));
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(Point::new(1_usize, 5).manhattan(Point::new(4, 2)), 6);
+    }
+
+    #[test]
+    fn test_reading_order() {
+        let mut points = vec![Point::new(2, 0), Point::new(0, 1), Point::new(1, 0)];
+        points.sort();
+        assert_eq!(
+            points,
+            vec![Point::new(1, 0), Point::new(2, 0), Point::new(0, 1)]
+        );
+    }
+}
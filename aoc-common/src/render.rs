@@ -0,0 +1,305 @@
+//! Drawing grid puzzles as terminal frames or images.
+//!
+//! A [`Frame`] is a grid of coloured glyphs, built from a puzzle's grid and
+//! then overlaid with whatever the day wants to show: a path, a region, a
+//! set of robots. The same frame can be written as plain text, as text with
+//! ANSI colours, or as a PPM or PNG image where each tile is a square of
+//! its colour.
+
+use crate::{Grid, Point};
+use std::fmt;
+use std::io::{self, Write};
+use std::str::FromStr;
+
+/// Pixels per tile side in images.
+const IMAGE_SCALE: usize = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Color(pub u8, pub u8, pub u8);
+
+impl Color {
+    pub const BLACK: Color = Color(0, 0, 0);
+    pub const DARK_GREY: Color = Color(60, 60, 60);
+    pub const GREY: Color = Color(140, 140, 140);
+    pub const WHITE: Color = Color(255, 255, 255);
+    pub const RED: Color = Color(220, 50, 47);
+    pub const GREEN: Color = Color(60, 200, 80);
+    pub const YELLOW: Color = Color(240, 200, 40);
+    pub const BLUE: Color = Color(60, 120, 230);
+    pub const BROWN: Color = Color(170, 110, 50);
+
+    /// The `index`th of a sequence of colours spread around the colour
+    /// wheel, so neighbouring indices look different.
+    pub fn distinct(index: usize) -> Color {
+        // Golden angle steps never line up with earlier hues
+        let hue = (index as f64 * 137.508) % 360.0;
+        Color::from_hsv(hue, 0.65, 0.95)
+    }
+
+    /// Scales the colour's brightness by `factor`, 0 to 1.
+    pub fn dim(self, factor: f64) -> Color {
+        let scale = |c: u8| (c as f64 * factor.clamp(0.0, 1.0)).round() as u8;
+        Color(scale(self.0), scale(self.1), scale(self.2))
+    }
+
+    fn from_hsv(hue: f64, saturation: f64, value: f64) -> Color {
+        let chroma = value * saturation;
+        let x = chroma * (1.0 - ((hue / 60.0) % 2.0 - 1.0).abs());
+        let (r, g, b) = match hue as u32 / 60 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+        let channel = |c: f64| ((c + value - chroma) * 255.0).round() as u8;
+        Color(channel(r), channel(g), channel(b))
+    }
+}
+
+/// One tile of a frame: the glyph used in text output, and its colour.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cell {
+    pub glyph: char,
+    pub color: Color,
+}
+
+impl Cell {
+    pub const fn new(glyph: char, color: Color) -> Self {
+        Cell { glyph, color }
+    }
+}
+
+/// How to write a [`Frame`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenderFormat {
+    /// Text coloured with ANSI escapes, for a terminal.
+    Ansi,
+    Text,
+    Ppm,
+    Png,
+}
+
+impl FromStr for RenderFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ansi" => Ok(RenderFormat::Ansi),
+            "text" => Ok(RenderFormat::Text),
+            "ppm" => Ok(RenderFormat::Ppm),
+            "png" => Ok(RenderFormat::Png),
+            _ => Err(format!(
+                "Unknown render format {:?} (expected ansi, text, ppm or png)",
+                s
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    cells: Grid<Cell>,
+}
+
+impl Frame {
+    pub fn new(width: usize, height: usize, background: Cell) -> Self {
+        Frame {
+            cells: Grid::new(width, height, background),
+        }
+    }
+
+    /// A frame the size of `grid`, drawing each tile with `cell`.
+    pub fn from_grid<T>(grid: &Grid<T>, mut cell: impl FnMut(Point, &T) -> Cell) -> Self {
+        let mut frame = Frame::new(grid.width(), grid.height(), Cell::new(' ', Color::BLACK));
+        for (pos, tile) in grid.iter() {
+            frame.cells[pos] = cell(pos, tile);
+        }
+        frame
+    }
+
+    pub fn width(&self) -> usize {
+        self.cells.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.cells.height()
+    }
+
+    pub fn get(&self, pos: Point) -> Option<&Cell> {
+        self.cells.get(pos)
+    }
+
+    /// Draws `cell` at `pos`, ignoring positions outside the frame.
+    pub fn set(&mut self, pos: Point, cell: Cell) -> &mut Self {
+        if let Some(tile) = self.cells.get_mut(pos) {
+            *tile = cell;
+        }
+        self
+    }
+
+    /// Draws `cell` at every position.
+    pub fn overlay(&mut self, positions: impl IntoIterator<Item = Point>, cell: Cell) -> &mut Self {
+        for pos in positions {
+            self.set(pos, cell);
+        }
+        self
+    }
+
+    /// Changes the colour at every position, keeping the glyphs.
+    pub fn tint(&mut self, positions: impl IntoIterator<Item = Point>, color: Color) -> &mut Self {
+        for pos in positions {
+            if let Some(tile) = self.cells.get_mut(pos) {
+                tile.color = color;
+            }
+        }
+        self
+    }
+
+    pub fn to_text(&self) -> String {
+        self.cells
+            .rows()
+            .map(|row| row.iter().map(|cell| cell.glyph).collect::<String>() + "\n")
+            .collect()
+    }
+
+    /// Text with a 24-bit colour escape wherever the colour changes, reset
+    /// at the end of each line.
+    pub fn to_ansi(&self) -> String {
+        let mut out = String::new();
+        for row in self.cells.rows() {
+            let mut current = None;
+            for cell in row {
+                if current != Some(cell.color) {
+                    let Color(r, g, b) = cell.color;
+                    out.push_str(&format!("\x1b[38;2;{};{};{}m", r, g, b));
+                    current = Some(cell.color);
+                }
+                out.push(cell.glyph);
+            }
+            out.push_str("\x1b[0m\n");
+        }
+        out
+    }
+
+    /// A binary PPM (P6) image.
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let (width, height, pixels) = self.pixels();
+        let mut out = format!("P6\n{} {}\n255\n", width, height).into_bytes();
+        out.extend(pixels);
+        out
+    }
+
+    pub fn to_png(&self) -> Vec<u8> {
+        let (width, height, pixels) = self.pixels();
+        let mut out = Vec::new();
+        let mut encoder = png::Encoder::new(&mut out, width as u32, height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        // Writing to a Vec can only fail on a size mismatch, which pixels()
+        // rules out
+        let mut writer = encoder.write_header().expect("PNG header");
+        writer.write_image_data(&pixels).expect("PNG data");
+        writer.finish().expect("PNG end");
+        out
+    }
+
+    pub fn write(&self, format: RenderFormat, out: &mut impl Write) -> io::Result<()> {
+        match format {
+            RenderFormat::Ansi => out.write_all(self.to_ansi().as_bytes()),
+            RenderFormat::Text => out.write_all(self.to_text().as_bytes()),
+            RenderFormat::Ppm => out.write_all(&self.to_ppm()),
+            RenderFormat::Png => out.write_all(&self.to_png()),
+        }
+    }
+
+    /// Image size and RGB bytes, with each tile a square of its colour.
+    fn pixels(&self) -> (usize, usize, Vec<u8>) {
+        let (width, height) = (self.width() * IMAGE_SCALE, self.height() * IMAGE_SCALE);
+        let mut pixels = Vec::with_capacity(width * height * 3);
+        for row in self.cells.rows() {
+            let line: Vec<u8> = row
+                .iter()
+                .flat_map(|cell| {
+                    let Color(r, g, b) = cell.color;
+                    [r, g, b].repeat(IMAGE_SCALE)
+                })
+                .collect();
+            for _ in 0..IMAGE_SCALE {
+                pixels.extend_from_slice(&line);
+            }
+        }
+        (width, height, pixels)
+    }
+}
+
+impl fmt::Display for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_text())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame() -> Frame {
+        let grid = Grid::from_rows(vec![vec!['#', '.'], vec!['.', '#']]);
+        let mut frame = Frame::from_grid(&grid, |_, &c| match c {
+            '#' => Cell::new('#', Color::GREY),
+            _ => Cell::new('.', Color::BLACK),
+        });
+        frame.overlay(
+            [Point { x: 1, y: 0 }, Point { x: 5, y: 5 }],
+            Cell::new('@', Color::RED),
+        );
+        frame
+    }
+
+    #[test]
+    fn test_text() {
+        assert_eq!(frame().to_text(), "#@\n.#\n");
+    }
+
+    #[test]
+    fn test_ansi() {
+        assert_eq!(
+            frame().to_ansi(),
+            "\x1b[38;2;140;140;140m#\x1b[38;2;220;50;47m@\x1b[0m\n\
+             \x1b[38;2;0;0;0m.\x1b[38;2;140;140;140m#\x1b[0m\n"
+        );
+    }
+
+    #[test]
+    fn test_images() {
+        let ppm = frame().to_ppm();
+        let header = b"P6\n8 8\n255\n";
+        assert_eq!(&ppm[..header.len()], header);
+        assert_eq!(ppm.len(), header.len() + 8 * 8 * 3);
+        // The second tile of the first row is red
+        let pixel = header.len() + IMAGE_SCALE * 3;
+        assert_eq!(&ppm[pixel..pixel + 3], &[220, 50, 47]);
+
+        let png = frame().to_png();
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+    }
+
+    #[test]
+    fn test_tint_and_distinct_colors() {
+        let mut frame = frame();
+        frame.tint([Point { x: 0, y: 1 }], Color::BLUE);
+        assert_eq!(
+            frame.get(Point { x: 0, y: 1 }),
+            Some(&Cell::new('.', Color::BLUE))
+        );
+        assert_ne!(Color::distinct(0), Color::distinct(1));
+        assert_eq!(Color::WHITE.dim(0.5), Color(128, 128, 128));
+    }
+
+    #[test]
+    fn test_format_from_str() {
+        assert_eq!("png".parse(), Ok(RenderFormat::Png));
+        assert!("gif".parse::<RenderFormat>().is_err());
+    }
+}
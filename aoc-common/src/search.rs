@@ -0,0 +1,349 @@
+//! Graph searches over states generated on the fly.
+//!
+//! A puzzle describes its graph with a successor function: given a state,
+//! the states one move away and, for weighted searches, what each move
+//! costs. [`bfs`] and [`dijkstra`] explore everything reachable and keep
+//! every shortest way into each state as a [`ShortestPaths`] DAG, which
+//! answers questions like "which tiles are on a best path" or "how many
+//! best paths are there". [`astar`] stops at the first goal it reaches.
+
+use std::cmp::Ordering;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// Least costs from the start states to every state reached, with every
+/// predecessor a state can be reached from at that cost.
+#[derive(Debug, Clone)]
+pub struct ShortestPaths<S, C> {
+    costs: HashMap<S, C>,
+    predecessors: HashMap<S, Vec<S>>,
+}
+
+impl<S: Clone + Eq + Hash, C: Copy> ShortestPaths<S, C> {
+    pub fn cost(&self, state: &S) -> Option<C> {
+        self.costs.get(state).copied()
+    }
+
+    pub fn contains(&self, state: &S) -> bool {
+        self.costs.contains_key(state)
+    }
+
+    pub fn len(&self) -> usize {
+        self.costs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.costs.is_empty()
+    }
+
+    /// Every state reached, in no particular order.
+    pub fn states(&self) -> impl Iterator<Item = &S> {
+        self.costs.keys()
+    }
+
+    pub fn costs(&self) -> impl Iterator<Item = (&S, C)> {
+        self.costs.iter().map(|(state, &cost)| (state, cost))
+    }
+
+    /// The states a shortest path can arrive at `state` from. Empty for the
+    /// start states.
+    pub fn predecessors(&self, state: &S) -> &[S] {
+        self.predecessors.get(state).map_or(&[], Vec::as_slice)
+    }
+
+    /// One shortest path from a start state to `end`, inclusive.
+    pub fn path_to(&self, end: &S) -> Option<Vec<S>> {
+        if !self.contains(end) {
+            return None;
+        }
+        let mut path = vec![end.clone()];
+        while let Some(previous) = self.predecessors(path.last()?).first() {
+            path.push(previous.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Number of distinct shortest paths from the start states to `end`.
+    pub fn count_paths(&self, end: &S) -> usize {
+        fn count<S: Clone + Eq + Hash, C: Copy>(
+            paths: &ShortestPaths<S, C>,
+            state: &S,
+            counts: &mut HashMap<S, usize>,
+        ) -> usize {
+            if let Some(&n) = counts.get(state) {
+                return n;
+            }
+            let n = match paths.predecessors(state) {
+                [] => 1,
+                previous => previous.iter().map(|p| count(paths, p, counts)).sum(),
+            };
+            counts.insert(state.clone(), n);
+            n
+        }
+
+        if !self.contains(end) {
+            return 0;
+        }
+        count(self, end, &mut HashMap::new())
+    }
+
+    /// Every state on at least one shortest path to any of `ends`.
+    pub fn on_paths_to(&self, ends: impl IntoIterator<Item = S>) -> HashSet<S> {
+        let mut on_paths = HashSet::new();
+        let mut stack: Vec<S> = ends.into_iter().filter(|end| self.contains(end)).collect();
+        while let Some(state) = stack.pop() {
+            if on_paths.insert(state.clone()) {
+                stack.extend(self.predecessors(&state).iter().cloned());
+            }
+        }
+        on_paths
+    }
+
+    fn starting_at(starts: impl IntoIterator<Item = S>, zero: C) -> Self {
+        ShortestPaths {
+            costs: starts.into_iter().map(|start| (start, zero)).collect(),
+            predecessors: HashMap::new(),
+        }
+    }
+
+    /// Records reaching `next` from `state` at `cost`. Returns true if that
+    /// beats the best cost so far, so `next` needs exploring (again).
+    fn relax(&mut self, state: &S, next: S, cost: C) -> bool
+    where
+        C: Ord,
+    {
+        match self.costs.entry(next.clone()) {
+            Entry::Vacant(entry) => {
+                entry.insert(cost);
+            }
+            Entry::Occupied(mut entry) => match cost.cmp(entry.get()) {
+                Ordering::Less => {
+                    entry.insert(cost);
+                }
+                Ordering::Equal => {
+                    // A start state reached again at no cost keeps no
+                    // predecessors, so it still counts as a start
+                    if self.predecessors.contains_key(&next) {
+                        self.predecessors
+                            .entry(next)
+                            .or_default()
+                            .push(state.clone());
+                    }
+                    return false;
+                }
+                Ordering::Greater => return false,
+            },
+        }
+        self.predecessors.insert(next, vec![state.clone()]);
+        true
+    }
+}
+
+/// Breadth-first search, where every move costs one step.
+pub fn bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+) -> ShortestPaths<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut paths = ShortestPaths::starting_at(starts, 0);
+    let mut queue: VecDeque<(S, usize)> = paths.costs().map(|(s, c)| (s.clone(), c)).collect();
+
+    while let Some((state, steps)) = queue.pop_front() {
+        for next in successors(&state) {
+            if paths.relax(&state, next.clone(), steps + 1) {
+                queue.push_back((next, steps + 1));
+            }
+        }
+    }
+
+    paths
+}
+
+/// Dijkstra's algorithm over moves with non-negative costs, exploring every
+/// reachable state.
+pub fn dijkstra<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+) -> ShortestPaths<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut paths = ShortestPaths::starting_at(starts, C::default());
+    let mut queue: BinaryHeap<Queued<S, C>> = paths
+        .costs()
+        .map(|(state, cost)| Queued::new(state.clone(), cost, cost))
+        .collect();
+
+    while let Some(Queued { state, cost, .. }) = queue.pop() {
+        // Skip stale entries for states since reached more cheaply
+        if paths.cost(&state).is_some_and(|best| best < cost) {
+            continue;
+        }
+        for (next, step) in successors(&state) {
+            let next_cost = cost + step;
+            if paths.relax(&state, next.clone(), next_cost) {
+                queue.push(Queued::new(next, next_cost, next_cost));
+            }
+        }
+    }
+
+    paths
+}
+
+/// A* search for the cheapest path to any state satisfying `is_goal`.
+/// `heuristic` estimates the remaining cost and must never overestimate it.
+/// Returns the path, inclusive of both ends, and its cost.
+pub fn astar<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<(Vec<S>, C)>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut paths = ShortestPaths::starting_at(starts, C::default());
+    let mut queue: BinaryHeap<Queued<S, C>> = paths
+        .costs()
+        .map(|(state, cost)| Queued::new(state.clone(), cost, cost + heuristic(state)))
+        .collect();
+
+    while let Some(Queued { state, cost, .. }) = queue.pop() {
+        if paths.cost(&state).is_some_and(|best| best < cost) {
+            continue;
+        }
+        if is_goal(&state) {
+            return Some((paths.path_to(&state)?, cost));
+        }
+        for (next, step) in successors(&state) {
+            let next_cost = cost + step;
+            if paths.relax(&state, next.clone(), next_cost) {
+                let estimate = next_cost + heuristic(&next);
+                queue.push(Queued::new(next, next_cost, estimate));
+            }
+        }
+    }
+
+    None
+}
+
+/// A state waiting in a priority queue, which pops the lowest priority
+/// first.
+struct Queued<S, C> {
+    state: S,
+    cost: C,
+    priority: C,
+}
+
+impl<S, C> Queued<S, C> {
+    fn new(state: S, cost: C, priority: C) -> Self {
+        Queued {
+            state,
+            cost,
+            priority,
+        }
+    }
+}
+
+impl<S, C: Ord> Ord for Queued<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Reversed, since BinaryHeap is a max-heap
+        other.priority.cmp(&self.priority)
+    }
+}
+
+impl<S, C: Ord> PartialOrd for Queued<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Ord> PartialEq for Queued<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<S, C: Ord> Eq for Queued<S, C> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A diamond 0 -> {1, 2} -> 3 -> 4, where both ways round cost 3 when
+    // weighted
+    fn edges(state: &u32) -> Vec<(u32, u32)> {
+        match state {
+            0 => vec![(1, 1), (2, 2)],
+            1 => vec![(3, 2)],
+            2 => vec![(3, 1)],
+            3 => vec![(4, 1)],
+            _ => vec![],
+        }
+    }
+
+    fn moves(state: &u32) -> Vec<u32> {
+        edges(state).into_iter().map(|(next, _)| next).collect()
+    }
+
+    #[test]
+    fn test_bfs() {
+        let paths = bfs([0], moves);
+        assert_eq!(paths.len(), 5);
+        assert_eq!(paths.cost(&4), Some(3));
+        assert_eq!(paths.count_paths(&4), 2);
+        assert_eq!(paths.on_paths_to([3]), HashSet::from([0, 1, 2, 3]));
+        assert_eq!(paths.predecessors(&0), &[] as &[u32]);
+        assert_eq!(paths.cost(&9), None);
+        assert_eq!(paths.count_paths(&9), 0);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let paths = dijkstra([0], edges);
+        assert_eq!(paths.cost(&3), Some(3));
+        // Both ways into 3 cost 3
+        assert_eq!(paths.count_paths(&4), 2);
+        assert_eq!(paths.path_to(&1), Some(vec![0, 1]));
+
+        let cheaper = dijkstra([0], |&state| match state {
+            0 => vec![(1, 1), (2, 5)],
+            _ => edges(&state),
+        });
+        assert_eq!(cheaper.count_paths(&4), 1);
+        assert_eq!(cheaper.on_paths_to([4]), HashSet::from([0, 1, 3, 4]));
+    }
+
+    #[test]
+    fn test_several_starts() {
+        let paths = bfs([1, 2], moves);
+        assert_eq!(paths.cost(&3), Some(1));
+        assert_eq!(paths.count_paths(&4), 2);
+        assert!(!paths.contains(&0));
+    }
+
+    #[test]
+    fn test_astar() {
+        // Walk a number line towards 10, where the heuristic is the distance
+        let step = |&n: &i32| [(n - 1, 1), (n + 1, 1)];
+        let (path, cost) = astar([3], step, |&n| (10 - n).abs(), |&n| n == 10).unwrap();
+        assert_eq!(cost, 7);
+        assert_eq!(path, (3..=10).collect::<Vec<_>>());
+
+        assert_eq!(
+            astar([0], edges, |_| 0, |&n| n == 4),
+            Some((vec![0, 1, 3, 4], 4))
+        );
+        assert_eq!(astar([0], edges, |_| 0, |&n| n == 9), None);
+    }
+}
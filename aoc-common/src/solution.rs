@@ -0,0 +1,218 @@
+use crate::{Answer, ParseError, Rng};
+use std::fmt;
+use std::time::{Duration, Instant};
+
+/// One of the two halves of a daily puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+/// A day's puzzle, split into a shared parse step and the two parts.
+pub trait Solution {
+    /// Day of the month, 1 through 25.
+    const DAY: u8;
+
+    /// The puzzle input bundled with the day, used when no other input is
+    /// given.
+    const INPUT: &'static str;
+
+    /// Parsed form of the puzzle input, shared by both parts.
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Answer;
+    fn part2(input: &Self::Input) -> Answer;
+
+    /// A random valid input that grows with `size`, for stress tests, or
+    /// `None` if the day has no generator. What `size` counts (lines, grid
+    /// side, ...) is up to the day.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let _ = (rng, size);
+        None
+    }
+
+    /// Every problem with the shape of `input`, where [`parse`](Solution::parse)
+    /// stops at the first. Days override this with checks run through a
+    /// [`Lint`](crate::Lint); by default it is just the parse error.
+    fn lint(input: &str) -> Vec<ParseError> {
+        Self::parse(input).err().into_iter().collect()
+    }
+
+    /// The answer to `part` worked out the slow, plain way, which stress
+    /// runs check the day's answer against, or `None` if the day has no
+    /// such answer for the part. It should share as little with the day's
+    /// solution as it can, so a silent overflow in one shows up as a
+    /// mismatch rather than the same wrong answer twice.
+    fn reference(input: &Self::Input, part: Part) -> Option<Answer> {
+        let _ = (input, part);
+        None
+    }
+}
+
+/// Answer and timing for a single part.
+#[derive(Debug, Clone)]
+pub struct PartRun {
+    pub part: Part,
+    pub answer: Answer,
+    pub time: Duration,
+}
+
+/// Everything produced by running one day against one input.
+#[derive(Debug, Clone)]
+pub struct Run {
+    pub day: u8,
+    pub parse_time: Duration,
+    pub parts: Vec<PartRun>,
+}
+
+/// Object-safe view of a [`Solution`], so the runner can keep every day in
+/// one table. Implemented for all solutions.
+pub trait DynSolution: Sync {
+    fn day(&self) -> u8;
+    fn input(&self) -> &'static str;
+    fn run(&self, input: &str, parts: &[Part]) -> Result<Run, ParseError>;
+    fn generate(&self, seed: u64, size: usize) -> Option<String>;
+    /// The day's [`Solution::lint`], which is never empty for an input the
+    /// day can't parse.
+    fn lint(&self, input: &str) -> Vec<ParseError>;
+    /// The day's [`Solution::reference`] answer, or `None` if the input
+    /// doesn't parse either.
+    fn reference(&self, input: &str, part: Part) -> Option<Answer>;
+}
+
+impl<S: Solution + Sync> DynSolution for S {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn input(&self) -> &'static str {
+        S::INPUT
+    }
+
+    fn run(&self, input: &str, parts: &[Part]) -> Result<Run, ParseError> {
+        let start = Instant::now();
+        let parsed = S::parse(input)?;
+        let parse_time = start.elapsed();
+
+        let parts = parts
+            .iter()
+            .map(|&part| {
+                let start = Instant::now();
+                let answer = match part {
+                    Part::One => S::part1(&parsed),
+                    Part::Two => S::part2(&parsed),
+                };
+                PartRun {
+                    part,
+                    answer,
+                    time: start.elapsed(),
+                }
+            })
+            .collect();
+
+        Ok(Run {
+            day: S::DAY,
+            parse_time,
+            parts,
+        })
+    }
+
+    fn generate(&self, seed: u64, size: usize) -> Option<String> {
+        S::generate(&mut Rng::new(seed), size)
+    }
+
+    fn lint(&self, input: &str) -> Vec<ParseError> {
+        let mut problems = S::lint(input);
+        if problems.is_empty() {
+            problems.extend(S::parse(input).err());
+        }
+        problems
+    }
+
+    fn reference(&self, input: &str, part: Part) -> Option<Answer> {
+        S::reference(&S::parse(input).ok()?, part)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ParseErrorKind, Source};
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 0;
+        const INPUT: &'static str = "1,2,3";
+        type Input = Vec<i64>;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            let source = Source::new(input)?;
+            source.numbers(input, ",")
+        }
+
+        fn part1(input: &Self::Input) -> Answer {
+            input.iter().sum::<i64>().into()
+        }
+
+        fn part2(_input: &Self::Input) -> Answer {
+            Answer::Unsolved
+        }
+
+        fn reference(input: &Self::Input, part: Part) -> Option<Answer> {
+            let mut sum = 0;
+            for &n in input {
+                sum += n;
+            }
+            (part == Part::One).then(|| sum.into())
+        }
+    }
+
+    #[test]
+    fn test_run_selected_parts() {
+        let run = Sum.run("1,2,3", &[Part::One]).unwrap();
+        assert_eq!(run.day, 0);
+        assert_eq!(run.parts.len(), 1);
+        assert_eq!(run.parts[0].answer, Answer::Number(6));
+    }
+
+    #[test]
+    fn test_run_both_parts() {
+        let run = Sum.run(Sum.input(), &Part::BOTH).unwrap();
+        let answers: Vec<_> = run.parts.into_iter().map(|p| p.answer).collect();
+        assert_eq!(answers, vec![Answer::Number(6), Answer::Unsolved]);
+    }
+
+    #[test]
+    fn test_reference() {
+        assert_eq!(Sum.reference("1,2,3", Part::One), Some(Answer::Number(6)));
+        assert_eq!(Sum.reference("1,2,3", Part::Two), None);
+        assert_eq!(Sum.reference("1,x,3", Part::One), None);
+    }
+
+    #[test]
+    fn test_run_bad_input() {
+        let err = Sum.run("1,x,3", &Part::BOTH).unwrap_err();
+        assert_eq!(err.kind, ParseErrorKind::InvalidNumber("x".into()));
+        assert_eq!(err.column, 3);
+    }
+}
@@ -0,0 +1,187 @@
+//! Property tests and fuzz targets shared by the day crates, behind the
+//! `testing` feature.
+//!
+//! Every day checks its parser with [`parse_properties!`](crate::parse_properties):
+//! arbitrary text and slightly broken generated inputs must be rejected
+//! with an error that points inside the input, never a panic, and
+//! generated inputs must parse. A day's lint has to agree with its parser
+//! about which inputs are valid. The fuzz targets in `fuzz/` run the same
+//! [`check_parse`] on whatever bytes the fuzzer comes up with. Days with
+//! their own invariants build on the [`generated`] inputs.
+
+use crate::{ParseError, Rng, Solution};
+use proptest::prelude::*;
+use proptest::sample::{self, Index};
+use std::ops::Range;
+
+pub use proptest;
+
+/// Parses and lints `data` as an input for `S`, which may reject it but
+/// must not panic, and must report a rejection inside the input.
+pub fn check_parse<S: Solution>(data: &[u8]) {
+    let Ok(input) = std::str::from_utf8(data) else {
+        return;
+    };
+    if let Err(err) = S::parse(input) {
+        check_error(input, &err);
+    }
+    check_lint::<S>(input);
+}
+
+/// Panics unless `S`'s lint finds problems with `input` exactly when its
+/// parser rejects it, all of them inside the input.
+pub fn check_lint<S: Solution>(input: &str) {
+    let problems = S::lint(input);
+    match S::parse(input) {
+        Ok(_) => {
+            if let Some(err) = problems.first() {
+                panic!("lint rejects a valid input: {}", err.diagnostic());
+            }
+        }
+        Err(err) => assert!(!problems.is_empty(), "lint misses {}", err.diagnostic()),
+    }
+    for err in &problems {
+        check_error(input, err);
+    }
+}
+
+/// Panics unless `err` is at a line and column of `input`, counting the
+/// position just past the end of each line.
+pub fn check_error(input: &str, err: &ParseError) {
+    let line = err
+        .line
+        .checked_sub(1)
+        .and_then(|index| input.split('\n').nth(index))
+        .unwrap_or_else(|| panic!("{} is past the end of the input", err));
+    assert!(
+        (1..=line.chars().count() + 1).contains(&err.column),
+        "{} is past the end of its line {:?}",
+        err,
+        line
+    );
+}
+
+/// Inputs from the day's generator with a size in `sizes`, shrinking
+/// towards smaller inputs.
+pub fn generated<S: Solution>(sizes: Range<usize>) -> impl Strategy<Value = String> {
+    (sizes, any::<u64>()).prop_map(|(size, seed)| {
+        S::generate(&mut Rng::new(seed), size)
+            .unwrap_or_else(|| panic!("day {} has no generator", S::DAY))
+    })
+}
+
+/// Generated inputs with a few characters replaced or deleted, which are
+/// mostly invalid in the ways a hand-edited input would be.
+pub fn mutated<S: Solution>(sizes: Range<usize>) -> impl Strategy<Value = String> {
+    // Characters that mean something to most parsers, and occasionally
+    // anything at all
+    let replacement = prop_oneof![
+        4 => sample::select(&['0', '9', '-', '+', ' ', '\n', ',', ':', '|', '#', '.'][..]),
+        1 => any::<char>(),
+    ];
+    let edits = prop::collection::vec((any::<Index>(), prop::option::of(replacement)), 1..4);
+
+    (generated::<S>(sizes), edits).prop_map(|(input, edits)| {
+        let mut chars: Vec<char> = input.chars().collect();
+        for (at, edit) in edits {
+            if chars.is_empty() {
+                break;
+            }
+            let at = at.index(chars.len());
+            match edit {
+                Some(c) => chars[at] = c,
+                None => {
+                    chars.remove(at);
+                }
+            }
+        }
+        chars.into_iter().collect()
+    })
+}
+
+/// Property tests of a day's parser, for its `tests` directory:
+///
+/// ```ignore
+/// aoc_common::parse_properties!(aoc01::Day01);
+/// ```
+#[macro_export]
+macro_rules! parse_properties {
+    ($day:ty) => {
+        $crate::testing::proptest::proptest! {
+            #[test]
+            fn parse_accepts_generated_inputs(
+                input in $crate::testing::generated::<$day>(1..40)
+            ) {
+                if let Err(err) = <$day as $crate::Solution>::parse(&input) {
+                    panic!("{}", err.diagnostic());
+                }
+                $crate::testing::check_lint::<$day>(&input);
+            }
+
+            #[test]
+            fn parse_rejects_broken_inputs_cleanly(
+                input in $crate::testing::mutated::<$day>(1..40)
+            ) {
+                $crate::testing::check_parse::<$day>(input.as_bytes());
+            }
+
+            #[test]
+            fn parse_rejects_arbitrary_text_cleanly(input in "\\PC*") {
+                $crate::testing::check_parse::<$day>(input.as_bytes());
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Answer, ParseErrorKind, Source};
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 0;
+        const INPUT: &'static str = "1,2,3";
+        type Input = Vec<i64>;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            let source = Source::new(input)?;
+            source.numbers(input.trim_end(), ",")
+        }
+
+        fn part1(input: &Self::Input) -> Answer {
+            input.iter().sum::<i64>().into()
+        }
+
+        fn part2(_input: &Self::Input) -> Answer {
+            Answer::Unsolved
+        }
+
+        fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+            let numbers: Vec<String> = (0..size).map(|_| rng.range(0..=99).to_string()).collect();
+            Some(numbers.join(",") + "\n")
+        }
+    }
+
+    parse_properties!(Sum);
+
+    #[test]
+    fn test_check_error() {
+        let input = "1,2\n3,x\n";
+        let err = Sum::parse(input).unwrap_err();
+        check_error(input, &err);
+        check_error("", &ParseError::empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "past the end of the input")]
+    fn test_check_error_outside_input() {
+        let input = "ab\ncd\n";
+        let err = ParseError::at(input, &input[4..], ParseErrorKind::InvalidChar('d'));
+        assert_eq!((err.line, err.column), (2, 2));
+        // Just past the end of the line is fine
+        check_error("ab\nc", &err);
+        check_error("ab", &err);
+    }
+}
@@ -0,0 +1,146 @@
+//! Recording what a simulation does, one event per step.
+//!
+//! A simulation that can be traced takes a [`Tracer`] for its own event
+//! type and reports each step to it. Untraced runs pass [`NoTrace`], which
+//! compiles away. [`JsonLines`] writes every event as a line of JSON, so two
+//! implementations can be compared with an ordinary `diff`, and a `Vec`
+//! collects events for tests.
+
+use serde::Serialize;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+/// Receives a simulation's events as they happen.
+pub trait Tracer<E> {
+    fn record(&mut self, event: &E);
+}
+
+/// Ignores every event.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoTrace;
+
+impl<E> Tracer<E> for NoTrace {
+    #[inline]
+    fn record(&mut self, _event: &E) {}
+}
+
+impl<E: Clone> Tracer<E> for Vec<E> {
+    fn record(&mut self, event: &E) {
+        self.push(event.clone());
+    }
+}
+
+/// Writes each event as one line of JSON.
+///
+/// Simulations don't expect their tracer to fail, so the first write error
+/// is kept, later events are dropped, and [`finish`](JsonLines::finish)
+/// returns the error.
+pub struct JsonLines<W: Write> {
+    out: W,
+    error: Option<io::Error>,
+}
+
+impl<W: Write> JsonLines<W> {
+    pub fn new(out: W) -> Self {
+        JsonLines { out, error: None }
+    }
+
+    /// Flushes the output, or returns the first error writing it.
+    pub fn finish(mut self) -> io::Result<W> {
+        match self.error.take() {
+            Some(err) => Err(err),
+            None => self.out.flush().map(|()| self.out),
+        }
+    }
+}
+
+impl JsonLines<Box<dyn Write>> {
+    /// Writes to the file at `path`, or stdout for `-`.
+    pub fn create(path: &Path) -> io::Result<Self> {
+        let out: Box<dyn Write> = if path == Path::new("-") {
+            Box::new(io::stdout().lock())
+        } else {
+            let file = File::create(path).map_err(|err| {
+                io::Error::new(err.kind(), format!("{}: {}", path.display(), err))
+            })?;
+            Box::new(file)
+        };
+        Ok(JsonLines::new(Box::new(BufWriter::new(out))))
+    }
+}
+
+impl<E: Serialize, W: Write> Tracer<E> for JsonLines<W> {
+    fn record(&mut self, event: &E) {
+        if self.error.is_some() {
+            return;
+        }
+        let written = serde_json::to_writer(&mut self.out, event)
+            .map_err(io::Error::from)
+            .and_then(|()| self.out.write_all(b"\n"));
+        if let Err(err) = written {
+            self.error = Some(err);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Direction, Point};
+
+    #[derive(Clone, Serialize)]
+    struct Step {
+        position: Point,
+        facing: Direction,
+    }
+
+    fn walk(tracer: &mut impl Tracer<Step>) {
+        for x in 0..2 {
+            tracer.record(&Step {
+                position: Point { x, y: 3 },
+                facing: Direction::East,
+            });
+        }
+    }
+
+    #[test]
+    fn test_json_lines() {
+        let mut tracer = JsonLines::new(Vec::new());
+        walk(&mut tracer);
+        let out = String::from_utf8(tracer.finish().unwrap()).unwrap();
+        assert_eq!(
+            out,
+            "{\"position\":{\"x\":0,\"y\":3},\"facing\":\"East\"}\n\
+             {\"position\":{\"x\":1,\"y\":3},\"facing\":\"East\"}\n"
+        );
+    }
+
+    #[test]
+    fn test_collect_and_ignore() {
+        let mut steps = Vec::new();
+        walk(&mut steps);
+        assert_eq!(steps.len(), 2);
+        walk(&mut NoTrace);
+    }
+
+    #[test]
+    fn test_write_error_is_kept() {
+        struct Full;
+        impl Write for Full {
+            fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+                Err(io::Error::new(io::ErrorKind::StorageFull, "full"))
+            }
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let mut tracer = JsonLines::new(Full);
+        walk(&mut tracer);
+        assert_eq!(
+            tracer.finish().err().map(|err| err.kind()),
+            Some(io::ErrorKind::StorageFull)
+        );
+    }
+}
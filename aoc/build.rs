@@ -0,0 +1,84 @@
+//! Hashes the sources behind each day's answers, so the result cache can
+//! tell when a cached answer was computed by code that has since changed.
+//!
+//! A day's hash covers its crate's `src/` and `Cargo.toml`, along with
+//! `aoc-common/src/`, which every day builds on. Inputs aren't included;
+//! the cache keys them separately.
+
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+fn main() -> io::Result<()> {
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let root = manifest_dir
+        .parent()
+        .expect("runner crate lives inside the workspace");
+    let common = root.join("aoc-common/src");
+    println!("cargo:rerun-if-changed={}", common.display());
+    // New days are registered in the workspace manifest
+    println!(
+        "cargo:rerun-if-changed={}",
+        root.join("Cargo.toml").display()
+    );
+
+    let mut days: Vec<(u8, PathBuf)> = fs::read_dir(root)?
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let name = entry.file_name().into_string().ok()?;
+            let day = name.strip_prefix("aoc")?.parse().ok()?;
+            Some((day, entry.path()))
+        })
+        .collect();
+    days.sort();
+
+    let mut table = String::from("&[\n");
+    for (day, dir) in days {
+        let src = dir.join("src");
+        let manifest = dir.join("Cargo.toml");
+        println!("cargo:rerun-if-changed={}", src.display());
+        println!("cargo:rerun-if-changed={}", manifest.display());
+
+        let mut files = vec![manifest];
+        collect_files(&src, &mut files)?;
+        collect_files(&common, &mut files)?;
+        let mut hash = FNV_OFFSET;
+        for file in &files {
+            let path = file.strip_prefix(root).unwrap_or(file);
+            hash = fnv(hash, path.to_string_lossy().as_bytes());
+            hash = fnv(hash, &fs::read(file)?);
+        }
+        table += &format!("    ({}, \"{:016x}\"),\n", day, hash);
+    }
+    table += "]\n";
+
+    let out = PathBuf::from(env::var("OUT_DIR").unwrap()).join("source_hashes.rs");
+    fs::write(out, table)
+}
+
+// Every file under `dir`, in a fixed order
+fn collect_files(dir: &Path, files: &mut Vec<PathBuf>) -> io::Result<()> {
+    let mut entries: Vec<PathBuf> = fs::read_dir(dir)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<_, _>>()?;
+    entries.sort();
+    for path in entries {
+        if path.is_dir() {
+            collect_files(&path, files)?;
+        } else {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+
+// 64-bit FNV-1a, as the runner uses for inputs, with a zero byte after each
+// chunk so moving bytes between a path and its contents changes the hash
+fn fnv(hash: u64, bytes: &[u8]) -> u64 {
+    bytes.iter().chain([0].iter()).fold(hash, |hash, &byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+    })
+}
@@ -0,0 +1,177 @@
+//! Running one day over a directory of inputs, such as everyone's inputs on
+//! a team, and checking each file's answers against the answers manifest.
+//! A solution that only works on the input it was written for shows up as
+//! mismatches on the others.
+
+use crate::manifest::{self, Manifest};
+use crate::report::Failure;
+use crate::verify::{self, Check, Status};
+use aoc_common::par::prelude::*;
+use aoc_common::{DynSolution, Part};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// The result of running a day on one file.
+#[derive(Debug)]
+pub struct FileRun {
+    pub name: String,
+    pub outcome: Result<Checked, Failure>,
+}
+
+#[derive(Debug)]
+pub struct Checked {
+    pub parse_time: Duration,
+    pub checks: Vec<Check>,
+}
+
+impl FileRun {
+    /// Whether the file ran and nothing disagreed with the manifest.
+    pub fn is_ok(&self) -> bool {
+        self.outcome
+            .as_ref()
+            .is_ok_and(|run| run.checks.iter().all(|c| c.status != Status::Mismatch))
+    }
+}
+
+/// The files in `dir`, by name, skipping subdirectories and hidden files.
+pub fn inputs(dir: &Path) -> Result<Vec<PathBuf>, String> {
+    let entries = fs::read_dir(dir).map_err(|err| format!("{}: {}", dir.display(), err))?;
+    let mut paths: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.is_file())
+        .filter(|path| {
+            path.file_name()
+                .is_some_and(|name| !name.to_string_lossy().starts_with('.'))
+        })
+        .collect();
+    paths.sort();
+    Ok(paths)
+}
+
+/// Runs both parts on every file in parallel, in the order given.
+pub fn run(solution: &dyn DynSolution, paths: &[PathBuf], manifest: &Manifest) -> Vec<FileRun> {
+    paths
+        .par_iter()
+        .map(|path| {
+            let outcome = match fs::read_to_string(path) {
+                Ok(input) => solution
+                    .run(&input, &Part::BOTH)
+                    .map(|run| Checked {
+                        parse_time: run.parse_time,
+                        checks: verify::check(&run, manifest, &manifest::input_hash(&input)),
+                    })
+                    .map_err(Failure::Parse),
+                Err(err) => Err(Failure::Read(err)),
+            };
+            FileRun {
+                name: path.file_name().map_or_else(
+                    || path.display().to_string(),
+                    |name| name.to_string_lossy().into(),
+                ),
+                outcome,
+            }
+        })
+        .collect()
+}
+
+pub fn print_table(runs: &[FileRun]) {
+    let width = runs
+        .iter()
+        .map(|run| run.name.len())
+        .max()
+        .unwrap_or(0)
+        .max(4);
+    println!(
+        "{:<width$} {:<5} {:<9} {:<20} {:<20} {:>12}",
+        "File", "Part", "Status", "Answer", "Expected", "Time"
+    );
+    for run in runs {
+        match &run.outcome {
+            Ok(checked) => {
+                println!(
+                    "{:<width$} {:<5} {:<9} {:<20} {:<20} {:>12.2?}",
+                    run.name, "parse", "", "", "", checked.parse_time
+                );
+                for check in &checked.checks {
+                    println!(
+                        "{:<width$} {:<5} {:<9} {:<20} {:<20} {:>12.2?}",
+                        run.name,
+                        check.part.number(),
+                        check.status,
+                        check.answer.to_string(),
+                        check.expected.as_deref().unwrap_or("-"),
+                        check.time
+                    );
+                }
+            }
+            Err(failure) => {
+                let label = match failure {
+                    Failure::Parse(_) => "PARSE",
+                    _ => "FAILED",
+                };
+                println!("{:<width$} {:<5} {}", run.name, "-", label);
+            }
+        }
+    }
+
+    let count = |f: fn(&FileRun) -> bool| runs.iter().filter(|run| f(run)).count();
+    println!(
+        "\n{} files: {} ok, {} mismatched, {} failed",
+        runs.len(),
+        count(FileRun::is_ok),
+        count(|run| run.outcome.is_ok() && !run.is_ok()),
+        count(|run| run.outcome.is_err())
+    );
+
+    for run in runs {
+        if let Err(failure) = &run.outcome {
+            eprintln!("\n{}: {}", run.name, failure);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Answer;
+
+    const EXAMPLE: &str = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+
+    #[test]
+    fn test_batch() {
+        let dir = std::env::temp_dir().join(format!("aoc-batch-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("nested")).unwrap();
+        fs::write(dir.join("alice.txt"), EXAMPLE).unwrap();
+        fs::write(dir.join("bob.txt"), "1   2\n").unwrap();
+        fs::write(dir.join("carol.txt"), "1   x\n").unwrap();
+        fs::write(dir.join(".hidden"), "").unwrap();
+
+        let mut manifest = Manifest::default();
+        manifest.insert(1, 1, &manifest::input_hash(EXAMPLE), "11");
+        manifest.insert(1, 1, &manifest::input_hash("1   2\n"), "2");
+
+        let paths = inputs(&dir).unwrap();
+        assert_eq!(paths.len(), 3);
+        let runs = run(crate::days::find(1).unwrap(), &paths, &manifest);
+        let names: Vec<&str> = runs.iter().map(|run| run.name.as_str()).collect();
+        assert_eq!(names, ["alice.txt", "bob.txt", "carol.txt"]);
+
+        let statuses = |run: &FileRun| -> Vec<Status> {
+            let checked = run.outcome.as_ref().unwrap();
+            checked.checks.iter().map(|c| c.status).collect()
+        };
+        assert_eq!(statuses(&runs[0]), [Status::Ok, Status::Missing]);
+        assert_eq!(statuses(&runs[1]), [Status::Mismatch, Status::Missing]);
+        assert_eq!(
+            runs[1].outcome.as_ref().unwrap().checks[0].answer,
+            Answer::Number(1)
+        );
+        assert!(matches!(runs[2].outcome, Err(Failure::Parse(_))));
+        let ok: Vec<bool> = runs.iter().map(FileRun::is_ok).collect();
+        assert_eq!(ok, [true, false, false]);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
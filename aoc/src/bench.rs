@@ -0,0 +1,305 @@
+//! Repeated timing of each day's parse and parts, compared against a
+//! baseline saved from an earlier run on the same machine.
+
+use aoc_common::{DynSolution, ParseError, Part};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// One timed step of a solution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    Parse,
+    Part(Part),
+}
+
+impl Phase {
+    pub const ALL: [Phase; 3] = [Phase::Parse, Phase::Part(Part::One), Phase::Part(Part::Two)];
+
+    fn key(self) -> &'static str {
+        match self {
+            Phase::Parse => "parse",
+            Phase::Part(Part::One) => "part1",
+            Phase::Part(Part::Two) => "part2",
+        }
+    }
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.key())
+    }
+}
+
+/// Summary of repeated timings of one phase.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// Summarises a non-empty set of samples. The median of an even number
+    /// of samples is the mean of the middle two.
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let runs = sorted.len();
+        let min = *sorted.first()?;
+        let median = if runs % 2 == 1 {
+            sorted[runs / 2]
+        } else {
+            (sorted[runs / 2 - 1] + sorted[runs / 2]) / 2
+        };
+
+        let secs: Vec<f64> = sorted.iter().map(Duration::as_secs_f64).collect();
+        let mean = secs.iter().sum::<f64>() / runs as f64;
+        let variance = secs.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / runs as f64;
+
+        Some(Stats {
+            runs,
+            min,
+            median,
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        })
+    }
+}
+
+/// Times `runs` solves of `input`, after one untimed warm-up, and returns
+/// the stats for each phase in [`Phase::ALL`] order.
+pub fn measure(
+    solution: &dyn DynSolution,
+    input: &str,
+    runs: usize,
+) -> Result<Vec<(Phase, Stats)>, ParseError> {
+    solution.run(input, &Part::BOTH)?;
+
+    let mut samples = vec![Vec::with_capacity(runs); Phase::ALL.len()];
+    for _ in 0..runs {
+        let run = solution.run(input, &Part::BOTH)?;
+        samples[0].push(run.parse_time);
+        for (part, samples) in run.parts.iter().zip(&mut samples[1..]) {
+            samples.push(part.time);
+        }
+    }
+
+    Ok(Phase::ALL
+        .into_iter()
+        .zip(samples)
+        .filter_map(|(phase, samples)| Some((phase, Stats::from_samples(&samples)?)))
+        .collect())
+}
+
+/// Timings from an earlier run, keyed by day, phase and input hash. They
+/// only mean something on the machine that recorded them, so the file isn't
+/// checked in.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Baseline {
+    #[serde(default, rename = "timing")]
+    timings: Vec<Timing>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct Timing {
+    day: u8,
+    phase: String,
+    input: String,
+    median_ns: u64,
+    stddev_ns: u64,
+    runs: usize,
+}
+
+impl Baseline {
+    pub fn default_path() -> PathBuf {
+        crate::days::workspace_dir().join("bench-baseline.toml")
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        crate::store::load(path)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        crate::store::save(self, path)
+    }
+
+    /// The recorded median and standard deviation.
+    pub fn get(&self, day: u8, phase: Phase, input: &str) -> Option<(Duration, Duration)> {
+        self.timings
+            .iter()
+            .find(|t| t.day == day && t.phase == phase.key() && t.input == input)
+            .map(|t| {
+                (
+                    Duration::from_nanos(t.median_ns),
+                    Duration::from_nanos(t.stddev_ns),
+                )
+            })
+    }
+
+    /// Records a timing, replacing any previous one for the same key.
+    pub fn insert(&mut self, day: u8, phase: Phase, input: &str, stats: &Stats) {
+        self.timings
+            .retain(|t| !(t.day == day && t.phase == phase.key() && t.input == input));
+        self.timings.push(Timing {
+            day,
+            phase: phase.key().to_string(),
+            input: input.to_string(),
+            median_ns: stats.median.as_nanos() as u64,
+            stddev_ns: stats.stddev.as_nanos() as u64,
+            runs: stats.runs,
+        });
+        self.timings
+            .sort_by(|a, b| (a.day, &a.phase, &a.input).cmp(&(b.day, &b.phase, &b.input)));
+    }
+}
+
+/// How a new median compares with the baseline's.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    /// Nothing recorded for this phase and input yet.
+    New,
+    Unchanged,
+    Faster,
+    Regression,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            Verdict::New => "new",
+            Verdict::Unchanged => "",
+            Verdict::Faster => "faster",
+            Verdict::Regression => "REGRESSION",
+        };
+        f.pad(label)
+    }
+}
+
+/// A change counts when the medians differ by more than `threshold` (a
+/// fraction of the baseline) and by more than three baseline standard
+/// deviations, so noisy sub-microsecond phases don't get flagged.
+pub fn compare(stats: &Stats, baseline: Option<(Duration, Duration)>, threshold: f64) -> Verdict {
+    let Some((median, stddev)) = baseline else {
+        return Verdict::New;
+    };
+    let new = stats.median.as_secs_f64();
+    let old = median.as_secs_f64();
+    let significant =
+        (new - old).abs() > old * threshold && (new - old).abs() > 3.0 * stddev.as_secs_f64();
+
+    match significant {
+        false => Verdict::Unchanged,
+        true if new > old => Verdict::Regression,
+        true => Verdict::Faster,
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Measurement {
+    pub day: u8,
+    pub phase: Phase,
+    pub stats: Stats,
+    pub baseline: Option<Duration>,
+    pub verdict: Verdict,
+}
+
+pub fn print_table(measurements: &[Measurement]) {
+    println!(
+        "{:<4} {:<6} {:>5} {:>12} {:>12} {:>12} {:>12} {:>8}  ",
+        "Day", "Phase", "Runs", "Min", "Median", "Stddev", "Baseline", "Change"
+    );
+    for m in measurements {
+        let (baseline, change) = match m.baseline {
+            Some(baseline) => {
+                let change = m.stats.median.as_secs_f64() / baseline.as_secs_f64() - 1.0;
+                (
+                    format!("{:.2?}", baseline),
+                    format!("{:+.1}%", change * 100.0),
+                )
+            }
+            None => ("-".to_string(), "-".to_string()),
+        };
+        println!(
+            "{:<4} {:<6} {:>5} {:>12.2?} {:>12.2?} {:>12.2?} {:>12} {:>8}  {}",
+            format!("{:02}", m.day),
+            m.phase,
+            m.stats.runs,
+            m.stats.min,
+            m.stats.median,
+            m.stats.stddev,
+            baseline,
+            change,
+            m.verdict
+        );
+    }
+
+    let count = |verdict| measurements.iter().filter(|m| m.verdict == verdict).count();
+    println!(
+        "\n{} regressions, {} faster, {} new",
+        count(Verdict::Regression),
+        count(Verdict::Faster),
+        count(Verdict::New)
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(millis: &[u64]) -> Vec<Duration> {
+        millis.iter().copied().map(Duration::from_millis).collect()
+    }
+
+    fn stats(median: u64) -> Stats {
+        Stats::from_samples(&ms(&[median])).unwrap()
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::from_samples(&ms(&[9, 2, 4, 4, 4, 5, 5, 7])).unwrap();
+        assert_eq!(stats.runs, 8);
+        assert_eq!(stats.min, Duration::from_millis(2));
+        assert_eq!(
+            stats.median,
+            Duration::from_millis(4) + Duration::from_micros(500)
+        );
+        assert!((stats.stddev.as_secs_f64() - 0.002).abs() < 1e-9);
+
+        let odd = Stats::from_samples(&ms(&[3, 1, 2])).unwrap();
+        assert_eq!(odd.median, Duration::from_millis(2));
+        assert_eq!(Stats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn test_compare() {
+        let base = Some((Duration::from_millis(100), Duration::from_millis(2)));
+        assert_eq!(compare(&stats(100), None, 0.1), Verdict::New);
+        assert_eq!(compare(&stats(105), base, 0.1), Verdict::Unchanged);
+        assert_eq!(compare(&stats(120), base, 0.1), Verdict::Regression);
+        assert_eq!(compare(&stats(80), base, 0.1), Verdict::Faster);
+
+        // A big relative change within the baseline's noise isn't flagged
+        let noisy = Some((Duration::from_millis(100), Duration::from_millis(20)));
+        assert_eq!(compare(&stats(130), noisy, 0.1), Verdict::Unchanged);
+    }
+
+    #[test]
+    fn test_baseline_round_trip() {
+        let mut baseline = Baseline::default();
+        baseline.insert(2, Phase::Part(Part::Two), "beef", &stats(30));
+        baseline.insert(1, Phase::Parse, "beef", &stats(10));
+        baseline.insert(1, Phase::Parse, "beef", &stats(12));
+
+        let text = toml::to_string(&baseline).unwrap();
+        let loaded: Baseline = toml::from_str(&text).unwrap();
+        assert_eq!(loaded, baseline);
+        assert_eq!(loaded.timings.len(), 2);
+        assert_eq!(
+            loaded.get(1, Phase::Parse, "beef"),
+            Some((Duration::from_millis(12), Duration::ZERO))
+        );
+        assert_eq!(loaded.get(1, Phase::Parse, "cafe"), None);
+    }
+}
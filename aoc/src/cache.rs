@@ -0,0 +1,244 @@
+//! Answers already computed, so re-running unchanged days on unchanged
+//! inputs is instant.
+//!
+//! Each answer is keyed by day, part, the hash of the input and the hash of
+//! the day's sources when it was computed, which `build.rs` works out for
+//! every build. Editing a day, or the common crate, makes its cached answers
+//! stale: they are never used again, and `aoc cache prune` drops them.
+
+use crate::manifest;
+use aoc_common::{Answer, DynSolution, ParseError, Part, PartRun, Run};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+// (day, hash of its sources) for every day crate in the workspace
+static SOURCE_HASHES: &[(u8, &str)] = include!(concat!(env!("OUT_DIR"), "/source_hashes.rs"));
+
+/// Hash of the sources the runner was built from for `day`.
+pub fn source_hash(day: u8) -> Option<&'static str> {
+    SOURCE_HASHES
+        .iter()
+        .find(|&&(d, _)| d == day)
+        .map(|&(_, hash)| hash)
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Cache {
+    #[serde(default, rename = "result")]
+    entries: Vec<Entry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Entry {
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+    pub source: String,
+    pub answer: String,
+    /// How long the part took when it was solved.
+    pub time_ns: u64,
+}
+
+impl Entry {
+    /// Whether the entry was computed from the sources the runner was built
+    /// from.
+    pub fn is_current(&self) -> bool {
+        source_hash(self.day) == Some(self.source.as_str())
+    }
+}
+
+impl Cache {
+    /// The cache file, next to the downloaded inputs.
+    pub fn default_path() -> PathBuf {
+        crate::client::cache_dir().join("results.toml")
+    }
+
+    /// Loads the cache, treating a missing file as an empty cache.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        crate::store::load(path)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|err| format!("{}: {}", dir.display(), err))?;
+        }
+        crate::store::save(self, path)
+    }
+
+    pub fn entries(&self) -> &[Entry] {
+        &self.entries
+    }
+
+    fn get(&self, day: u8, part: Part, input: &str, source: &str) -> Option<&Entry> {
+        self.entries.iter().find(|e| {
+            e.day == day && e.part == part.number() && e.input == input && e.source == source
+        })
+    }
+
+    fn insert(&mut self, entry: Entry) {
+        self.entries.retain(|e| {
+            !(e.day == entry.day
+                && e.part == entry.part
+                && e.input == entry.input
+                && e.source == entry.source)
+        });
+        self.entries.push(entry);
+        self.entries
+            .sort_by(|a, b| (a.day, a.part, &a.input).cmp(&(b.day, b.part, &b.input)));
+    }
+
+    /// Drops the entries `keep` rejects, returning how many went.
+    pub fn prune(&mut self, keep: impl Fn(&Entry) -> bool) -> usize {
+        let before = self.entries.len();
+        self.entries.retain(keep);
+        before - self.entries.len()
+    }
+}
+
+/// Like [`DynSolution::run`], but the parts with a current answer in the
+/// cache aren't solved again. The input is only parsed if some part has to
+/// be solved. Returns the run, in the order of `parts`, and which of its
+/// parts came from the cache.
+pub fn run(
+    cache: &mut Cache,
+    solution: &dyn DynSolution,
+    input: &str,
+    parts: &[Part],
+) -> Result<(Run, Vec<Part>), ParseError> {
+    let day = solution.day();
+    let Some(source) = source_hash(day) else {
+        // A day added since the runner was built
+        return Ok((solution.run(input, parts)?, Vec::new()));
+    };
+    let input_hash = manifest::input_hash(input);
+
+    let cached: Vec<PartRun> = parts
+        .iter()
+        .filter_map(|&part| {
+            let entry = cache.get(day, part, &input_hash, source)?;
+            Some(PartRun {
+                part,
+                answer: parse_answer(&entry.answer),
+                time: Duration::from_nanos(entry.time_ns),
+            })
+        })
+        .collect();
+    let missing: Vec<Part> = parts
+        .iter()
+        .copied()
+        .filter(|&part| !cached.iter().any(|run| run.part == part))
+        .collect();
+
+    let mut run = if missing.is_empty() {
+        Run {
+            day,
+            parse_time: Duration::ZERO,
+            parts: Vec::new(),
+        }
+    } else {
+        solution.run(input, &missing)?
+    };
+    for part in &run.parts {
+        if part.answer != Answer::Unsolved {
+            cache.insert(Entry {
+                day,
+                part: part.part.number(),
+                input: input_hash.clone(),
+                source: source.to_string(),
+                answer: part.answer.to_string(),
+                time_ns: part.time.as_nanos() as u64,
+            });
+        }
+    }
+
+    let from_cache = cached.iter().map(|run| run.part).collect();
+    run.parts.extend(cached);
+    run.parts
+        .sort_by_key(|run| parts.iter().position(|&part| part == run.part));
+    Ok((run, from_cache))
+}
+
+// Answers are stored as text; give numbers back as numbers, as long as that
+// doesn't change how they print
+fn parse_answer(answer: &str) -> Answer {
+    match answer.parse::<i64>() {
+        Ok(n) if n.to_string() == answer => Answer::Number(n),
+        _ => Answer::Text(answer.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+
+    const EXAMPLE: &str = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+
+    #[test]
+    fn test_every_day_has_a_source_hash() {
+        for solution in days::DAYS {
+            let hash = source_hash(solution.day()).unwrap();
+            assert_eq!(hash.len(), 16);
+        }
+        assert_ne!(source_hash(1), source_hash(2));
+    }
+
+    #[test]
+    fn test_cached_parts_are_not_solved_again() {
+        let day01 = days::find(1).unwrap();
+        let mut cache = Cache::default();
+
+        let (solved, cached) = run(&mut cache, day01, EXAMPLE, &[Part::Two]).unwrap();
+        assert!(cached.is_empty());
+        assert_eq!(solved.parts[0].answer, Answer::Number(31));
+        assert_eq!(cache.entries().len(), 1);
+
+        // Only part one has to be solved
+        let (solved, cached) = run(&mut cache, day01, EXAMPLE, &Part::BOTH).unwrap();
+        assert_eq!(cached, [Part::Two]);
+        let answers: Vec<String> = solved.parts.iter().map(|p| p.answer.to_string()).collect();
+        assert_eq!(answers, ["11", "31"]);
+        assert_eq!(cache.entries().len(), 2);
+
+        // Another input is a miss
+        let (_, cached) = run(&mut cache, day01, "1   2\n", &[Part::Two]).unwrap();
+        assert!(cached.is_empty());
+        assert_eq!(cache.entries().len(), 3);
+    }
+
+    #[test]
+    fn test_stale_entries() {
+        let day01 = days::find(1).unwrap();
+        let mut cache = Cache::default();
+        run(&mut cache, day01, EXAMPLE, &Part::BOTH).unwrap();
+        cache.entries[0].source = "0000000000000000".to_string();
+        cache.entries[0].answer = "wrong".to_string();
+
+        let (solved, cached) = run(&mut cache, day01, EXAMPLE, &[Part::One]).unwrap();
+        assert!(cached.is_empty());
+        assert_eq!(solved.parts[0].answer, Answer::Number(11));
+        assert_eq!(cache.prune(Entry::is_current), 1);
+        assert!(cache.entries().iter().all(Entry::is_current));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let path = std::env::temp_dir()
+            .join(format!("aoc-cache-{}", std::process::id()))
+            .join("results.toml");
+        let mut cache = Cache::default();
+        run(&mut cache, days::find(1).unwrap(), EXAMPLE, &Part::BOTH).unwrap();
+        cache.save(&path).unwrap();
+        assert_eq!(Cache::load(&path).unwrap(), cache);
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_parse_answer() {
+        assert_eq!(parse_answer("-42"), Answer::Number(-42));
+        assert_eq!(parse_answer("007"), Answer::Text("007".into()));
+        assert_eq!(parse_answer("4,6,3"), Answer::Text("4,6,3".into()));
+    }
+}
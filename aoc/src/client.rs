@@ -0,0 +1,487 @@
+//! Downloading puzzle inputs from the puzzle site and submitting answers.
+//!
+//! Everything the site says is kept in a cache directory so it only has to
+//! be asked once: inputs never change, and an answer it has judged gets the
+//! same verdict if it is submitted again. Requests are spaced at least
+//! [`Config::min_interval`] apart, across runs, and after the site says to
+//! wait nothing is submitted until the wait is over.
+
+use aoc_common::Part;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+pub const YEAR: u16 = 2024;
+pub const DEFAULT_URL: &str = "https://adventofcode.com";
+
+/// The site asks automated tools to identify themselves.
+const USER_AGENT: &str = "aoc2024rs runner (fetch and submit)";
+
+/// How long the site locks out answers after a wrong one, when it doesn't
+/// say.
+const WRONG_ANSWER_WAIT: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub base_url: String,
+    pub session: String,
+    pub cache_dir: PathBuf,
+    pub min_interval: Duration,
+}
+
+impl Config {
+    /// The session cookie comes from `AOC_SESSION` or the file
+    /// `.aoc-session` at the workspace root, and `AOC_URL` can point the
+    /// client at another server.
+    pub fn from_env() -> Result<Self, String> {
+        let root = crate::days::workspace_dir();
+        let session = match std::env::var("AOC_SESSION") {
+            Ok(session) => session,
+            Err(_) => fs::read_to_string(root.join(".aoc-session")).map_err(|_| {
+                "No session token: set AOC_SESSION or save the session cookie in .aoc-session"
+                    .to_string()
+            })?,
+        };
+
+        Ok(Config {
+            base_url: std::env::var("AOC_URL").unwrap_or_else(|_| DEFAULT_URL.to_string()),
+            session: session.trim().to_string(),
+            cache_dir: cache_dir(),
+            min_interval: Duration::from_secs(5),
+        })
+    }
+}
+
+/// The cache at the workspace root.
+pub fn cache_dir() -> PathBuf {
+    crate::days::workspace_dir().join(".aoc-cache")
+}
+
+/// Where a day's input is cached.
+pub fn input_path(cache_dir: &Path, day: u8) -> PathBuf {
+    cache_dir.join(format!("{:02}-input.txt", day))
+}
+
+/// A reply, and whether it came from the cache rather than the site.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reply<T> {
+    pub value: T,
+    pub cached: bool,
+}
+
+/// What the site made of a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    /// With the site's hint, like "too high", if it gave one.
+    Wrong(Option<String>),
+    /// The site is still locked after an earlier answer.
+    TooRecent(Duration),
+    /// The part has been solved already, with a different answer.
+    AlreadySolved,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "That's the right answer"),
+            Verdict::Wrong(None) => write!(f, "That's not the right answer"),
+            Verdict::Wrong(Some(hint)) => write!(f, "That's not the right answer ({})", hint),
+            Verdict::TooRecent(wait) => write!(
+                f,
+                "Answered too recently, try again in {}s",
+                wait.as_secs_f64().ceil()
+            ),
+            Verdict::AlreadySolved => write!(f, "That part is already solved"),
+        }
+    }
+}
+
+/// Everything sent to the site so far, kept in the cache directory.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct History {
+    /// Unix time in milliseconds.
+    last_request: Option<u64>,
+    /// No answers until this Unix time in milliseconds.
+    locked_until: Option<u64>,
+    #[serde(default, rename = "submission")]
+    submissions: Vec<Submission>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct Submission {
+    day: u8,
+    part: u8,
+    answer: String,
+    correct: bool,
+    hint: Option<String>,
+}
+
+pub struct Client {
+    config: Config,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(config: Config) -> Self {
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(USER_AGENT)
+            .timeout(Duration::from_secs(30))
+            .build();
+        Client { config, agent }
+    }
+
+    /// A day's input, downloading it unless it is cached or `refresh` is set.
+    pub fn input(&self, day: u8, refresh: bool) -> Result<Reply<String>, String> {
+        let path = input_path(&self.config.cache_dir, day);
+        if !refresh {
+            if let Ok(input) = fs::read_to_string(&path) {
+                return Ok(Reply {
+                    value: input,
+                    cached: true,
+                });
+            }
+        }
+
+        let url = format!("{}/{}/day/{}/input", self.config.base_url, YEAR, day);
+        self.wait_turn()?;
+        let input = self
+            .agent
+            .get(&url)
+            .set("Cookie", &self.cookie())
+            .call()
+            .map_err(|err| request_error(day, err))?
+            .into_string()
+            .map_err(|err| format!("Cannot read the input for day {}: {}", day, err))?;
+
+        fs::create_dir_all(&self.config.cache_dir).map_err(|err| cache_error(&path, err))?;
+        fs::write(&path, &input).map_err(|err| cache_error(&path, err))?;
+        Ok(Reply {
+            value: input,
+            cached: false,
+        })
+    }
+
+    /// Submits an answer, unless the cache already knows the verdict or the
+    /// site is still locked.
+    pub fn submit(&self, day: u8, part: Part, answer: &str) -> Result<Reply<Verdict>, String> {
+        let level = part.number();
+        let mut history = self.history()?;
+        let cached = |value| {
+            Ok(Reply {
+                value,
+                cached: true,
+            })
+        };
+
+        let judged = history
+            .submissions
+            .iter()
+            .filter(|s| s.day == day && s.part == level);
+        for submission in judged.clone() {
+            if submission.answer == answer {
+                return cached(match submission.correct {
+                    true => Verdict::Correct,
+                    false => Verdict::Wrong(submission.hint.clone()),
+                });
+            }
+        }
+        if judged.clone().any(|s| s.correct) {
+            return cached(Verdict::AlreadySolved);
+        }
+        if let Some(wait) = history
+            .locked_until
+            .and_then(|until| until.checked_sub(now_millis()))
+        {
+            return cached(Verdict::TooRecent(Duration::from_millis(wait)));
+        }
+
+        let url = format!("{}/{}/day/{}/answer", self.config.base_url, YEAR, day);
+        self.wait_turn()?;
+        let page = self
+            .agent
+            .post(&url)
+            .set("Cookie", &self.cookie())
+            .send_form(&[("level", &level.to_string()), ("answer", answer)])
+            .map_err(|err| request_error(day, err))?
+            .into_string()
+            .map_err(|err| format!("Cannot read the reply for day {}: {}", day, err))?;
+        let verdict = parse_verdict(&page)?;
+
+        // The history may have been touched by wait_turn
+        history = self.history()?;
+        let mut record = |correct, hint: &Option<String>| {
+            history.submissions.push(Submission {
+                day,
+                part: level,
+                answer: answer.to_string(),
+                correct,
+                hint: hint.clone(),
+            })
+        };
+        match &verdict {
+            Verdict::Correct => record(true, &None),
+            Verdict::Wrong(hint) => {
+                record(false, hint);
+                history.locked_until = Some(now_millis() + WRONG_ANSWER_WAIT.as_millis() as u64);
+            }
+            Verdict::TooRecent(wait) => {
+                history.locked_until = Some(now_millis() + wait.as_millis() as u64);
+            }
+            Verdict::AlreadySolved => {}
+        }
+        self.save_history(&history)?;
+
+        Ok(Reply {
+            value: verdict,
+            cached: false,
+        })
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.config.session)
+    }
+
+    /// Sleeps until `min_interval` has passed since the last request, then
+    /// records this one.
+    fn wait_turn(&self) -> Result<(), String> {
+        let mut history = self.history()?;
+        let interval = self.config.min_interval.as_millis() as u64;
+        if let Some(last) = history.last_request {
+            let wait = (last + interval).saturating_sub(now_millis());
+            thread::sleep(Duration::from_millis(wait));
+        }
+        history.last_request = Some(now_millis());
+        self.save_history(&history)
+    }
+
+    fn history_path(&self) -> PathBuf {
+        self.config.cache_dir.join("history.toml")
+    }
+
+    fn history(&self) -> Result<History, String> {
+        crate::store::load(&self.history_path())
+    }
+
+    fn save_history(&self, history: &History) -> Result<(), String> {
+        fs::create_dir_all(&self.config.cache_dir)
+            .map_err(|err| cache_error(&self.config.cache_dir, err))?;
+        crate::store::save(history, &self.history_path())
+    }
+}
+
+fn now_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |time| time.as_millis() as u64)
+}
+
+fn request_error(day: u8, err: ureq::Error) -> String {
+    match err {
+        ureq::Error::Status(400, _) => "The site rejected the session token".to_string(),
+        ureq::Error::Status(404, _) => format!("Day {} isn't unlocked yet", day),
+        ureq::Error::Status(code, _) => format!("The site answered {} for day {}", code, day),
+        ureq::Error::Transport(err) => format!("Cannot reach the site: {}", err),
+    }
+}
+
+fn cache_error(path: &Path, err: std::io::Error) -> String {
+    format!("Cannot write {}: {}", path.display(), err)
+}
+
+/// Reads the verdict out of the page the site returns for an answer.
+fn parse_verdict(page: &str) -> Result<Verdict, String> {
+    let text = article_text(page);
+    if text.contains("That's the right answer") {
+        Ok(Verdict::Correct)
+    } else if text.contains("That's not the right answer") {
+        let hint = ["too high", "too low"]
+            .into_iter()
+            .find(|hint| text.contains(hint))
+            .map(str::to_string);
+        Ok(Verdict::Wrong(hint))
+    } else if text.contains("You gave an answer too recently") {
+        Ok(Verdict::TooRecent(
+            parse_wait(&text).unwrap_or(WRONG_ANSWER_WAIT),
+        ))
+    } else if text.contains("You don't seem to be solving the right level") {
+        Ok(Verdict::AlreadySolved)
+    } else {
+        Err(format!("Unexpected reply from the site: {}", text.trim()))
+    }
+}
+
+/// The text of the page's `<article>`, or the whole page without one, with
+/// the tags taken out.
+fn article_text(page: &str) -> String {
+    let article = page
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once('>'))
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(page, |(article, _)| article);
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text
+}
+
+/// The wait in "You have 1m 5s left to wait".
+fn parse_wait(text: &str) -> Option<Duration> {
+    let (_, rest) = text.split_once("You have ")?;
+    let (amount, _) = rest.split_once(" left")?;
+    amount
+        .split_whitespace()
+        .map(|part| {
+            let (number, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+            let number: u64 = number.parse().ok()?;
+            match unit {
+                "m" => Some(number * 60),
+                "s" => Some(number),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_site::{MockSite, Puzzle, SESSION};
+    use std::time::Instant;
+
+    const INPUT: &str = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+
+    fn site(cooldown: Duration) -> MockSite {
+        MockSite::start(
+            vec![Puzzle {
+                day: 1,
+                input: INPUT,
+                answers: ["11", "31"],
+            }],
+            cooldown,
+        )
+    }
+
+    fn client(name: &str, site: &MockSite, session: &str) -> Client {
+        let cache_dir =
+            std::env::temp_dir().join(format!("aoc-client-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&cache_dir);
+        Client::new(Config {
+            base_url: site.url.clone(),
+            session: session.to_string(),
+            cache_dir,
+            min_interval: Duration::from_millis(50),
+        })
+    }
+
+    #[test]
+    fn test_fetch_is_cached() {
+        let site = site(Duration::ZERO);
+        let client = client("fetch", &site, SESSION);
+
+        let first = client.input(1, false).unwrap();
+        assert_eq!(first.value, INPUT);
+        assert!(!first.cached);
+        let second = client.input(1, false).unwrap();
+        assert!(second.cached);
+        assert_eq!(site.requests(), 1);
+
+        // Refreshing asks again, no sooner than the minimum interval
+        let start = Instant::now();
+        assert!(!client.input(1, true).unwrap().cached);
+        assert!(start.elapsed() >= Duration::from_millis(40));
+        assert_eq!(site.requests(), 2);
+    }
+
+    #[test]
+    fn test_fetch_errors() {
+        let site = site(Duration::ZERO);
+        assert_eq!(
+            client("locked", &site, SESSION).input(2, false),
+            Err("Day 2 isn't unlocked yet".to_string())
+        );
+        assert_eq!(
+            client("session", &site, "stale").input(1, false),
+            Err("The site rejected the session token".to_string())
+        );
+    }
+
+    #[test]
+    fn test_submit() {
+        let site = site(Duration::from_secs(60));
+        let client = client("submit", &site, SESSION);
+        let submit = |answer: &str| client.submit(1, Part::One, answer).unwrap();
+
+        let wrong = submit("10");
+        assert_eq!(wrong.value, Verdict::Wrong(Some("too low".to_string())));
+        assert!(!wrong.cached);
+
+        // The same answer isn't sent twice, and the lockout is respected
+        assert!(submit("10").cached);
+        let locked = submit("12");
+        assert!(locked.cached);
+        assert!(matches!(locked.value, Verdict::TooRecent(wait) if wait.as_secs() > 50));
+        assert_eq!(site.requests(), 1);
+    }
+
+    #[test]
+    fn test_submit_after_lockout() {
+        let site = site(Duration::from_secs(60));
+        let client = client("lockout", &site, SESSION);
+
+        // Another client's wrong answer locked the site
+        let other = self::client("lockout-other", &site, SESSION);
+        other.submit(1, Part::Two, "1").unwrap();
+        let locked = client.submit(1, Part::One, "11").unwrap();
+        assert!(!locked.cached);
+        assert!(matches!(locked.value, Verdict::TooRecent(wait) if wait.as_secs() >= 59));
+        assert!(client.submit(1, Part::One, "11").unwrap().cached);
+    }
+
+    #[test]
+    fn test_submit_correct() {
+        let site = site(Duration::ZERO);
+        let client = client("correct", &site, SESSION);
+
+        assert_eq!(
+            client.submit(1, Part::Two, "31").unwrap().value,
+            Verdict::Correct
+        );
+        let again = client.submit(1, Part::Two, "31").unwrap();
+        assert_eq!(again.value, Verdict::Correct);
+        assert!(again.cached);
+        assert_eq!(
+            client.submit(1, Part::Two, "32").unwrap().value,
+            Verdict::AlreadySolved
+        );
+        assert_eq!(site.requests(), 1);
+    }
+
+    #[test]
+    fn test_parse_verdict() {
+        let page = "<main><article><p>You gave an answer too recently; you have to wait after \
+                    submitting an answer before trying again.  You have 1m 5s left to wait. \
+                    <a href=\"/2024/day/1\">[Return to Day 1]</a></p></article></main>";
+        assert_eq!(
+            parse_verdict(page),
+            Ok(Verdict::TooRecent(Duration::from_secs(65)))
+        );
+        assert_eq!(
+            parse_verdict("<article><p>That's not the right answer.</p></article>"),
+            Ok(Verdict::Wrong(None))
+        );
+        assert!(parse_verdict("<html>Welcome</html>").is_err());
+    }
+}
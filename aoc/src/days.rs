@@ -0,0 +1,65 @@
+use aoc_common::DynSolution;
+use std::path::{Path, PathBuf};
+
+/// Every solved day, in order.
+pub static DAYS: &[&dyn DynSolution] = &[
+    &aoc01::Day01,
+    &aoc02::Day02,
+    &aoc03::Day03,
+    &aoc04::Day04,
+    &aoc05::Day05,
+    &aoc06::Day06,
+    &aoc07::Day07,
+    &aoc08::Day08,
+    &aoc09::Day09,
+    &aoc10::Day10,
+    &aoc11::Day11,
+    &aoc12::Day12,
+    &aoc13::Day13,
+    &aoc14::Day14,
+    &aoc15::Day15,
+    &aoc16::Day16,
+    &aoc17::Day17,
+];
+
+pub fn find(day: u8) -> Option<&'static dyn DynSolution> {
+    DAYS.iter().copied().find(|solution| solution.day() == day)
+}
+
+/// Source directory of a day's crate, which holds its puzzle description.
+pub fn crate_dir(day: u8) -> PathBuf {
+    workspace_dir().join(format!("aoc{:02}", day))
+}
+
+pub fn workspace_dir() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("runner crate lives inside the workspace")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_days_are_ordered_and_unique() {
+        let days: Vec<u8> = DAYS.iter().map(|s| s.day()).collect();
+        assert!(days.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn test_find() {
+        assert_eq!(find(12).map(|s| s.day()), Some(12));
+        assert!(find(25).is_none());
+    }
+
+    #[test]
+    fn test_crate_dir() {
+        assert!(crate_dir(7).join("07-text.md").exists());
+    }
+
+    #[test]
+    fn test_inputs_are_bundled() {
+        assert!(DAYS.iter().all(|s| !s.input().trim().is_empty()));
+    }
+}
@@ -0,0 +1,362 @@
+//! Pulls the worked example and its answer out of a puzzle description.
+//!
+//! The descriptions are pasted from the puzzle pages, so examples show up
+//! either as fenced code blocks or as bare lines introduced by a sentence
+//! ending in a colon. The answer is taken from the last sentence before the
+//! closing question that mentions a number. Both are guesses: fixtures are
+//! written once and then reviewed by hand.
+
+use aoc_common::Part;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// What could be found in one description. Blocks keep a trailing newline.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Extracted {
+    /// The block introduced as the example ("For example:", "Here's a
+    /// larger example:").
+    pub example: Option<String>,
+    /// The first block of any kind, for descriptions that never say
+    /// "example".
+    pub first_block: Option<String>,
+    pub answer: Option<String>,
+}
+
+pub fn extract(markdown: &str) -> Extracted {
+    let lines: Vec<&str> = markdown.lines().collect();
+    let blocks = find_blocks(&lines);
+
+    // The last line is the question asked of the real input
+    let question = lines
+        .iter()
+        .rposition(|line| !line.trim().is_empty())
+        .unwrap_or(0);
+    let answer = (0..question)
+        .rev()
+        .filter(|&i| !blocks.iter().any(|block| block.contains(&i)))
+        .filter(|&i| is_prose(lines[i]))
+        .find_map(|i| last_number(lines[i]).map(|answer| (i, answer)));
+
+    // Descriptions often warm up on a small case before "a larger example",
+    // so take the last example introduced ahead of the answer
+    let answer_line = answer.as_ref().map_or(lines.len(), |&(i, _)| i);
+    let example = blocks.iter().rfind(|block| {
+        block.start < answer_line
+            && lines[..block.start]
+                .iter()
+                .rev()
+                .find(|line| !line.trim().is_empty() && !line.starts_with("```"))
+                .is_some_and(|intro| introduces_example(intro))
+    });
+
+    let text = |block: &std::ops::Range<usize>| {
+        let mut text = lines[block.clone()].join("\n");
+        text.push('\n');
+        text
+    };
+    Extracted {
+        example: example.map(text),
+        first_block: blocks.first().map(text),
+        answer: answer.map(|(_, answer)| answer),
+    }
+}
+
+/// Extracts fixtures from `NN-text.md` and `NNb-text.md` in a day's crate
+/// into its `examples` directory, keeping any existing files unless
+/// `force` is set. Part two reuses part one's example unless its
+/// description introduces a new one. Returns one summary line per part.
+pub fn generate(crate_dir: &Path, day: u8, force: bool) -> io::Result<Vec<String>> {
+    let dir = crate_dir.join("examples");
+    fs::create_dir_all(&dir)?;
+
+    let mut report = Vec::new();
+    let mut previous_input = None;
+    for part in Part::BOTH {
+        let suffix = if part == Part::One { "" } else { "b" };
+        let text = fs::read_to_string(crate_dir.join(format!("{:02}{}-text.md", day, suffix)))?;
+        let extracted = extract(&text);
+        let answer = extracted.answer;
+        let input = match part {
+            Part::One => extracted.example.or(extracted.first_block),
+            Part::Two => extracted.example.or(previous_input.take()),
+        };
+
+        let mut notes = Vec::new();
+        for (ext, contents) in [("txt", &input), ("answer", &answer)] {
+            let path = dir.join(format!("part{}.{}", part, ext));
+            match contents {
+                Some(_) if path.exists() && !force => {
+                    notes.push(format!("kept part{}.{}", part, ext))
+                }
+                Some(contents) => fs::write(&path, format!("{}\n", contents.trim_end()))?,
+                None => notes.push(format!("no {} found", ext)),
+            }
+        }
+        if dir.join(format!("part{}.skip", part)).exists() {
+            notes.push("skipped".to_string());
+        }
+
+        report.push(format!(
+            "Day {:02} part {}: {}",
+            day,
+            part,
+            if notes.is_empty() {
+                "ok".to_string()
+            } else {
+                notes.join(", ")
+            }
+        ));
+        previous_input = input;
+    }
+
+    Ok(report)
+}
+
+/// Line ranges of the example blocks, in order.
+fn find_blocks(lines: &[&str]) -> Vec<std::ops::Range<usize>> {
+    let mut blocks = Vec::new();
+    let mut i = 0;
+
+    while i < lines.len() {
+        let line = lines[i].trim_end();
+        if line.starts_with("```") {
+            let start = i + 1;
+            let end = (start..lines.len())
+                .find(|&j| lines[j].trim_end() == "```")
+                .unwrap_or(lines.len());
+            blocks.push(start..end);
+            i = end + 1;
+        } else if line.ends_with(':') && word_count(line) >= 2 {
+            let start = (i + 1..lines.len())
+                .find(|&j| !lines[j].trim().is_empty())
+                .unwrap_or(lines.len());
+            // Blank lines can separate records, as in a list of machines
+            let mut end = (start..lines.len())
+                .find(|&j| lines[j].starts_with("```") || is_prose(lines[j]))
+                .unwrap_or(lines.len());
+            while end > start && lines[end - 1].trim().is_empty() {
+                end -= 1;
+            }
+            if start < end && !lines[start].starts_with("```") {
+                blocks.push(start..end);
+            }
+            i = end.max(i + 1);
+        } else {
+            i += 1;
+        }
+    }
+
+    // Fenced blocks are also used for lists of rules; those aren't examples
+    blocks.retain(|block| {
+        let prose = lines[block.clone()]
+            .iter()
+            .filter(|line| is_prose(line))
+            .count();
+        prose * 2 <= block.len()
+    });
+    blocks
+}
+
+/// Whether a line like "For example, consider this map:" or "Here's a
+/// larger example:" leads into an example, as opposed to a worked
+/// illustration such as "In the above example, the updates are:".
+fn introduces_example(line: &str) -> bool {
+    let line = line.trim_end().to_lowercase();
+    let sentence = line.rsplit(". ").next().unwrap_or(&line);
+    line.ends_with(':') && (sentence.starts_with("for example") || line.ends_with("example:"))
+}
+
+/// Whether a line reads like a sentence rather than puzzle data.
+fn is_prose(line: &str) -> bool {
+    !(line.starts_with("# ") || line.starts_with("## ")) && word_count(line) >= 3
+}
+
+/// Number of English-looking words in a line.
+fn word_count(line: &str) -> usize {
+    line.split_whitespace()
+        .map(|word| word.trim_end_matches([',', '.', ';', ':', '!', '?']))
+        .filter(|word| {
+            word.len() >= 2
+                && word
+                    .chars()
+                    .all(|c| c.is_alphabetic() || c == '\'' || c == '-')
+        })
+        .count()
+}
+
+/// The last number in a line, ignoring anything in parentheses. Numbers
+/// joined by bare commas, like `4,6,3`, count as a single answer.
+fn last_number(line: &str) -> Option<String> {
+    let mut depth = 0;
+    let outside: String = line
+        .chars()
+        .map(|c| match c {
+            '(' => {
+                depth += 1;
+                ' '
+            }
+            ')' => {
+                depth -= 1;
+                ' '
+            }
+            _ if depth > 0 => ' ',
+            _ => c,
+        })
+        .collect();
+
+    outside
+        .split(|c: char| !c.is_ascii_digit() && c != ',')
+        .map(|token| token.trim_matches(','))
+        .rfind(|token| !token.is_empty() && !token.contains(",,"))
+        .map(str::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bare_example() {
+        let markdown = "\
+# Day 1: Historian Hysteria
+
+For example:
+
+3   4
+4   3
+
+In the example above, this is 2 + 1, a total distance of 11!
+
+Your actual lists are longer. What is the total distance between your lists?";
+        assert_eq!(
+            extract(markdown),
+            Extracted {
+                example: Some("3   4\n4   3\n".to_string()),
+                first_block: Some("3   4\n4   3\n".to_string()),
+                answer: Some("11".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn test_fenced_example_followed_by_prose() {
+        let markdown = "\
+Here is an example:
+
+```txt
+Register A: 729
+
+Program: 0,1,5,4,3,0
+```
+The program will eventually output 4,6,3,5,6,3,5,2,1,0.
+What do you get if you use commas to join the values it output?";
+        let extracted = extract(markdown);
+        assert_eq!(
+            extracted.example.as_deref(),
+            Some("Register A: 729\n\nProgram: 0,1,5,4,3,0\n")
+        );
+        assert_eq!(extracted.answer.as_deref(), Some("4,6,3,5,6,3,5,2,1,0"));
+    }
+
+    #[test]
+    fn test_example_ended_by_prose_line() {
+        let markdown = "\
+For example, consider the following section of corrupted memory:
+
+xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)
+Adding up the result of each instruction produces 161 (2*4 + 5*5).
+
+What do you get if you add up all of the results?";
+        let extracted = extract(markdown);
+        assert_eq!(
+            extracted.example.as_deref(),
+            Some("xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)\n")
+        );
+        assert_eq!(extracted.answer.as_deref(), Some("161"));
+    }
+
+    #[test]
+    fn test_prefers_last_example_before_answer() {
+        let markdown = "\
+Each plot grows a single type of plant:
+
+AAAA
+BBCD
+
+Here's a larger example:
+
+RRRR
+RRII
+
+So, it has a total price of 1930.
+
+Here's a map that includes an E-shaped region:
+
+EEE
+EXX
+
+What is the total price of fencing all regions on your map?";
+        let extracted = extract(markdown);
+        assert_eq!(extracted.example.as_deref(), Some("RRRR\nRRII\n"));
+        assert_eq!(extracted.answer.as_deref(), Some("1930"));
+    }
+
+    #[test]
+    fn test_nothing_found() {
+        let markdown = "\
+## --- Part Two ---
+
+The Historians sure are taking a long time.
+
+How many stones would you have after blinking a total of 75 times?";
+        assert_eq!(extract(markdown), Extracted::default());
+    }
+
+    #[test]
+    fn test_blank_lines_between_records() {
+        let markdown = "\
+For example:
+
+Button A: X+94, Y+34
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Prize: X=12748, Y=12176
+
+This list describes two claw machines. The total is 480.
+
+What is the fewest tokens you would have to spend?";
+        assert_eq!(
+            extract(markdown).example.as_deref(),
+            Some("Button A: X+94, Y+34\nPrize: X=8400, Y=5400\n\nButton A: X+26, Y+66\nPrize: X=12748, Y=12176\n")
+        );
+    }
+
+    #[test]
+    fn test_illustration_is_not_an_example() {
+        let markdown = "\
+In the above example, the correctly-ordered updates are:
+
+75,47,61,53,29
+97,61,53,29,13
+
+Adding these page numbers together gives 143.
+
+What do you get if you add up the middle page numbers?";
+        let extracted = extract(markdown);
+        assert_eq!(extracted.example, None);
+        assert_eq!(
+            extracted.first_block.as_deref(),
+            Some("75,47,61,53,29\n97,61,53,29,13\n")
+        );
+        assert_eq!(extracted.answer.as_deref(), Some("143"));
+    }
+
+    #[test]
+    fn test_last_number() {
+        assert_eq!(last_number("these are 61, 53, and 29."), Some("29".into()));
+        assert_eq!(last_number("is 31 (9 + 4 + 0)."), Some("31".into()));
+        assert_eq!(last_number("no numbers here"), None);
+    }
+}
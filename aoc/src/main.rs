@@ -0,0 +1,759 @@
+mod batch;
+mod bench;
+mod cache;
+mod client;
+mod days;
+mod examples;
+mod manifest;
+#[cfg(test)]
+mod mock_site;
+mod report;
+mod scaffold;
+mod select;
+mod store;
+mod stress;
+mod verify;
+
+use aoc_common::{DynSolution, InputSource, ParseError, Part, Run};
+use bench::Baseline;
+use cache::Cache;
+use clap::{Parser, Subcommand};
+use client::Client;
+use manifest::Manifest;
+use report::{DayReport, Failure, Format, Report};
+use select::DaySelection;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::Duration;
+
+#[derive(Parser)]
+#[command(about = "Run Advent of Code 2024 solutions")]
+struct Cli {
+    /// Threads for the days that run in parallel, by default one per core.
+    /// Answers are the same for any number
+    #[arg(long, global = true, value_parser = clap::value_parser!(u16).range(1..))]
+    threads: Option<u16>,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one or more days, by default against their bundled inputs
+    Run {
+        /// `all`, a day number, a range like `3-7`, or a comma list
+        days: DaySelection,
+        /// Only run this part
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Read the input from PATH, or `-` for stdin (single day only)
+        #[arg(short, long, value_name = "PATH")]
+        input: Option<InputSource>,
+        /// How to print the results
+        #[arg(long, value_enum, default_value_t)]
+        format: Format,
+        /// Solve every part again, without reading or writing the result
+        /// cache
+        #[arg(long)]
+        no_cache: bool,
+    },
+    /// Check answers against the recorded answers manifest
+    Verify {
+        /// `all`, a day number, a range like `3-7`, or a comma list
+        #[arg(default_value = "all")]
+        days: DaySelection,
+        /// Read the input from PATH, or `-` for stdin (single day only)
+        #[arg(short, long, value_name = "PATH")]
+        input: Option<InputSource>,
+        /// Answers manifest to check against [default: answers.toml at the
+        /// workspace root]
+        #[arg(long, value_name = "PATH")]
+        answers: Option<PathBuf>,
+        /// Record answers that are missing from the manifest
+        #[arg(long)]
+        record: bool,
+        /// How to print the results
+        #[arg(long, value_enum, default_value_t)]
+        format: Format,
+        /// Solve every part again, without reading or writing the result
+        /// cache
+        #[arg(long)]
+        no_cache: bool,
+    },
+    /// Time parsing and each part over repeated runs and compare with the
+    /// saved baseline
+    Bench {
+        /// `all`, a day number, a range like `3-7`, or a comma list
+        #[arg(default_value = "all")]
+        days: DaySelection,
+        /// Timed runs per day, after one warm-up run
+        #[arg(short, long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+        runs: u32,
+        /// Read the input from PATH, or `-` for stdin (single day only)
+        #[arg(short, long, value_name = "PATH")]
+        input: Option<InputSource>,
+        /// Baseline timings [default: bench-baseline.toml at the workspace
+        /// root]
+        #[arg(long, value_name = "PATH")]
+        baseline: Option<PathBuf>,
+        /// Replace the baseline with these timings (new entries are always
+        /// added)
+        #[arg(long)]
+        save: bool,
+        /// Percentage change in the median that counts as a regression
+        #[arg(long, value_name = "PCT", default_value_t = 10.0)]
+        threshold: f64,
+    },
+    /// Run one day against every input file in a directory, in parallel,
+    /// and check the answers against the manifest
+    Batch {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Directory of puzzle inputs, one per file
+        dir: PathBuf,
+        /// Answers manifest to check against [default: answers.toml at the
+        /// workspace root]
+        #[arg(long, value_name = "PATH")]
+        answers: Option<PathBuf>,
+    },
+    /// Extract example fixtures from the puzzle descriptions
+    Examples {
+        /// `all`, a day number, a range like `3-7`, or a comma list
+        #[arg(default_value = "all")]
+        days: DaySelection,
+        /// Overwrite fixtures that already exist
+        #[arg(long)]
+        force: bool,
+    },
+    /// Download a day's puzzle input into its crate
+    Fetch {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Download again even if the input is cached
+        #[arg(long)]
+        refresh: bool,
+    },
+    /// Submit an answer to the puzzle site
+    Submit {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        answer: String,
+    },
+    /// Create a crate for a new day from the template and register it
+    New {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// The puzzle's title, for the crate docs
+        #[arg(long)]
+        title: Option<String>,
+    },
+    /// Run days on generated inputs of growing size and report panics,
+    /// rejected inputs, timeouts and answers that differ from the day's
+    /// slower reference answer (overflows only panic in debug builds)
+    Stress {
+        /// `all`, a day number, a range like `3-7`, or a comma list
+        #[arg(default_value = "all")]
+        days: DaySelection,
+        /// Only run this part
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Input sizes to try, in order; what a size counts depends on the
+        /// day
+        #[arg(long, value_delimiter = ',', default_values_t = [10, 100, 1000])]
+        sizes: Vec<usize>,
+        /// First seed
+        #[arg(long, default_value_t = 1)]
+        seed: u64,
+        /// Seeds to try at each size
+        #[arg(short, long, default_value_t = 3)]
+        runs: u64,
+        /// Seconds before a run counts as hung
+        #[arg(long, value_name = "SECS", default_value_t = 10.0)]
+        timeout: f64,
+    },
+    /// Check an input's shape without solving it, reporting every problem
+    /// rather than the first
+    Lint {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// The input file, or `-` for stdin; the bundled input if left out
+        #[arg(value_name = "PATH")]
+        input: Option<InputSource>,
+    },
+    /// List or prune the cached answers that `run` and `verify` reuse
+    Cache {
+        #[command(subcommand)]
+        command: CacheCommand,
+    },
+    /// Print a generated input for a day
+    Generate {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        #[arg(long, default_value_t = 100)]
+        size: usize,
+        #[arg(long, default_value_t = 1)]
+        seed: u64,
+    },
+}
+
+#[derive(Subcommand)]
+enum CacheCommand {
+    /// Show every cached answer, and whether it is still current
+    List,
+    /// Drop the answers computed by code that has since changed
+    Prune {
+        /// Drop every answer
+        #[arg(long)]
+        all: bool,
+    },
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    if let Some(threads) = cli.threads {
+        if !aoc_common::par::ENABLED {
+            eprintln!("--threads has no effect without the parallel feature");
+        } else if let Err(err) = aoc_common::par::set_threads(threads.into()) {
+            eprintln!("{}", err);
+            return ExitCode::FAILURE;
+        }
+    }
+
+    let result = match cli.command {
+        Command::Run {
+            days,
+            part,
+            input,
+            format,
+            no_cache,
+        } => run(&days, part, &input.unwrap_or_default(), format, no_cache),
+        Command::Verify {
+            days,
+            input,
+            answers,
+            record,
+            format,
+            no_cache,
+        } => verify(
+            &days,
+            &input.unwrap_or_default(),
+            &answers.unwrap_or_else(Manifest::default_path),
+            record,
+            format,
+            no_cache,
+        ),
+        Command::Bench {
+            days,
+            runs,
+            input,
+            baseline,
+            save,
+            threshold,
+        } => bench(
+            &days,
+            runs as usize,
+            &input.unwrap_or_default(),
+            &baseline.unwrap_or_else(Baseline::default_path),
+            save,
+            threshold / 100.0,
+        ),
+        Command::Batch { day, dir, answers } => {
+            batch(day, &dir, &answers.unwrap_or_else(Manifest::default_path))
+        }
+        Command::Examples { days, force } => extract_examples(&days, force),
+        Command::Fetch { day, refresh } => fetch(day, refresh),
+        Command::Submit { day, part, answer } => submit(day, part, &answer),
+        Command::New { day, title } => new_day(day, title.as_deref()),
+        Command::Stress {
+            days,
+            part,
+            sizes,
+            seed,
+            runs,
+            timeout,
+        } => stress(&days, part, &sizes, seed..seed + runs, timeout),
+        Command::Cache { command } => cache(command),
+        Command::Lint { day, input } => lint(day, &input.unwrap_or_default()),
+        Command::Generate { day, size, seed } => generate(day, size, seed),
+    };
+
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(err) => {
+            eprintln!("{}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+// Each command returns whether everything it checked was fine, or an error
+// that stopped it before it could check anything.
+
+fn run(
+    selection: &DaySelection,
+    part: Option<u8>,
+    source: &InputSource,
+    format: Format,
+    no_cache: bool,
+) -> Result<bool, String> {
+    let parts: &[Part] = match part {
+        Some(1) => &[Part::One],
+        Some(2) => &[Part::Two],
+        _ => &Part::BOTH,
+    };
+
+    let mut cache = load_cache(no_cache)?;
+    let mut days = Vec::new();
+    let failures = for_each_input(selection, source, |solution, input| {
+        let (run, cached) = run_cached(&mut cache, solution, input, parts)?;
+        match format {
+            Format::Text => print_run(&run, &cached),
+            Format::Json => days.push(DayReport::from_run(
+                &run,
+                &manifest::input_hash(input),
+                &cached,
+            )),
+        }
+        Ok(())
+    })?;
+    save_cache(&cache)?;
+
+    Ok(finish_report(format, "run", days, &failures))
+}
+
+fn verify(
+    selection: &DaySelection,
+    source: &InputSource,
+    answers: &Path,
+    record: bool,
+    format: Format,
+    no_cache: bool,
+) -> Result<bool, String> {
+    let mut manifest =
+        Manifest::load(answers).map_err(|err| format!("Cannot load answers: {}", err))?;
+
+    let mut checks = Vec::new();
+    let mut days = Vec::new();
+    let mut recorded = Vec::new();
+    let mut cache = load_cache(no_cache)?;
+    let failures = for_each_input(selection, source, |solution, input| {
+        let hash = manifest::input_hash(input);
+        let (run, cached) = run_cached(&mut cache, solution, input, &Part::BOTH)?;
+        let mut day = DayReport::from_run(&run, &hash, &cached);
+        for (check, part) in verify::check(&run, &manifest, &hash)
+            .into_iter()
+            .zip(&mut day.parts)
+        {
+            if check.status == verify::Status::Missing {
+                recorded.push((
+                    check.day,
+                    check.part.number(),
+                    hash.clone(),
+                    check.answer.to_string(),
+                ));
+            }
+            part.status = Some(check.status);
+            part.expected = check.expected.clone();
+            checks.push(check);
+        }
+        days.push(day);
+        Ok(())
+    })?;
+
+    save_cache(&cache)?;
+
+    if format == Format::Text {
+        verify::print_table(&checks);
+    }
+    let ok = finish_report(format, "verify", days, &failures);
+
+    if record && !recorded.is_empty() {
+        for (day, part, hash, answer) in &recorded {
+            manifest.insert(*day, *part, hash, answer);
+        }
+        manifest
+            .save(answers)
+            .map_err(|err| format!("Cannot save answers: {}", err))?;
+        // Keep stdout to the JSON document
+        let note = format!(
+            "Recorded {} answers in {}",
+            recorded.len(),
+            answers.display()
+        );
+        match format {
+            Format::Text => println!("{}", note),
+            Format::Json => eprintln!("{}", note),
+        }
+    }
+
+    let mismatched = checks
+        .iter()
+        .any(|check| check.status == verify::Status::Mismatch);
+    Ok(ok && !mismatched)
+}
+
+fn bench(
+    selection: &DaySelection,
+    runs: usize,
+    source: &InputSource,
+    path: &Path,
+    save: bool,
+    threshold: f64,
+) -> Result<bool, String> {
+    let mut baseline =
+        Baseline::load(path).map_err(|err| format!("Cannot load baseline: {}", err))?;
+
+    let mut measurements = Vec::new();
+    let mut updated = Vec::new();
+    let failures = for_each_input(selection, source, |solution, input| {
+        let hash = manifest::input_hash(input);
+        for (phase, stats) in bench::measure(solution, input, runs)? {
+            let recorded = baseline.get(solution.day(), phase, &hash);
+            let verdict = bench::compare(&stats, recorded, threshold);
+            if save || verdict == bench::Verdict::New {
+                updated.push((solution.day(), phase, hash.clone(), stats));
+            }
+            measurements.push(bench::Measurement {
+                day: solution.day(),
+                phase,
+                stats,
+                baseline: recorded.map(|(median, _)| median),
+                verdict,
+            });
+        }
+        Ok(())
+    })?;
+
+    bench::print_table(&measurements);
+    let ok = finish_report(Format::Text, "bench", Vec::new(), &failures);
+
+    if !updated.is_empty() {
+        for (day, phase, hash, stats) in &updated {
+            baseline.insert(*day, *phase, hash, stats);
+        }
+        baseline
+            .save(path)
+            .map_err(|err| format!("Cannot save baseline: {}", err))?;
+        println!("Saved baseline to {}", path.display());
+    }
+
+    let regressed = measurements
+        .iter()
+        .any(|m| m.verdict == bench::Verdict::Regression);
+    Ok(ok && !regressed)
+}
+
+fn batch(day: u8, dir: &Path, answers: &Path) -> Result<bool, String> {
+    let solution = days::find(day).ok_or_else(|| format!("Day {:02}: no solution", day))?;
+    let manifest =
+        Manifest::load(answers).map_err(|err| format!("Cannot load answers: {}", err))?;
+    let paths = batch::inputs(dir)?;
+    if paths.is_empty() {
+        return Err(format!("{}: no input files", dir.display()));
+    }
+
+    let runs = batch::run(solution, &paths, &manifest);
+    batch::print_table(&runs);
+    Ok(runs.iter().all(batch::FileRun::is_ok))
+}
+
+fn extract_examples(selection: &DaySelection, force: bool) -> Result<bool, String> {
+    let mut ok = true;
+    for solution in days::DAYS.iter().filter(|s| selection.contains(s.day())) {
+        let day = solution.day();
+        match examples::generate(&days::crate_dir(day), day, force) {
+            Ok(report) => report.iter().for_each(|line| println!("{}", line)),
+            Err(err) => {
+                eprintln!("Day {:02}: {}", day, err);
+                ok = false;
+            }
+        }
+    }
+    Ok(ok)
+}
+
+fn fetch(day: u8, refresh: bool) -> Result<bool, String> {
+    let client = Client::new(client::Config::from_env()?);
+    let input = client.input(day, refresh)?;
+
+    let dir = days::crate_dir(day);
+    let path = dir.join(format!("{:02}-input.txt", day));
+    let origin = if input.cached { "cached" } else { "downloaded" };
+    if dir.is_dir() {
+        fs::write(&path, &input.value).map_err(|err| format!("{}: {}", path.display(), err))?;
+        println!(
+            "Day {:02}: {} input saved to {}",
+            day,
+            origin,
+            path.display()
+        );
+    } else {
+        println!(
+            "Day {:02}: {} input saved to {} (no aoc{:02} crate yet)",
+            day,
+            origin,
+            client::input_path(&client::cache_dir(), day).display(),
+            day
+        );
+    }
+    Ok(true)
+}
+
+fn submit(day: u8, part: u8, answer: &str) -> Result<bool, String> {
+    let client = Client::new(client::Config::from_env()?);
+    let part = if part == 1 { Part::One } else { Part::Two };
+    let verdict = client.submit(day, part, answer.trim())?;
+
+    let note = if verdict.cached { " (from cache)" } else { "" };
+    println!("Day {:02} part {}: {}{}", day, part, verdict.value, note);
+    Ok(verdict.value == client::Verdict::Correct)
+}
+
+fn new_day(day: u8, title: Option<&str>) -> Result<bool, String> {
+    // Use an input fetched before the crate existed
+    let input = fs::read_to_string(client::input_path(&client::cache_dir(), day)).ok();
+    let root = days::workspace_dir();
+    for path in scaffold::create(root, day, title, input.as_deref())? {
+        let path = path.strip_prefix(root).unwrap_or(&path);
+        println!("wrote {}", path.display());
+    }
+    if input.is_none() {
+        println!("No input cached yet: run `aoc fetch {}`", day);
+    }
+    Ok(true)
+}
+
+fn stress(
+    selection: &DaySelection,
+    part: Option<u8>,
+    sizes: &[usize],
+    seeds: std::ops::Range<u64>,
+    timeout: f64,
+) -> Result<bool, String> {
+    let parts: &[Part] = match part {
+        Some(1) => &[Part::One],
+        Some(2) => &[Part::Two],
+        _ => &Part::BOTH,
+    };
+    let timeout = Duration::try_from_secs_f64(timeout)
+        .map_err(|_| format!("Invalid timeout: {}", timeout))?;
+    let solutions: Vec<&'static dyn DynSolution> = days::DAYS
+        .iter()
+        .copied()
+        .filter(|s| selection.contains(s.day()))
+        .collect();
+
+    println!(
+        "{:<4} {:>8} {:>6} {:>10} {:<8} {:>12}",
+        "Day", "Size", "Seed", "Bytes", "Status", "Time"
+    );
+    let mut failures = Vec::new();
+    stress::run(&solutions, sizes, seeds, parts, timeout, |case| {
+        if let stress::Outcome::NoGenerator = case.outcome {
+            println!("{:02}   no generator", case.day);
+            return;
+        }
+        println!(
+            "{:02}   {:>8} {:>6} {:>10} {:<8} {:>12.2?}",
+            case.day, case.size, case.seed, case.bytes, case.outcome, case.time
+        );
+        if case.outcome.is_failure() {
+            failures.push(case);
+        }
+    });
+
+    for case in &failures {
+        let detail = match &case.outcome {
+            stress::Outcome::Parse(err) => err.to_string(),
+            stress::Outcome::Panic(message) => message.clone(),
+            stress::Outcome::Wrong {
+                part,
+                answer,
+                expected,
+            } => format!("part {} answered {}, expected {}", part, answer, expected),
+            _ => format!("no answer after {:.2?}", case.time),
+        };
+        eprintln!(
+            "\nDay {:02} size {} seed {}: {}\n  reproduce with `aoc generate {} --size {} --seed {}`",
+            case.day, case.size, case.seed, detail, case.day, case.size, case.seed
+        );
+    }
+    Ok(failures.is_empty())
+}
+
+fn cache(command: CacheCommand) -> Result<bool, String> {
+    let path = Cache::default_path();
+    let mut cache = Cache::load(&path)?;
+    match command {
+        CacheCommand::List => {
+            for entry in cache.entries() {
+                println!(
+                    "Day {:02} part {}  input {}  source {}{}  {}",
+                    entry.day,
+                    entry.part,
+                    entry.input,
+                    entry.source,
+                    if entry.is_current() { "" } else { " (stale)" },
+                    entry.answer
+                );
+            }
+            let stale = cache.entries().iter().filter(|e| !e.is_current()).count();
+            println!(
+                "{} cached answers, {} stale, in {}",
+                cache.entries().len(),
+                stale,
+                path.display()
+            );
+        }
+        CacheCommand::Prune { all } => {
+            let pruned = cache.prune(|entry| !all && entry.is_current());
+            cache.save(&path)?;
+            println!("Dropped {} cached answers", pruned);
+        }
+    }
+    Ok(true)
+}
+
+fn lint(day: u8, source: &InputSource) -> Result<bool, String> {
+    let solution = days::find(day).ok_or_else(|| format!("Day {:02}: no solution", day))?;
+    let input = source
+        .read(solution.input())
+        .map_err(|err| format!("Day {:02}: {}", day, err))?;
+
+    let problems = solution.lint(&input);
+    for err in &problems {
+        println!("{}\n", err.diagnostic());
+    }
+    match problems.len() {
+        0 => println!("Day {:02}: no problems", day),
+        1 => println!("Day {:02}: 1 problem", day),
+        n => println!("Day {:02}: {} problems", day, n),
+    }
+    Ok(problems.is_empty())
+}
+
+fn generate(day: u8, size: usize, seed: u64) -> Result<bool, String> {
+    let solution = days::find(day).ok_or_else(|| format!("Day {:02}: no solution", day))?;
+    let input = solution
+        .generate(seed, size)
+        .ok_or_else(|| format!("Day {:02}: no generator", day))?;
+    print!("{}", input);
+    Ok(true)
+}
+
+// The result cache, unless the command was asked not to use it
+fn load_cache(no_cache: bool) -> Result<Option<Cache>, String> {
+    if no_cache {
+        return Ok(None);
+    }
+    Cache::load(&Cache::default_path())
+        .map(Some)
+        .map_err(|err| format!("Cannot load the result cache: {}", err))
+}
+
+fn save_cache(cache: &Option<Cache>) -> Result<(), String> {
+    match cache {
+        Some(cache) => cache
+            .save(&Cache::default_path())
+            .map_err(|err| format!("Cannot save the result cache: {}", err)),
+        None => Ok(()),
+    }
+}
+
+// Runs the parts, from the cache where it has them, returning which did
+fn run_cached(
+    cache: &mut Option<Cache>,
+    solution: &dyn DynSolution,
+    input: &str,
+    parts: &[Part],
+) -> Result<(Run, Vec<Part>), ParseError> {
+    match cache {
+        Some(cache) => cache::run(cache, solution, input, parts),
+        None => Ok((solution.run(input, parts)?, Vec::new())),
+    }
+}
+
+/// Reads the input for every selected day and hands it to `f`. Returns the
+/// days that couldn't be run because they have no solution or their input
+/// can't be read or parsed, in the order they failed.
+fn for_each_input(
+    selection: &DaySelection,
+    source: &InputSource,
+    mut f: impl FnMut(&dyn DynSolution, &str) -> Result<(), ParseError>,
+) -> Result<Vec<(u8, Failure)>, String> {
+    if *source != InputSource::Bundled
+        && !matches!(selection, DaySelection::Days(days) if days.len() == 1)
+    {
+        return Err("--input needs exactly one day".to_string());
+    }
+
+    let mut failures = Vec::new();
+    if let DaySelection::Days(requested) = selection {
+        for &day in requested.iter().filter(|&&day| days::find(day).is_none()) {
+            failures.push((day, Failure::NoSolution));
+        }
+    }
+
+    for &solution in days::DAYS.iter().filter(|s| selection.contains(s.day())) {
+        let result = match source.read(solution.input()) {
+            Ok(input) => f(solution, &input).map_err(Failure::Parse),
+            Err(err) => Err(Failure::Read(err)),
+        };
+        if let Err(failure) = result {
+            failures.push((solution.day(), failure));
+        }
+    }
+
+    Ok(failures)
+}
+
+/// Prints the failures, along with the days that ran for the JSON format.
+/// Returns whether there were no failures.
+fn finish_report(
+    format: Format,
+    command: &'static str,
+    mut days: Vec<DayReport>,
+    failures: &[(u8, Failure)],
+) -> bool {
+    match format {
+        Format::Text => {
+            for (day, failure) in failures {
+                eprintln!("Day {:02}: {}", day, failure);
+            }
+        }
+        Format::Json => {
+            days.extend(
+                failures
+                    .iter()
+                    .map(|(day, failure)| DayReport::failed(*day, failure)),
+            );
+            Report::new(command, days).print();
+        }
+    }
+    failures.is_empty()
+}
+
+fn print_run(run: &Run, cached: &[Part]) {
+    if run.parts.iter().all(|part| cached.contains(&part.part)) {
+        println!("Day {:02} (cached)", run.day);
+    } else {
+        println!("Day {:02} (parse {:.2?})", run.day, run.parse_time);
+    }
+    for part in &run.parts {
+        println!(
+            "  part {}: {:<20} {:>12.2?}{}",
+            part.part,
+            part.answer.to_string(),
+            part.time,
+            if cached.contains(&part.part) {
+                " (cached)"
+            } else {
+                ""
+            }
+        );
+    }
+}
@@ -0,0 +1,102 @@
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Known-correct answers, keyed by day, part and a hash of the input they
+/// were computed from, so the same manifest can hold answers for several
+/// people's inputs.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Manifest {
+    #[serde(default, rename = "answer")]
+    answers: Vec<Entry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct Entry {
+    day: u8,
+    part: u8,
+    input: String,
+    answer: String,
+}
+
+impl Manifest {
+    /// The manifest checked in at the workspace root.
+    pub fn default_path() -> PathBuf {
+        crate::days::workspace_dir().join("answers.toml")
+    }
+
+    /// Loads a manifest, treating a missing file as an empty one.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        crate::store::load(path)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        crate::store::save(self, path)
+    }
+
+    pub fn get(&self, day: u8, part: u8, input: &str) -> Option<&str> {
+        self.answers
+            .iter()
+            .find(|e| e.day == day && e.part == part && e.input == input)
+            .map(|e| e.answer.as_str())
+    }
+
+    /// Records an answer, replacing any previous one for the same key.
+    pub fn insert(&mut self, day: u8, part: u8, input: &str, answer: &str) {
+        self.answers
+            .retain(|e| !(e.day == day && e.part == part && e.input == input));
+        self.answers.push(Entry {
+            day,
+            part,
+            input: input.to_string(),
+            answer: answer.to_string(),
+        });
+        self.answers
+            .sort_by(|a, b| (a.day, a.part, &a.input).cmp(&(b.day, b.part, &b.input)));
+    }
+}
+
+/// 64-bit FNV-1a hash of the input, as 16 hex digits. Trailing whitespace is
+/// ignored so a missing final newline doesn't change the key.
+pub fn input_hash(input: &str) -> String {
+    let hash = input
+        .trim_end()
+        .bytes()
+        .fold(0xcbf2_9ce4_8422_2325, |hash: u64, byte| {
+            (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+        });
+    format!("{:016x}", hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_hash() {
+        assert_eq!(input_hash(""), "cbf29ce484222325");
+        assert_eq!(input_hash("a"), "af63dc4c8601ec8c");
+        assert_eq!(input_hash("1 2\n"), input_hash("1 2"));
+        assert_ne!(input_hash("1 2"), input_hash("2 1"));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut manifest = Manifest::default();
+        manifest.insert(2, 1, "beef", "282");
+        manifest.insert(1, 2, "beef", "31");
+        manifest.insert(1, 2, "beef", "32");
+
+        let text = toml::to_string(&manifest).unwrap();
+        let loaded: Manifest = toml::from_str(&text).unwrap();
+        assert_eq!(loaded, manifest);
+        assert_eq!(loaded.get(1, 2, "beef"), Some("32"));
+        assert_eq!(loaded.get(1, 2, "cafe"), None);
+        assert_eq!(loaded.answers[0].day, 1);
+    }
+
+    #[test]
+    fn test_missing_file_is_empty() {
+        let path = Path::new("/nonexistent/answers.toml");
+        assert_eq!(Manifest::load(path), Ok(Manifest::default()));
+    }
+}
@@ -0,0 +1,222 @@
+//! A stand-in for the puzzle site, for testing the client without a
+//! network. It serves inputs and judges answers over plain HTTP on a local
+//! port, with the site's status codes and the sentences the client looks
+//! for in its answer pages.
+
+use std::collections::HashSet;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+pub const SESSION: &str = "mock-session";
+
+/// A puzzle the site knows about.
+#[derive(Debug, Clone)]
+pub struct Puzzle {
+    pub day: u8,
+    pub input: &'static str,
+    pub answers: [&'static str; 2],
+}
+
+pub struct MockSite {
+    pub url: String,
+    requests: Arc<AtomicUsize>,
+}
+
+struct State {
+    puzzles: Vec<Puzzle>,
+    solved: HashSet<(u8, u8)>,
+    cooldown: Duration,
+    locked_until: Option<Instant>,
+}
+
+impl MockSite {
+    /// Starts serving on a free port. A wrong answer locks out further
+    /// answers for `cooldown`.
+    pub fn start(puzzles: Vec<Puzzle>, cooldown: Duration) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("bind a local port");
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(AtomicUsize::new(0));
+        let state = Arc::new(Mutex::new(State {
+            puzzles,
+            solved: HashSet::new(),
+            cooldown,
+            locked_until: None,
+        }));
+
+        let counter = Arc::clone(&requests);
+        // Serves until the test process exits
+        thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                counter.fetch_add(1, Ordering::SeqCst);
+                handle(stream, &state);
+            }
+        });
+
+        MockSite { url, requests }
+    }
+
+    /// Number of requests served so far.
+    pub fn requests(&self) -> usize {
+        self.requests.load(Ordering::SeqCst)
+    }
+}
+
+fn handle(stream: TcpStream, state: &Mutex<State>) {
+    let mut reader = BufReader::new(&stream);
+    let mut request_line = String::new();
+    if reader.read_line(&mut request_line).is_err() {
+        return;
+    }
+
+    let mut cookie = String::new();
+    let mut length = 0;
+    loop {
+        let mut header = String::new();
+        if reader.read_line(&mut header).is_err() || header.trim().is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            match name.trim().to_ascii_lowercase().as_str() {
+                "cookie" => cookie = value.trim().to_string(),
+                "content-length" => length = value.trim().parse().unwrap_or(0),
+                _ => {}
+            }
+        }
+    }
+    let mut body = vec![0; length];
+    if reader.read_exact(&mut body).is_err() {
+        return;
+    }
+
+    let mut words = request_line.split_whitespace();
+    let method = words.next().unwrap_or("");
+    let path = words.next().unwrap_or("");
+    let body = String::from_utf8_lossy(&body);
+    let (status, text) = respond(&mut state.lock().unwrap(), method, path, &cookie, &body);
+
+    let reason = match status {
+        200 => "OK",
+        400 => "Bad Request",
+        _ => "Not Found",
+    };
+    let response = format!(
+        "HTTP/1.1 {} {}\r\nContent-Type: text/html\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        reason,
+        text.len(),
+        text
+    );
+    let _ = (&stream).write_all(response.as_bytes());
+}
+
+fn respond(state: &mut State, method: &str, path: &str, cookie: &str, body: &str) -> (u16, String) {
+    if !cookie
+        .split(';')
+        .any(|c| c.trim() == format!("session={}", SESSION))
+    {
+        return (
+            400,
+            "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n".into(),
+        );
+    }
+
+    let route = path
+        .strip_prefix("/2024/day/")
+        .and_then(|rest| rest.split_once('/'))
+        .and_then(|(day, action)| Some((day.parse::<u8>().ok()?, action)));
+    let Some(puzzle) = route.and_then(|(day, _)| state.puzzles.iter().find(|p| p.day == day))
+    else {
+        return (
+            404,
+            "Please don't repeatedly request this endpoint before it unlocks!\n".into(),
+        );
+    };
+    let puzzle = puzzle.clone();
+
+    match (method, route.map(|(_, action)| action)) {
+        ("GET", Some("input")) => (200, puzzle.input.to_string()),
+        ("POST", Some("answer")) => (200, article(&judge(state, &puzzle, body))),
+        _ => (404, "404 Not Found\n".into()),
+    }
+}
+
+fn judge(state: &mut State, puzzle: &Puzzle, form: &str) -> String {
+    let field = |name: &str| {
+        form.split('&')
+            .find_map(|pair| pair.strip_prefix(name)?.strip_prefix('='))
+            .map(decode)
+            .unwrap_or_default()
+    };
+    let level: u8 = field("level").parse().unwrap_or(0);
+    let answer = field("answer");
+
+    if let Some(wait) = state
+        .locked_until
+        .map(|until| until.saturating_duration_since(Instant::now()))
+        .filter(|wait| !wait.is_zero())
+    {
+        return format!(
+            "You gave an answer too recently; you have to wait after submitting an answer \
+             before trying again.  You have {}s left to wait.",
+            wait.as_secs().max(1)
+        );
+    }
+    if !(1..=2).contains(&level) || state.solved.contains(&(puzzle.day, level)) {
+        return "You don't seem to be solving the right level.  Did you already complete it?"
+            .into();
+    }
+
+    let expected = puzzle.answers[level as usize - 1];
+    if answer == expected {
+        state.solved.insert((puzzle.day, level));
+        return "That's the right answer!  You are one gold star closer to finding the Chief \
+                Historian."
+            .into();
+    }
+
+    state.locked_until = Some(Instant::now() + state.cooldown);
+    let hint = match (answer.parse::<i64>(), expected.parse::<i64>()) {
+        (Ok(given), Ok(expected)) if given < expected => "; your answer is too low",
+        (Ok(given), Ok(expected)) if given > expected => "; your answer is too high",
+        _ => "",
+    };
+    format!(
+        "That's not the right answer{}.  If you're stuck, make sure you're using the full \
+         input data.  Please wait one minute before trying again.",
+        hint
+    )
+}
+
+fn article(text: &str) -> String {
+    format!(
+        "<html><body><main>\n<article><p>{}</p></article>\n</main></body></html>\n",
+        text
+    )
+}
+
+/// Undoes form encoding.
+fn decode(value: &str) -> String {
+    let mut bytes = value.bytes();
+    let mut out = Vec::with_capacity(value.len());
+    while let Some(byte) = bytes.next() {
+        match byte {
+            b'+' => out.push(b' '),
+            b'%' => {
+                let hex: Vec<u8> = bytes.by_ref().take(2).collect();
+                let decoded = std::str::from_utf8(&hex)
+                    .ok()
+                    .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+                match decoded {
+                    Some(byte) => out.push(byte),
+                    None => out.extend([b'%'].iter().chain(&hex)),
+                }
+            }
+            byte => out.push(byte),
+        }
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
@@ -0,0 +1,239 @@
+//! The `--format json` output: a single document per command with a fixed
+//! schema, so dashboards can collect results without scraping the tables.
+//!
+//! Every field is always present, using `null` where it doesn't apply.
+//! Answers are strings, since some days answer with text, and `null` for
+//! unsolved parts. Answers served from the result cache are marked
+//! `cached`, with the time they took when they were solved. Bump
+//! [`VERSION`] when the schema changes.
+
+use crate::verify::Status;
+use aoc_common::{Answer, ParseError, Part, Run};
+use serde::Serialize;
+use std::fmt;
+use std::io;
+
+pub const VERSION: u32 = 2;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum Format {
+    /// Tables for reading in a terminal
+    #[default]
+    Text,
+    /// One JSON document on stdout
+    Json,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Report {
+    pub version: u32,
+    pub command: &'static str,
+    pub days: Vec<DayReport>,
+}
+
+impl Report {
+    /// A report with the days in order, keeping those that failed alongside
+    /// the ones that ran.
+    pub fn new(command: &'static str, mut days: Vec<DayReport>) -> Self {
+        days.sort_by_key(|day| day.day);
+        Report {
+            version: VERSION,
+            command,
+            days,
+        }
+    }
+
+    pub fn print(&self) {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(self).expect("report serializes")
+        );
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DayReport {
+    pub day: u8,
+    /// Hash of the input, as used as a key in the answers manifest.
+    pub input: Option<String>,
+    /// `null` when the input wasn't parsed, because every part was cached.
+    pub parse_ns: Option<u64>,
+    pub parts: Vec<PartReport>,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PartReport {
+    pub part: u8,
+    pub answer: Option<String>,
+    pub time_ns: u64,
+    pub cached: bool,
+    /// Only set by `verify`.
+    pub status: Option<Status>,
+    pub expected: Option<String>,
+}
+
+impl DayReport {
+    /// The report of a run whose `cached` parts came from the result cache.
+    pub fn from_run(run: &Run, input_hash: &str, cached: &[Part]) -> Self {
+        let parsed = run.parts.iter().any(|part| !cached.contains(&part.part));
+        DayReport {
+            day: run.day,
+            input: Some(input_hash.to_string()),
+            parse_ns: parsed.then_some(run.parse_time.as_nanos() as u64),
+            parts: run
+                .parts
+                .iter()
+                .map(|part| PartReport {
+                    part: part.part.number(),
+                    answer: (part.answer != Answer::Unsolved).then(|| part.answer.to_string()),
+                    time_ns: part.time.as_nanos() as u64,
+                    cached: cached.contains(&part.part),
+                    status: None,
+                    expected: None,
+                })
+                .collect(),
+            diagnostics: Vec::new(),
+        }
+    }
+
+    pub fn failed(day: u8, failure: &Failure) -> Self {
+        DayReport {
+            day,
+            input: None,
+            parse_ns: None,
+            parts: Vec::new(),
+            diagnostics: vec![failure.diagnostic()],
+        }
+    }
+}
+
+/// Why a selected day produced no answers.
+#[derive(Debug)]
+pub enum Failure {
+    NoSolution,
+    Read(io::Error),
+    Parse(ParseError),
+}
+
+impl Failure {
+    fn diagnostic(&self) -> Diagnostic {
+        let (kind, line, column, snippet) = match self {
+            Failure::NoSolution => ("no_solution", None, None, None),
+            Failure::Read(_) => ("read", None, None, None),
+            Failure::Parse(err) => (
+                "parse",
+                Some(err.line),
+                Some(err.column),
+                Some(err.snippet.clone()),
+            ),
+        };
+        let message = match self {
+            Failure::Parse(err) => err.kind.to_string(),
+            other => other.to_string(),
+        };
+        Diagnostic {
+            kind,
+            message,
+            line,
+            column,
+            snippet,
+        }
+    }
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Failure::NoSolution => write!(f, "no solution"),
+            Failure::Read(err) => write!(f, "cannot read input: {}", err),
+            Failure::Parse(err) => write!(f, "{}", err.diagnostic()),
+        }
+    }
+}
+
+/// A problem with one day, located in the input for parse errors.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Diagnostic {
+    /// `no_solution`, `read` or `parse`.
+    pub kind: &'static str,
+    pub message: String,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    pub snippet: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{PartRun, Source};
+    use std::time::Duration;
+
+    #[test]
+    fn test_schema() {
+        let run = Run {
+            day: 17,
+            parse_time: Duration::from_micros(3),
+            parts: vec![
+                PartRun {
+                    part: Part::One,
+                    answer: Answer::Text("4,6,3".into()),
+                    time: Duration::from_nanos(250),
+                },
+                PartRun {
+                    part: Part::Two,
+                    answer: Answer::Unsolved,
+                    time: Duration::ZERO,
+                },
+            ],
+        };
+        let mut day = DayReport::from_run(&run, "beef", &[Part::Two]);
+        day.parts[0].status = Some(Status::Ok);
+        let report = Report::new("verify", vec![day]);
+
+        let json: serde_json::Value = serde_json::to_value(&report).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "version": 2,
+                "command": "verify",
+                "days": [{
+                    "day": 17,
+                    "input": "beef",
+                    "parse_ns": 3000,
+                    "parts": [
+                        {"part": 1, "answer": "4,6,3", "time_ns": 250, "cached": false, "status": "ok", "expected": null},
+                        {"part": 2, "answer": null, "time_ns": 0, "cached": true, "status": null, "expected": null}
+                    ],
+                    "diagnostics": []
+                }]
+            })
+        );
+    }
+
+    #[test]
+    fn test_failures_are_sorted_in() {
+        let source = Source::new("1 x").unwrap();
+        let err = source.number::<i32>(&source.text()[2..]).unwrap_err();
+        let report = Report::new(
+            "run",
+            vec![
+                DayReport::failed(9, &Failure::Parse(err)),
+                DayReport::failed(4, &Failure::NoSolution),
+            ],
+        );
+
+        let days: Vec<u8> = report.days.iter().map(|day| day.day).collect();
+        assert_eq!(days, [4, 9]);
+        assert_eq!(
+            report.days[1].diagnostics[0],
+            Diagnostic {
+                kind: "parse",
+                message: "Invalid number \"x\"".to_string(),
+                line: Some(1),
+                column: Some(3),
+                snippet: Some("1 x".to_string()),
+            }
+        );
+    }
+}
@@ -0,0 +1,205 @@
+//! `aoc new`: a crate for a new day, generated from the templates in
+//! `aoc/templates` and registered with the workspace and the runner.
+//!
+//! The registrations are plain text edits that keep each list in day order,
+//! so they only recognise the one-entry-per-line layout the files already
+//! use.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const CARGO_TOML: &str = include_str!("../templates/Cargo.toml.tmpl");
+const LIB_RS: &str = include_str!("../templates/lib.rs.tmpl");
+const GENERATE_RS: &str = include_str!("../templates/generate.rs.tmpl");
+const MAIN_RS: &str = include_str!("../templates/main.rs.tmpl");
+const API_RS: &str = include_str!("../templates/api.rs.tmpl");
+const SKIP: &str = include_str!("../templates/skip.tmpl");
+const INPUT: &str = include_str!("../templates/input.tmpl");
+
+/// Creates `aocNN` under the workspace `root` and registers it, returning
+/// every file written. `input` fills `NN-input.txt`, which gets a
+/// placeholder without it: the runner expects every day to bundle an input,
+/// and `aoc fetch` replaces it.
+pub fn create(
+    root: &Path,
+    day: u8,
+    title: Option<&str>,
+    input: Option<&str>,
+) -> Result<Vec<PathBuf>, String> {
+    let name = format!("aoc{:02}", day);
+    let dir = root.join(&name);
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+
+    // Work out every registration before writing anything, so a file that
+    // can't be edited leaves the workspace as it was
+    let registrations = [
+        (
+            root.join("Cargo.toml"),
+            format!("    \"{}\",", name),
+            "\"aoc",
+        ),
+        (
+            root.join("aoc/Cargo.toml"),
+            format!("{0} = {{ path = \"../{0}\" }}", name),
+            "aoc",
+        ),
+        (
+            root.join("aoc/src/days.rs"),
+            format!("    &{}::Day{:02},", name, day),
+            "&aoc",
+        ),
+    ];
+    let mut edits = Vec::new();
+    for (path, line, prefix) in registrations {
+        let text = fs::read_to_string(&path).map_err(|err| file_error(&path, err))?;
+        let edited = register(&text, day, &line, prefix)
+            .ok_or_else(|| format!("{}: cannot register day {}", path.display(), day))?;
+        edits.push((path, edited));
+    }
+
+    let heading = match title {
+        Some(title) => format!("Day {}: {}.", day, title.trim_end_matches('.')),
+        None => format!("Day {}.", day),
+    };
+    let fill = |template: &str| {
+        template
+            .replace("{{nn}}", &format!("{:02}", day))
+            .replace("{{day}}", &day.to_string())
+            .replace("{{heading}}", &heading)
+    };
+    let files = [
+        ("Cargo.toml".to_string(), fill(CARGO_TOML)),
+        ("src/lib.rs".to_string(), fill(LIB_RS)),
+        ("src/generate.rs".to_string(), fill(GENERATE_RS)),
+        ("src/main.rs".to_string(), fill(MAIN_RS)),
+        ("tests/api.rs".to_string(), fill(API_RS)),
+        (
+            format!("{:02}-input.txt", day),
+            input.map_or_else(|| fill(INPUT), str::to_string),
+        ),
+        (
+            "examples/part1.skip".to_string(),
+            SKIP.replace("{{part}}", "1"),
+        ),
+        (
+            "examples/part2.skip".to_string(),
+            SKIP.replace("{{part}}", "2"),
+        ),
+    ];
+
+    let mut written = Vec::new();
+    for (file, contents) in files {
+        let path = dir.join(file);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|err| file_error(parent, err))?;
+        }
+        fs::write(&path, contents).map_err(|err| file_error(&path, err))?;
+        written.push(path);
+    }
+    for (path, text) in edits {
+        fs::write(&path, text).map_err(|err| file_error(&path, err))?;
+        written.push(path);
+    }
+
+    Ok(written)
+}
+
+/// Adds `line` to the run of lines starting with `prefix` and a two-digit
+/// day, in day order. `None` if there is no such run or the day is already
+/// in it.
+fn register(text: &str, day: u8, line: &str, prefix: &str) -> Option<String> {
+    let day_of = |line: &str| -> Option<u8> {
+        line.trim_start()
+            .strip_prefix(prefix)?
+            .get(..2)?
+            .parse()
+            .ok()
+    };
+
+    let mut lines: Vec<&str> = text.lines().collect();
+    let days: Vec<(usize, u8)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| Some((i, day_of(line)?)))
+        .collect();
+    if days.iter().any(|&(_, d)| d == day) {
+        return None;
+    }
+    let at = match days.iter().find(|&&(_, d)| d > day) {
+        Some(&(i, _)) => i,
+        None => days.last()?.0 + 1,
+    };
+
+    lines.insert(at, line);
+    let mut edited = lines.join("\n");
+    if text.ends_with('\n') {
+        edited.push('\n');
+    }
+    Some(edited)
+}
+
+fn file_error(path: &Path, err: std::io::Error) -> String {
+    format!("{}: {}", path.display(), err)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MEMBERS: &str = "[workspace]\nmembers = [\n    \"aoc\",\n    \"aoc-common\",\n    \"aoc01\",\n    \"aoc03\",\n]\n";
+
+    #[test]
+    fn test_register_in_order() {
+        let edited = register(MEMBERS, 2, "    \"aoc02\",", "\"aoc").unwrap();
+        assert!(edited.contains("\"aoc01\",\n    \"aoc02\",\n    \"aoc03\",\n]\n"));
+
+        let edited = register(MEMBERS, 18, "    \"aoc18\",", "\"aoc").unwrap();
+        assert!(edited.ends_with("\"aoc03\",\n    \"aoc18\",\n]\n"));
+    }
+
+    #[test]
+    fn test_register_refuses_duplicates_and_unknown_layouts() {
+        assert_eq!(register(MEMBERS, 3, "    \"aoc03\",", "\"aoc"), None);
+        assert_eq!(
+            register("[workspace]\n", 3, "    \"aoc03\",", "\"aoc"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_create() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("aoc/src")).unwrap();
+        let write = |file: &str, text: &str| fs::write(root.join(file), text).unwrap();
+        write("Cargo.toml", MEMBERS);
+        write(
+            "aoc/Cargo.toml",
+            "[dependencies]\naoc-common = { path = \"../aoc-common\" }\naoc01 = { path = \"../aoc01\" }\nclap = \"4.5\"\n",
+        );
+        write(
+            "aoc/src/days.rs",
+            "pub static DAYS: &[&dyn DynSolution] = &[\n    &aoc01::Day01,\n    &aoc03::Day03,\n];\n",
+        );
+
+        let written = create(&root, 18, Some("RAM Run"), Some("5,4\n")).unwrap();
+        assert_eq!(written.len(), 11);
+        let read = |file: &str| fs::read_to_string(root.join(file)).unwrap();
+        assert!(read("aoc18/src/lib.rs").starts_with("//! Day 18: RAM Run.\n"));
+        assert!(read("aoc18/src/lib.rs").contains("include_str!(\"../18-input.txt\")"));
+        assert_eq!(read("aoc18/18-input.txt"), "5,4\n");
+        assert!(read("Cargo.toml").contains("    \"aoc18\",\n"));
+        assert!(read("aoc/Cargo.toml").contains("aoc18 = { path = \"../aoc18\" }\n"));
+        assert!(read("aoc/src/days.rs").contains("    &aoc18::Day18,\n"));
+
+        assert!(create(&root, 18, None, None).is_err());
+        create(&root, 19, None, None).unwrap();
+        assert!(read("aoc19/19-input.txt").contains("aoc fetch 19"));
+        // A registered day without its crate directory is still refused
+        assert!(create(&root, 3, None, None).is_err());
+        assert!(!root.join("aoc03").exists());
+        fs::remove_dir_all(root).unwrap();
+    }
+}
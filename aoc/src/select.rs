@@ -0,0 +1,80 @@
+use std::str::FromStr;
+
+/// Which days to run: `all`, a single day, a range `3-7`, or a comma list of
+/// either (`1,4-6,12`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DaySelection {
+    All,
+    Days(Vec<u8>),
+}
+
+impl DaySelection {
+    pub fn contains(&self, day: u8) -> bool {
+        match self {
+            DaySelection::All => true,
+            DaySelection::Days(days) => days.contains(&day),
+        }
+    }
+}
+
+impl FromStr for DaySelection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("all") {
+            return Ok(DaySelection::All);
+        }
+
+        let mut days = Vec::new();
+        for item in s.split(',') {
+            let item = item.trim();
+            let (first, last) = match item.split_once('-') {
+                Some((first, last)) => (parse_day(first)?, parse_day(last)?),
+                None => (parse_day(item)?, parse_day(item)?),
+            };
+            if first > last {
+                return Err(format!("Empty day range: {}", item));
+            }
+            days.extend(first..=last);
+        }
+
+        days.sort_unstable();
+        days.dedup();
+        Ok(DaySelection::Days(days))
+    }
+}
+
+fn parse_day(s: &str) -> Result<u8, String> {
+    match s.trim().parse::<u8>() {
+        Ok(day @ 1..=25) => Ok(day),
+        _ => Err(format!("Invalid day: {:?} (expected 1-25)", s)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_all() {
+        assert_eq!("all".parse(), Ok(DaySelection::All));
+    }
+
+    #[test]
+    fn test_single_day() {
+        assert_eq!("12".parse(), Ok(DaySelection::Days(vec![12])));
+    }
+
+    #[test]
+    fn test_ranges_and_lists() {
+        assert_eq!("5,1-3,2".parse(), Ok(DaySelection::Days(vec![1, 2, 3, 5])));
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert!("0".parse::<DaySelection>().is_err());
+        assert!("26".parse::<DaySelection>().is_err());
+        assert!("7-3".parse::<DaySelection>().is_err());
+        assert!("x".parse::<DaySelection>().is_err());
+    }
+}
@@ -0,0 +1,21 @@
+//! Loading and saving the TOML files the runner keeps at the workspace root.
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Loads a file, treating a missing one as empty.
+pub fn load<T: DeserializeOwned + Default>(path: &Path) -> Result<T, String> {
+    match fs::read_to_string(path) {
+        Ok(text) => toml::from_str(&text).map_err(|err| format!("{}: {}", path.display(), err)),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(T::default()),
+        Err(err) => Err(format!("{}: {}", path.display(), err)),
+    }
+}
+
+pub fn save<T: Serialize>(value: &T, path: &Path) -> Result<(), String> {
+    let text = toml::to_string(value).map_err(|err| err.to_string())?;
+    fs::write(path, text).map_err(|err| format!("{}: {}", path.display(), err))
+}
@@ -0,0 +1,431 @@
+//! Running days against generated inputs that are much larger or stranger
+//! than the examples, to find the panics and blow-ups that only show at
+//! scale. Every case is a (size, seed) pair, so a failure can be reproduced
+//! with `aoc generate`.
+//!
+//! Overflow checks only run in debug builds, and never cover `as` casts, so
+//! an overflow can give a wrong answer without a panic. Days with a
+//! [`reference`](aoc_common::Solution::reference) answer have every answer
+//! checked against it, which catches those too.
+
+use aoc_common::{par, Answer, DynSolution, ParseError, Part, Run};
+use std::cell::RefCell;
+use std::fmt;
+use std::ops::Range;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// One day run on one generated input.
+#[derive(Debug)]
+pub struct Case {
+    pub day: u8,
+    pub size: usize,
+    pub seed: u64,
+    /// Length of the generated input in bytes, 0 if it wasn't generated.
+    pub bytes: usize,
+    pub time: Duration,
+    pub outcome: Outcome,
+}
+
+#[derive(Debug)]
+pub enum Outcome {
+    Ok,
+    /// The generated input was rejected, which is a bug in either the
+    /// generator or the parser.
+    Parse(ParseError),
+    Panic(String),
+    /// The day's answer to a part differs from its reference answer.
+    Wrong {
+        part: Part,
+        answer: Answer,
+        expected: Answer,
+    },
+    Timeout,
+    NoGenerator,
+}
+
+impl Outcome {
+    pub fn is_failure(&self) -> bool {
+        !matches!(self, Outcome::Ok | Outcome::NoGenerator)
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Ok => write!(f, "ok"),
+            Outcome::Parse(_) => write!(f, "PARSE"),
+            Outcome::Panic(_) => write!(f, "PANIC"),
+            Outcome::Wrong { .. } => write!(f, "WRONG"),
+            Outcome::Timeout => write!(f, "TIMEOUT"),
+            Outcome::NoGenerator => write!(f, "-"),
+        }
+    }
+}
+
+thread_local! {
+    // Where and why the current thread last panicked, kept by the hook
+    // because the payload `catch_unwind` returns has no location
+    static LAST_PANIC: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Runs `parts` of every day on inputs of each size, once per seed, handing
+/// each case to `each` as it finishes. A day that times out isn't tried on
+/// larger inputs.
+///
+/// Panics are caught and reported rather than printed. A case that times
+/// out is abandoned, but its thread keeps running until the process exits.
+/// Every case has a thread pool of its own, so an abandoned one can't keep
+/// the `par_iter()` calls of the cases after it waiting.
+pub fn run(
+    solutions: &[&'static dyn DynSolution],
+    sizes: &[usize],
+    seeds: Range<u64>,
+    parts: &[Part],
+    timeout: Duration,
+    mut each: impl FnMut(Case),
+) {
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|info| {
+        let message = panic_message(info.payload());
+        let message = match info.location() {
+            Some(location) => format!("{} at {}", message, location),
+            None => message,
+        };
+        LAST_PANIC.with(|last| *last.borrow_mut() = Some(message));
+    }));
+
+    for &solution in solutions {
+        let day = solution.day();
+        if solution.generate(0, 1).is_none() {
+            each(Case {
+                day,
+                size: 0,
+                seed: 0,
+                bytes: 0,
+                time: Duration::ZERO,
+                outcome: Outcome::NoGenerator,
+            });
+            continue;
+        }
+
+        'sizes: for &size in sizes {
+            for seed in seeds.clone() {
+                let case = run_case(solution, size, seed, parts, timeout);
+                let timed_out = matches!(case.outcome, Outcome::Timeout);
+                each(case);
+                if timed_out {
+                    break 'sizes;
+                }
+            }
+        }
+    }
+
+    panic::set_hook(hook);
+}
+
+fn run_case(
+    solution: &'static dyn DynSolution,
+    size: usize,
+    seed: u64,
+    parts: &[Part],
+    timeout: Duration,
+) -> Case {
+    let (sender, receiver) = mpsc::channel();
+    let parts = parts.to_vec();
+    let threads = par::threads();
+    thread::spawn(move || {
+        // The case runs on one of the pool's threads, so the panic is
+        // caught and looked up there
+        let result = par::with_threads(threads, || {
+            let result = panic::catch_unwind(AssertUnwindSafe(|| {
+                let input = solution.generate(seed, size).unwrap_or_default();
+                let start = Instant::now();
+                let run = solution.run(&input, &parts);
+                let time = start.elapsed();
+                let outcome = match run {
+                    Ok(run) => check(solution, &input, run),
+                    Err(err) => Outcome::Parse(err),
+                };
+                (input.len(), time, outcome)
+            }));
+            result.map_err(|payload| {
+                LAST_PANIC
+                    .with(|last| last.borrow_mut().take())
+                    .unwrap_or_else(|| panic_message(&*payload))
+            })
+        });
+        // The receiver is gone if the case timed out
+        let _ = sender.send(result);
+    });
+
+    let (bytes, time, outcome) = match receiver.recv_timeout(timeout) {
+        Ok(Ok((bytes, time, outcome))) => (bytes, time, outcome),
+        Ok(Err(message)) => (0, Duration::ZERO, Outcome::Panic(message)),
+        Err(_) => (0, timeout, Outcome::Timeout),
+    };
+    Case {
+        day: solution.day(),
+        size,
+        seed,
+        bytes,
+        time,
+        outcome,
+    }
+}
+
+// The first part whose answer isn't the day's reference answer
+fn check(solution: &dyn DynSolution, input: &str, run: Run) -> Outcome {
+    for part in run.parts {
+        if let Some(expected) = solution.reference(input, part.part) {
+            if part.answer != expected {
+                return Outcome::Wrong {
+                    part: part.part,
+                    answer: part.answer,
+                    expected,
+                };
+            }
+        }
+    }
+    Outcome::Ok
+}
+
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "panicked".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{Rng, Solution, Source};
+
+    /// Sums the input, panicking on a zero and hanging on a nine.
+    struct Fragile;
+
+    impl Solution for Fragile {
+        const DAY: u8 = 0;
+        const INPUT: &'static str = "";
+        type Input = Vec<i64>;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            let source = Source::new(input)?;
+            source.numbers(input.trim_end(), ",")
+        }
+
+        fn part1(input: &Self::Input) -> Answer {
+            assert!(!input.contains(&0), "zero");
+            input.iter().sum::<i64>().into()
+        }
+
+        fn part2(input: &Self::Input) -> Answer {
+            if input.contains(&9) {
+                thread::sleep(Duration::from_secs(5));
+            }
+            Answer::Unsolved
+        }
+
+        fn generate(_rng: &mut Rng, size: usize) -> Option<String> {
+            Some(match size {
+                1 => "1,2\n".to_string(),
+                2 => "3,0\n".to_string(),
+                3 => "4,x\n".to_string(),
+                _ => "9\n".to_string(),
+            })
+        }
+    }
+
+    #[test]
+    fn test_outcomes() {
+        let mut cases = Vec::new();
+        let timeout = Duration::from_millis(200);
+        run(
+            &[&Fragile],
+            &[1, 2, 3, 4, 5],
+            0..1,
+            &Part::BOTH,
+            timeout,
+            |case| cases.push(case),
+        );
+
+        let sizes: Vec<usize> = cases.iter().map(|case| case.size).collect();
+        // Size 5 isn't tried after size 4 times out
+        assert_eq!(sizes, [1, 2, 3, 4]);
+        assert!(matches!(cases[0].outcome, Outcome::Ok));
+        assert!(
+            matches!(&cases[1].outcome, Outcome::Panic(message) if message.starts_with("zero at "))
+        );
+        assert!(matches!(cases[2].outcome, Outcome::Parse(_)));
+        assert!(matches!(cases[3].outcome, Outcome::Timeout));
+    }
+
+    /// Keeps every thread of its pool asleep on a single number, and
+    /// otherwise sums the input in parallel.
+    struct Hog;
+
+    impl Solution for Hog {
+        const DAY: u8 = 0;
+        const INPUT: &'static str = "";
+        type Input = Vec<u64>;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            let source = Source::new(input)?;
+            source.numbers(input.trim_end(), ",")
+        }
+
+        fn part1(input: &Self::Input) -> Answer {
+            use aoc_common::par::prelude::*;
+            if input.len() == 1 {
+                let busy = vec![0; 2 * par::threads()];
+                busy.par_iter()
+                    .for_each(|_| thread::sleep(Duration::from_secs(30)));
+            }
+            input.par_iter().sum::<u64>().into()
+        }
+
+        fn part2(_input: &Self::Input) -> Answer {
+            Answer::Unsolved
+        }
+
+        fn generate(_rng: &mut Rng, size: usize) -> Option<String> {
+            let numbers: Vec<String> = (0..size).map(|n| n.to_string()).collect();
+            Some(numbers.join(",") + "\n")
+        }
+    }
+
+    #[test]
+    fn test_timeouts_leave_the_pool_free() {
+        let mut outcomes = Vec::new();
+        let timeout = Duration::from_millis(500);
+        run(&[&Hog], &[1], 0..1, &Part::BOTH, timeout, |case| {
+            outcomes.push(case.outcome)
+        });
+        run(&[&Hog], &[1000], 0..3, &Part::BOTH, timeout, |case| {
+            outcomes.push(case.outcome)
+        });
+        assert!(
+            matches!(
+                outcomes[..],
+                [Outcome::Timeout, Outcome::Ok, Outcome::Ok, Outcome::Ok]
+            ),
+            "{:?}",
+            outcomes
+        );
+    }
+
+    /// Sums the input in an `i16`, which wraps without a panic.
+    struct Narrow;
+
+    impl Solution for Narrow {
+        const DAY: u8 = 0;
+        const INPUT: &'static str = "";
+        type Input = Vec<i64>;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            let source = Source::new(input)?;
+            source.numbers(input.trim_end(), ",")
+        }
+
+        fn part1(input: &Self::Input) -> Answer {
+            input
+                .iter()
+                .map(|&n| n as i16)
+                .fold(0, i16::wrapping_add)
+                .into()
+        }
+
+        fn part2(_input: &Self::Input) -> Answer {
+            Answer::Unsolved
+        }
+
+        fn generate(_rng: &mut Rng, size: usize) -> Option<String> {
+            Some(vec!["1000"; size].join(",") + "\n")
+        }
+
+        fn reference(input: &Self::Input, part: Part) -> Option<Answer> {
+            (part == Part::One).then(|| input.iter().sum::<i64>().into())
+        }
+    }
+
+    #[test]
+    fn test_wrong_answers() {
+        let mut cases = Vec::new();
+        let timeout = Duration::from_secs(10);
+        run(&[&Narrow], &[10, 100], 0..1, &Part::BOTH, timeout, |case| {
+            cases.push(case)
+        });
+
+        assert!(matches!(cases[0].outcome, Outcome::Ok));
+        let Outcome::Wrong {
+            part,
+            answer,
+            expected,
+        } = &cases[1].outcome
+        else {
+            panic!("expected a wrong answer, got {:?}", cases[1].outcome);
+        };
+        assert_eq!(*part, Part::One);
+        assert_eq!(*answer, Answer::Number(100_000 - 131_072));
+        assert_eq!(*expected, Answer::Number(100_000));
+    }
+
+    #[test]
+    fn test_answers_past_i16() {
+        // Day 2 with more safe reports than an i16 can count, and day 9 with
+        // 35,000 files. Day 9's part two is quadratic, too slow for a test at
+        // this size
+        let day02 = crate::days::find(2).unwrap();
+        let safe = day02.reference(&day02.generate(1, 70_000).unwrap(), Part::One);
+        assert!(matches!(safe, Some(Answer::Number(n)) if n > i16::MAX.into()));
+
+        let cases: [(u8, &[Part]); 2] = [(2, &Part::BOTH), (9, &[Part::One])];
+        for (day, parts) in cases {
+            let solution = crate::days::find(day).unwrap();
+            let mut outcomes = Vec::new();
+            let timeout = Duration::from_secs(60);
+            run(&[solution], &[70_000], 1..2, parts, timeout, |case| {
+                outcomes.push(case.outcome)
+            });
+            assert!(
+                matches!(outcomes[..], [Outcome::Ok]),
+                "day {}: {:?}",
+                day,
+                outcomes
+            );
+        }
+    }
+
+    #[test]
+    fn test_every_day_generates_valid_inputs() {
+        for solution in crate::days::DAYS {
+            for seed in 0..3 {
+                let input = solution
+                    .generate(seed, 8)
+                    .unwrap_or_else(|| panic!("day {} has no generator", solution.day()));
+                if let Err(err) = solution.run(&input, &[]) {
+                    panic!("day {} seed {}: {}\n{}", solution.day(), seed, err, input);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_every_day_ignores_trailing_blank_lines() {
+        for solution in crate::days::DAYS {
+            let input = solution.generate(0, 8).unwrap();
+            for blank in ["\n", "\n\n", "\r\n\r\n", "\n \n"] {
+                let padded = format!("{}{}", input, blank);
+                let result = solution.run(&padded, &[]);
+                assert!(result.is_ok(), "day {} with {:?}", solution.day(), blank);
+                assert!(solution.lint(&padded).is_empty());
+            }
+        }
+    }
+}
@@ -0,0 +1,161 @@
+use crate::manifest::Manifest;
+use aoc_common::{Answer, Part, Run};
+use serde::Serialize;
+use std::fmt;
+use std::time::Duration;
+
+/// Outcome of comparing one part's answer with the manifest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Ok,
+    Mismatch,
+    /// The manifest has no answer for this input yet.
+    Missing,
+    /// The part has no solution and nothing is recorded for it.
+    Unsolved,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            Status::Ok => "ok",
+            Status::Mismatch => "MISMATCH",
+            Status::Missing => "missing",
+            Status::Unsolved => "unsolved",
+        };
+        // Pad through the formatter so table widths apply
+        f.pad(label)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Check {
+    pub day: u8,
+    pub part: Part,
+    pub answer: Answer,
+    pub expected: Option<String>,
+    pub time: Duration,
+    pub status: Status,
+}
+
+pub fn check(run: &Run, manifest: &Manifest, input_hash: &str) -> Vec<Check> {
+    run.parts
+        .iter()
+        .map(|part| {
+            let expected = manifest
+                .get(run.day, part.part.number(), input_hash)
+                .map(str::to_string);
+            let status = match (&expected, &part.answer) {
+                (Some(expected), answer) if *expected == answer.to_string() => Status::Ok,
+                (Some(_), _) => Status::Mismatch,
+                (None, Answer::Unsolved) => Status::Unsolved,
+                (None, _) => Status::Missing,
+            };
+            Check {
+                day: run.day,
+                part: part.part,
+                answer: part.answer.clone(),
+                expected,
+                time: part.time,
+                status,
+            }
+        })
+        .collect()
+}
+
+pub fn print_table(checks: &[Check]) {
+    println!(
+        "{:<4} {:<5} {:<9} {:<20} {:<20} {:>12}",
+        "Day", "Part", "Status", "Answer", "Expected", "Time"
+    );
+    for check in checks {
+        println!(
+            "{:<4} {:<5} {:<9} {:<20} {:<20} {:>12.2?}",
+            format!("{:02}", check.day),
+            check.part.number(),
+            check.status,
+            check.answer.to_string(),
+            check.expected.as_deref().unwrap_or("-"),
+            check.time
+        );
+    }
+
+    let count = |status| checks.iter().filter(|c| c.status == status).count();
+    let total: Duration = checks.iter().map(|c| c.time).sum();
+    println!(
+        "\n{} ok, {} mismatched, {} missing, {} unsolved in {:.2?}",
+        count(Status::Ok),
+        count(Status::Mismatch),
+        count(Status::Missing),
+        count(Status::Unsolved),
+        total
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::PartRun;
+
+    fn run(answers: [Answer; 2]) -> Run {
+        Run {
+            day: 3,
+            parse_time: Duration::ZERO,
+            parts: Part::BOTH
+                .into_iter()
+                .zip(answers)
+                .map(|(part, answer)| PartRun {
+                    part,
+                    answer,
+                    time: Duration::ZERO,
+                })
+                .collect(),
+        }
+    }
+
+    fn statuses(run: &Run, manifest: &Manifest) -> Vec<Status> {
+        check(run, manifest, "beef")
+            .into_iter()
+            .map(|c| c.status)
+            .collect()
+    }
+
+    #[test]
+    fn test_ok_and_mismatch() {
+        let mut manifest = Manifest::default();
+        manifest.insert(3, 1, "beef", "161");
+        manifest.insert(3, 2, "beef", "48");
+        let run = run([Answer::Number(161), Answer::Number(47)]);
+        assert_eq!(statuses(&run, &manifest), [Status::Ok, Status::Mismatch]);
+    }
+
+    #[test]
+    fn test_missing_and_unsolved() {
+        let run = run([Answer::Number(161), Answer::Unsolved]);
+        let manifest = Manifest::default();
+        assert_eq!(
+            statuses(&run, &manifest),
+            [Status::Missing, Status::Unsolved]
+        );
+    }
+
+    #[test]
+    fn test_other_input_is_missing() {
+        let mut manifest = Manifest::default();
+        manifest.insert(3, 1, "cafe", "161");
+        let run = run([Answer::Number(161), Answer::Number(48)]);
+        assert_eq!(statuses(&run, &manifest), [Status::Missing; 2]);
+    }
+
+    #[test]
+    fn test_recorded_part_that_stops_solving_is_a_mismatch() {
+        let mut manifest = Manifest::default();
+        manifest.insert(3, 1, "beef", "161");
+        let run = run([Answer::Unsolved, Answer::Unsolved]);
+        assert_eq!(
+            statuses(&run, &manifest),
+            [Status::Mismatch, Status::Unsolved]
+        );
+    }
+}
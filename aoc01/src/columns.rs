@@ -0,0 +1,394 @@
+//! Reading any number of columns of IDs from a stream.
+//!
+//! The puzzle's input has two columns, but a reader gets any number of them
+//! and a row at a time, so even very long inputs never have to be in memory
+//! as text. The number of columns is given up front rather than guessed
+//! from the rows, so a corrupt first row can't turn every good row after it
+//! away. A row that doesn't fit is never dropped without a trace: strict
+//! reading fails with every rejected row, and lenient reading skips them
+//! but keeps the errors. Blank lines at the end of the input are not rows.
+
+use crate::{ListComparison, LocationId};
+use aoc_common::{ParseError, ParseErrorKind, Source};
+use std::fmt;
+use std::io::{self, BufRead};
+use std::str::FromStr;
+
+/// What to do with a row that isn't a full row of numbers.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Mode {
+    /// Fail, with every row that was rejected.
+    #[default]
+    Strict,
+    /// Skip the row, keeping the error in [`Columns::rejected`].
+    Lenient,
+}
+
+#[derive(Debug)]
+pub enum ReadError {
+    Io(io::Error),
+    /// The rows that were rejected, in order, or only an
+    /// [`ParseErrorKind::EmptyInput`] error if there were no rows at all.
+    Rejected(Vec<ParseError>),
+}
+
+impl ReadError {
+    /// The line numbers of the rejected rows.
+    pub fn lines(&self) -> Vec<usize> {
+        match self {
+            ReadError::Io(_) => Vec::new(),
+            ReadError::Rejected(errors) => errors.iter().map(|err| err.line).collect(),
+        }
+    }
+}
+
+impl fmt::Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const SHOWN: usize = 10;
+        match self {
+            ReadError::Io(err) => write!(f, "{}", err),
+            ReadError::Rejected(errors) => match errors.as_slice() {
+                [err] => write!(f, "{}", err),
+                _ => {
+                    write!(f, "{} rows rejected, at lines ", errors.len())?;
+                    let lines: Vec<String> = errors
+                        .iter()
+                        .take(SHOWN)
+                        .map(|err| err.line.to_string())
+                        .collect();
+                    write!(f, "{}", lines.join(", "))?;
+                    if errors.len() > SHOWN {
+                        write!(f, " and {} more", errors.len() - SHOWN)?;
+                    }
+                    Ok(())
+                }
+            },
+        }
+    }
+}
+
+impl std::error::Error for ReadError {}
+
+impl From<io::Error> for ReadError {
+    fn from(err: io::Error) -> Self {
+        ReadError::Io(err)
+    }
+}
+
+/// Columns of IDs, all the same length.
+#[derive(Debug, Clone)]
+pub struct Columns<T> {
+    columns: Vec<Vec<T>>,
+    rejected: Vec<ParseError>,
+}
+
+/// How one column compares with a later one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ColumnPair {
+    /// Indices of the two columns, from zero.
+    pub left: usize,
+    pub right: usize,
+    /// Sum of the distances between the sorted columns' pairs.
+    pub distance: u128,
+    /// Sum of each left ID times the number of times it appears on the
+    /// right.
+    pub similarity: i128,
+}
+
+impl<T: LocationId + FromStr> Columns<T> {
+    /// Reads rows of `width` whitespace-separated numbers a line at a time;
+    /// a row with another number of fields is rejected. Panics if `width`
+    /// is less than two, as there would be nothing to compare.
+    pub fn read(mut reader: impl BufRead, width: usize, mode: Mode) -> Result<Self, ReadError> {
+        assert!(width >= 2, "at least two columns are needed to compare");
+        let mut columns: Vec<Vec<T>> = vec![Vec::new(); width];
+        let mut rejected = Vec::new();
+        // Blank lines only count as rejected rows once a row follows them
+        let mut blank = Vec::new();
+        let mut buffer = Vec::new();
+        let mut row = Vec::new();
+
+        for number in 1.. {
+            buffer.clear();
+            if reader.read_until(b'\n', &mut buffer)? == 0 {
+                break;
+            }
+            let result = read_row(&buffer, width, &mut row).map_err(|mut err| {
+                err.line = number;
+                err
+            });
+            if buffer.trim_ascii().is_empty() {
+                blank.extend(result.err());
+                continue;
+            }
+            rejected.append(&mut blank);
+            match result {
+                Ok(()) => {
+                    for (column, id) in columns.iter_mut().zip(row.drain(..)) {
+                        column.push(id);
+                    }
+                }
+                Err(err) => rejected.push(err),
+            }
+        }
+
+        let empty = columns[0].is_empty();
+        if empty && rejected.is_empty() {
+            rejected.push(ParseError::empty());
+        }
+        if empty || (mode == Mode::Strict && !rejected.is_empty()) {
+            return Err(ReadError::Rejected(rejected));
+        }
+        Ok(Columns { columns, rejected })
+    }
+}
+
+impl<T: LocationId> Columns<T> {
+    pub fn width(&self) -> usize {
+        self.columns.len()
+    }
+
+    /// The number of rows read.
+    pub fn len(&self) -> usize {
+        self.columns[0].len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn column(&self, index: usize) -> &[T] {
+        &self.columns[index]
+    }
+
+    /// The rows skipped when reading leniently.
+    pub fn rejected(&self) -> &[ParseError] {
+        &self.rejected
+    }
+
+    /// Every metric for two of the columns, paired up row by row.
+    pub fn compare(&self, left: usize, right: usize) -> ListComparison<T> {
+        ListComparison::new(self.columns[left].clone(), self.columns[right].clone())
+    }
+
+    /// The distance and similarity for every pair of columns, first column
+    /// first. Each column is sorted once, and each pair is compared by
+    /// walking the sorted columns together.
+    pub fn pairwise(&self) -> Vec<ColumnPair> {
+        let sorted: Vec<Vec<T>> = self
+            .columns
+            .iter()
+            .map(|column| {
+                let mut column = column.clone();
+                column.sort_unstable();
+                column
+            })
+            .collect();
+
+        let mut pairs = Vec::new();
+        for left in 0..sorted.len() {
+            for right in left + 1..sorted.len() {
+                pairs.push(ColumnPair {
+                    left,
+                    right,
+                    distance: sorted[left]
+                        .iter()
+                        .zip(&sorted[right])
+                        .map(|(a, b)| a.wide().abs_diff(b.wide()))
+                        .sum(),
+                    similarity: sorted_similarity(&sorted[left], &sorted[right]),
+                });
+            }
+        }
+        pairs
+    }
+}
+
+// Parses one line into `row`, with errors on line 1
+fn read_row<T: FromStr>(line: &[u8], width: usize, row: &mut Vec<T>) -> Result<(), ParseError> {
+    row.clear();
+    let text = String::from_utf8_lossy(line);
+    let text = text.trim_end_matches(['\n', '\r']);
+    if let Some(at) = text.find(char::REPLACEMENT_CHARACTER) {
+        return Err(ParseError::at(
+            text,
+            &text[at..],
+            ParseErrorKind::InvalidChar(char::REPLACEMENT_CHARACTER),
+        ));
+    }
+    let Ok(source) = Source::new(text) else {
+        return Err(ParseError::at(
+            text,
+            text,
+            ParseErrorKind::Expected(numbers(width)),
+        ));
+    };
+    parse_row(&source, text, width, row)
+}
+
+/// Parses a row of `width` numbers into `row`.
+pub(crate) fn parse_row<T: FromStr>(
+    source: &Source,
+    line: &str,
+    width: usize,
+    row: &mut Vec<T>,
+) -> Result<(), ParseError> {
+    let mut fields = line.split_whitespace();
+    for field in fields.by_ref().take(width) {
+        row.push(source.number(field)?);
+    }
+    if row.len() < width {
+        return Err(source.expected(line.trim_end(), numbers(width)));
+    }
+    if let Some(extra) = fields.next() {
+        return Err(source.error(
+            extra,
+            ParseErrorKind::Expected("the end of the line".to_string()),
+        ));
+    }
+    Ok(())
+}
+
+fn numbers(count: usize) -> String {
+    match count {
+        2 => "two numbers".to_string(),
+        _ => format!("{} numbers", count),
+    }
+}
+
+// The similarity score of two sorted lists, a run of equal IDs at a time
+fn sorted_similarity<T: LocationId>(left: &[T], right: &[T]) -> i128 {
+    let (mut i, mut j) = (0, 0);
+    let mut score = 0;
+    while i < left.len() && j < right.len() {
+        match left[i].cmp(&right[j]) {
+            std::cmp::Ordering::Less => i += 1,
+            std::cmp::Ordering::Greater => j += 1,
+            std::cmp::Ordering::Equal => {
+                let id = left[i];
+                let count = |list: &[T], from: usize| {
+                    list[from..]
+                        .iter()
+                        .take_while(|&&other| other == id)
+                        .count()
+                };
+                let (a, b) = (count(left, i), count(right, j));
+                score += id.wide() * (a * b) as i128;
+                i += a;
+                j += b;
+            }
+        }
+    }
+    score
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const THREE: &str = "3 4 1\n4 3 1\n2 5 2\n1 3 2\n3 9 3\n3 3 3\n";
+
+    fn read<T: LocationId + FromStr>(
+        input: &str,
+        width: usize,
+        mode: Mode,
+    ) -> Result<Columns<T>, ReadError> {
+        Columns::read(input.as_bytes(), width, mode)
+    }
+
+    #[test]
+    fn test_pairwise() {
+        let columns = read::<i64>(THREE, 3, Mode::Strict).unwrap();
+        assert_eq!((columns.width(), columns.len()), (3, 6));
+        let pairs = columns.pairwise();
+        assert_eq!(pairs.len(), 3);
+        assert_eq!(
+            pairs[0],
+            ColumnPair {
+                left: 0,
+                right: 1,
+                distance: 11,
+                similarity: 31,
+            }
+        );
+        for pair in pairs {
+            let lists = columns.compare(pair.left, pair.right);
+            assert_eq!(pair.distance, lists.l1_distance());
+            assert_eq!(pair.similarity, lists.similarity());
+        }
+    }
+
+    #[test]
+    fn test_strict_reports_every_row() {
+        let input = "3 4\n4 x\n2 5\n1\n\n3 9 7\r\n3 3\r\n";
+        let err = read::<i32>(input, 2, Mode::Strict).unwrap_err();
+        assert_eq!(err.lines(), [2, 4, 5, 6]);
+        assert_eq!(err.to_string(), "4 rows rejected, at lines 2, 4, 5, 6");
+        let ReadError::Rejected(errors) = err else {
+            panic!("expected rejected rows");
+        };
+        assert_eq!((errors[0].line, errors[0].column), (2, 3));
+        assert_eq!((errors[1].line, errors[1].column), (4, 2));
+        assert_eq!((errors[3].line, errors[3].column), (6, 5));
+
+        let columns = read::<i32>(input, 2, Mode::Lenient).unwrap();
+        assert_eq!(columns.column(1), [4, 5, 3]);
+        assert_eq!(columns.rejected().len(), 4);
+    }
+
+    #[test]
+    fn test_corrupt_first_row() {
+        let input = "3 4 1
+4 3
+2 5
+1 3
+";
+        let columns = read::<i32>(input, 2, Mode::Lenient).unwrap();
+        assert_eq!(columns.column(0), [4, 2, 1]);
+        assert_eq!(columns.rejected()[0].line, 1);
+        assert_eq!(
+            read::<i32>(input, 2, Mode::Strict).unwrap_err().lines(),
+            [1]
+        );
+    }
+
+    #[test]
+    fn test_trailing_blank_lines() {
+        // Only the blank line with a row after it is rejected
+        let input = "3 4\n\n4 3\n\n  \r\n\n";
+        let err = read::<i32>(input, 2, Mode::Strict).unwrap_err();
+        assert_eq!(err.lines(), [2]);
+        let columns = read::<i32>(input, 2, Mode::Lenient).unwrap();
+        assert_eq!((columns.len(), columns.rejected().len()), (2, 1));
+
+        let columns = read::<i32>("3 4\n4 3\n\n  \r\n", 2, Mode::Strict).unwrap();
+        assert_eq!(columns.len(), 2);
+        let err = read::<i32>("\n\n", 2, Mode::Lenient).unwrap_err();
+        assert!(
+            matches!(&err, ReadError::Rejected(errors) if errors[0].kind == ParseErrorKind::EmptyInput)
+        );
+    }
+
+    #[test]
+    fn test_wide_values_and_bad_bytes() {
+        let input = format!("{} {}\n{} {}\n", i64::MIN, i64::MAX, i64::MAX, i64::MIN);
+        let columns = read::<i64>(&input, 2, Mode::Strict).unwrap();
+        assert_eq!(columns.pairwise()[0].distance, 0);
+        assert_eq!(columns.compare(0, 1).l1_distance(), 0);
+
+        let err = Columns::<i64>::read(&b"1 2\n3 \xff\n"[..], 2, Mode::Strict).unwrap_err();
+        assert_eq!(err.lines(), [2]);
+        assert!(read::<u8>("1 2\n3 256\n", 2, Mode::Strict).is_err());
+    }
+
+    #[test]
+    fn test_nothing_read() {
+        let err = read::<i32>("", 2, Mode::Strict).unwrap_err();
+        assert!(
+            matches!(&err, ReadError::Rejected(errors) if errors[0].kind == ParseErrorKind::EmptyInput)
+        );
+        // Leniently skipping every row leaves nothing to compare
+        let err = read::<i32>("1\nx y\n", 2, Mode::Lenient).unwrap_err();
+        assert_eq!(err.lines(), [1, 2]);
+    }
+}
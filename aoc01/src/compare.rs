@@ -0,0 +1,326 @@
+//! Ways of comparing the two lists of location IDs.
+//!
+//! The puzzle asks for the distance between the sorted lists and a
+//! similarity score, but the lists can be compared in plenty of other ways
+//! from the same parse. Sums are widened to 128 bits, so no choice of ID
+//! type can overflow them.
+//!
+//! The lists may differ in length. Only the metrics that pair rows up,
+//! the distances and the Kendall tau distance, need them the same length,
+//! and panic otherwise.
+
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+/// Integer types location IDs can be read as.
+pub trait LocationId: Copy + Ord + Hash + Debug {
+    fn wide(self) -> i128;
+}
+
+macro_rules! impl_location_id {
+    ($($t:ty),*) => {
+        $(
+            impl LocationId for $t {
+                fn wide(self) -> i128 {
+                    self as i128
+                }
+            }
+        )*
+    };
+}
+
+impl_location_id!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+/// The left and right lists, paired up row by row as they were read when
+/// they are the same length.
+#[derive(Debug, Clone)]
+pub struct ListComparison<T> {
+    left: Vec<T>,
+    right: Vec<T>,
+    left_sorted: Vec<T>,
+    right_sorted: Vec<T>,
+    left_counts: HashMap<T, usize>,
+    right_counts: HashMap<T, usize>,
+}
+
+/// One sorted pair's share of the distance between the lists.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DistanceTerm<T> {
+    pub left: T,
+    pub right: T,
+    pub distance: u128,
+}
+
+/// One left ID's share of the similarity score.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SimilarityTerm<T> {
+    pub id: T,
+    /// How often the ID appears in the right list.
+    pub count: usize,
+    pub score: i128,
+}
+
+impl<T: LocationId> ListComparison<T> {
+    pub fn new(left: Vec<T>, right: Vec<T>) -> Self {
+        let sorted = |list: &[T]| {
+            let mut list = list.to_vec();
+            list.sort_unstable();
+            list
+        };
+        ListComparison {
+            left_sorted: sorted(&left),
+            right_sorted: sorted(&right),
+            left_counts: counts(&left),
+            right_counts: counts(&right),
+            left,
+            right,
+        }
+    }
+
+    pub fn left(&self) -> &[T] {
+        &self.left
+    }
+
+    pub fn right(&self) -> &[T] {
+        &self.right
+    }
+
+    /// The number of rows, which is the length of the left list if the
+    /// lists differ.
+    pub fn len(&self) -> usize {
+        self.left.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.left.is_empty()
+    }
+
+    /// Whether the lists are the same length, so rows pair up.
+    pub fn is_paired(&self) -> bool {
+        self.left.len() == self.right.len()
+    }
+
+    fn assert_paired(&self) {
+        assert!(self.is_paired(), "lists must be the same length to pair up");
+    }
+
+    /// The lists sorted and paired smallest with smallest, with the distance
+    /// between each pair. Panics unless the lists are the same length.
+    pub fn distance_terms(&self) -> Vec<DistanceTerm<T>> {
+        self.assert_paired();
+        self.left_sorted
+            .iter()
+            .zip(&self.right_sorted)
+            .map(|(&left, &right)| DistanceTerm {
+                left,
+                right,
+                distance: left.wide().abs_diff(right.wide()),
+            })
+            .collect()
+    }
+
+    /// Sum of the distances between the sorted pairs, the part one answer.
+    pub fn l1_distance(&self) -> u128 {
+        self.distance_terms().iter().map(|term| term.distance).sum()
+    }
+
+    /// Euclidean distance between the sorted lists.
+    pub fn l2_distance(&self) -> f64 {
+        self.distance_terms()
+            .iter()
+            .map(|term| (term.distance as f64).powi(2))
+            .sum::<f64>()
+            .sqrt()
+    }
+
+    /// Each left ID, in list order, with how often it appears on the right
+    /// and the score that earns.
+    pub fn similarity_terms(&self) -> Vec<SimilarityTerm<T>> {
+        self.left
+            .iter()
+            .map(|&id| {
+                let count = self.right_counts.get(&id).copied().unwrap_or(0);
+                SimilarityTerm {
+                    id,
+                    count,
+                    score: id.wide() * count as i128,
+                }
+            })
+            .collect()
+    }
+
+    /// Sum of each left ID times the number of times it appears on the
+    /// right, the part two answer.
+    pub fn similarity(&self) -> i128 {
+        self.similarity_terms().iter().map(|term| term.score).sum()
+    }
+
+    /// Size of the multiset intersection: how many IDs the lists share,
+    /// counting repeats as often as both lists have them.
+    pub fn overlap(&self) -> usize {
+        self.left_counts
+            .iter()
+            .map(|(id, &count)| count.min(self.right_counts.get(id).copied().unwrap_or(0)))
+            .sum()
+    }
+
+    /// Multiset Jaccard index, the overlap over the size of the multiset
+    /// union: 1 when the lists hold the same IDs as often, 0 when they share
+    /// none. Two empty lists are the same.
+    pub fn jaccard(&self) -> f64 {
+        let overlap = self.overlap();
+        let union = self.left.len() + self.right.len() - overlap;
+        if union == 0 {
+            1.0
+        } else {
+            overlap as f64 / union as f64
+        }
+    }
+
+    /// Kendall tau distance between the columns: the number of pairs of rows
+    /// that the left list orders one way and the right list the other. Rows
+    /// tied in either list don't count. Panics unless the lists are the same
+    /// length.
+    pub fn kendall_tau_distance(&self) -> u64 {
+        self.assert_paired();
+        // Sorted by left and then right, a discordant pair is an inversion
+        // of the right values; rows tied on the left are in order
+        let mut rows: Vec<(T, T)> = self
+            .left
+            .iter()
+            .copied()
+            .zip(self.right.iter().copied())
+            .collect();
+        rows.sort_unstable();
+        let mut right: Vec<T> = rows.into_iter().map(|(_, right)| right).collect();
+        inversions(&mut right)
+    }
+}
+
+fn counts<T: LocationId>(list: &[T]) -> HashMap<T, usize> {
+    let mut counts = HashMap::new();
+    for &id in list {
+        *counts.entry(id).or_insert(0) += 1;
+    }
+    counts
+}
+
+// Pairs i < j with values[i] > values[j], counted by merge sort, which
+// leaves `values` sorted
+fn inversions<T: Ord + Copy>(values: &mut [T]) -> u64 {
+    if values.len() < 2 {
+        return 0;
+    }
+    let mid = values.len() / 2;
+    let mut count = inversions(&mut values[..mid]) + inversions(&mut values[mid..]);
+
+    let mut merged = Vec::with_capacity(values.len());
+    let (mut i, mut j) = (0, mid);
+    while i < mid && j < values.len() {
+        if values[j] < values[i] {
+            // Everything left in the first half is larger
+            count += (mid - i) as u64;
+            merged.push(values[j]);
+            j += 1;
+        } else {
+            merged.push(values[i]);
+            i += 1;
+        }
+    }
+    merged.extend_from_slice(&values[i..mid]);
+    merged.extend_from_slice(&values[j..]);
+    values.copy_from_slice(&merged);
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> ListComparison<i32> {
+        ListComparison::new(vec![3, 4, 2, 1, 3, 3], vec![4, 3, 5, 3, 9, 3])
+    }
+
+    #[test]
+    fn test_puzzle_metrics() {
+        let lists = example();
+        assert_eq!(lists.l1_distance(), 11);
+        assert_eq!(lists.similarity(), 31);
+        assert_eq!(
+            lists.distance_terms()[0],
+            DistanceTerm {
+                left: 1,
+                right: 3,
+                distance: 2
+            }
+        );
+        let scores: Vec<i128> = lists.similarity_terms().iter().map(|t| t.score).collect();
+        assert_eq!(scores, [9, 4, 0, 0, 9, 9]);
+    }
+
+    #[test]
+    fn test_other_metrics() {
+        let lists = example();
+        // Sorted distances 2, 1, 0, 1, 2, 5
+        assert!((lists.l2_distance() - 35_f64.sqrt()).abs() < 1e-12);
+        // {1, 2, 3, 3, 3, 4} and {3, 3, 3, 4, 5, 9} share 3, 3, 3 and 4
+        assert_eq!(lists.overlap(), 4);
+        assert!((lists.jaccard() - 0.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_kendall_tau_distance() {
+        let lists = example();
+        let naive = (0..lists.len())
+            .flat_map(|i| (i + 1..lists.len()).map(move |j| (i, j)))
+            .filter(|&(i, j)| {
+                let left = lists.left()[i].cmp(&lists.left()[j]);
+                let right = lists.right()[i].cmp(&lists.right()[j]);
+                left.is_ne() && right.is_ne() && left != right
+            })
+            .count();
+        assert_eq!(lists.kendall_tau_distance(), naive as u64);
+
+        let reversed = ListComparison::new(vec![1_u8, 2, 3, 4], vec![8, 7, 6, 5]);
+        assert_eq!(reversed.kendall_tau_distance(), 6);
+        assert_eq!(
+            ListComparison::new(vec![1, 2], vec![1, 2]).kendall_tau_distance(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_wide_types() {
+        let lists = ListComparison::new(vec![u64::MAX, 0], vec![0, u64::MAX]);
+        assert_eq!(lists.l1_distance(), 0);
+        assert_eq!(lists.similarity(), u64::MAX as i128);
+        let lists = ListComparison::new(vec![i64::MIN], vec![i64::MAX]);
+        assert_eq!(lists.l1_distance(), u64::MAX as u128);
+    }
+
+    #[test]
+    fn test_empty() {
+        let lists = ListComparison::<i32>::new(Vec::new(), Vec::new());
+        assert_eq!(lists.l1_distance(), 0);
+        assert_eq!(lists.jaccard(), 1.0);
+        assert_eq!(lists.kendall_tau_distance(), 0);
+    }
+
+    #[test]
+    fn test_uneven_lists() {
+        let lists = ListComparison::new(vec![3, 4, 2], vec![4, 3, 5, 3, 9, 3]);
+        assert!(!lists.is_paired());
+        assert_eq!(lists.similarity(), 13);
+        // {2, 3, 4} and {3, 3, 3, 4, 5, 9} share 3 and 4, out of 7
+        assert_eq!(lists.overlap(), 2);
+        assert!((lists.jaccard() - 2.0 / 7.0).abs() < 1e-12);
+        assert_eq!(ListComparison::new(vec![1], Vec::new()).jaccard(), 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "same length")]
+    fn test_uneven_lists_do_not_pair() {
+        ListComparison::new(vec![1, 2], vec![1]).l1_distance();
+    }
+}
@@ -0,0 +1,21 @@
+//! Random location lists.
+
+use aoc_common::Rng;
+
+/// `size` lines of two five-digit location IDs. About a third of the right
+/// list repeats IDs from the left so the similarity score isn't always zero.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let left: Vec<i64> = (0..size.max(1))
+        .map(|_| rng.range(10_000..=99_999))
+        .collect();
+    let mut out = String::new();
+    for &id in &left {
+        let other = if rng.chance(0.3) {
+            *rng.pick(&left)
+        } else {
+            rng.range(10_000..=99_999)
+        };
+        out.push_str(&format!("{}   {}\n", id, other));
+    }
+    out
+}
@@ -0,0 +1,431 @@
+//! Keeping the puzzle's two metrics up to date while IDs come and go.
+//!
+//! The similarity score only needs the counts of each ID, so an update
+//! changes it by the ID times its count in the other list.
+//!
+//! The distance between the sorted lists is harder, since one insertion
+//! re-pairs everything above it. But for lists of the same length it is
+//! also the area between their counting functions,
+//!
+//! ```text
+//! sum |left[i] - right[i]| = integral |#{left <= t} - #{right <= t}| dt
+//! ```
+//!
+//! and an update only adds or takes one from the difference for every `t`
+//! past the ID. Every ID from both lists is kept in order, right before left
+//! among equal IDs, cut into blocks of about `sqrt(n)`. Each block caches its
+//! share of the area for any difference at its start, so an update rebuilds
+//! one or two blocks and shifts the rest, in `O(sqrt(n))`.
+
+use crate::LocationId;
+use std::collections::HashMap;
+
+/// Two lists of location IDs that change one ID at a time, with their
+/// similarity score and sorted-pair distance kept current.
+#[derive(Debug, Clone)]
+pub struct IncrementalLists<T> {
+    left: HashMap<T, usize>,
+    right: HashMap<T, usize>,
+    left_len: usize,
+    right_len: usize,
+    similarity: i128,
+    blocks: Vec<Block>,
+    area: i128,
+}
+
+impl<T: LocationId> Default for IncrementalLists<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: LocationId> IncrementalLists<T> {
+    pub fn new() -> Self {
+        IncrementalLists {
+            left: HashMap::new(),
+            right: HashMap::new(),
+            left_len: 0,
+            right_len: 0,
+            similarity: 0,
+            blocks: Vec::new(),
+            area: 0,
+        }
+    }
+
+    /// Lists holding the given IDs.
+    pub fn from_lists(left: &[T], right: &[T]) -> Self {
+        let mut lists = Self::new();
+        for &id in left {
+            lists.insert_left(id);
+        }
+        for &id in right {
+            lists.insert_right(id);
+        }
+        lists
+    }
+
+    pub fn left_len(&self) -> usize {
+        self.left_len
+    }
+
+    pub fn right_len(&self) -> usize {
+        self.right_len
+    }
+
+    /// How often `id` is in the left list.
+    pub fn left_count(&self, id: T) -> usize {
+        self.left.get(&id).copied().unwrap_or(0)
+    }
+
+    /// How often `id` is in the right list.
+    pub fn right_count(&self, id: T) -> usize {
+        self.right.get(&id).copied().unwrap_or(0)
+    }
+
+    /// Sum of each left ID times the number of times it appears on the
+    /// right.
+    pub fn similarity(&self) -> i128 {
+        self.similarity
+    }
+
+    /// Sum of the distances between the sorted lists' pairs, or `None` while
+    /// the lists differ in length and can't be paired up.
+    pub fn distance(&self) -> Option<u128> {
+        (self.left_len == self.right_len).then_some(self.area.unsigned_abs())
+    }
+
+    pub fn insert_left(&mut self, id: T) {
+        self.similarity += id.wide() * self.right_count(id) as i128;
+        *self.left.entry(id).or_insert(0) += 1;
+        self.left_len += 1;
+        self.insert(id.wide(), 1);
+    }
+
+    pub fn insert_right(&mut self, id: T) {
+        self.similarity += id.wide() * self.left_count(id) as i128;
+        *self.right.entry(id).or_insert(0) += 1;
+        self.right_len += 1;
+        self.insert(id.wide(), -1);
+    }
+
+    /// Removes one `id` from the left list, returning whether it was there.
+    pub fn remove_left(&mut self, id: T) -> bool {
+        if !decrement(&mut self.left, id) {
+            return false;
+        }
+        self.similarity -= id.wide() * self.right_count(id) as i128;
+        self.left_len -= 1;
+        self.remove(id.wide(), 1);
+        true
+    }
+
+    /// Removes one `id` from the right list, returning whether it was there.
+    pub fn remove_right(&mut self, id: T) -> bool {
+        if !decrement(&mut self.right, id) {
+            return false;
+        }
+        self.similarity -= id.wide() * self.left_count(id) as i128;
+        self.right_len -= 1;
+        self.remove(id.wide(), -1);
+        true
+    }
+
+    // Blocks grow to twice this before they are split
+    fn block_size(&self) -> usize {
+        (((self.left_len + self.right_len) as f64).sqrt() as usize).max(16)
+    }
+
+    // Equal IDs have no gap between them, so ordering them by side as well
+    // changes no area, and lets a token be found by binary search
+    fn insert(&mut self, value: i128, side: i64) {
+        let token = (value, side);
+        if self.blocks.is_empty() {
+            self.blocks.push(Block::default());
+        }
+        // The first block with a larger token, so equal tokens stay together
+        let k = self
+            .blocks
+            .partition_point(|block| block.last() <= token)
+            .min(self.blocks.len() - 1);
+        let block = &mut self.blocks[k];
+        let at = block.tokens.partition_point(|&other| other <= token);
+        block.tokens.insert(at, token);
+
+        self.rebuild(k);
+        if at == 0 && k > 0 {
+            // The block before now ends closer to its neighbour
+            self.rebuild(k - 1);
+        }
+        self.shift_from(k + 1, side);
+
+        if self.blocks[k].tokens.len() > 2 * self.block_size() {
+            self.split(k);
+        }
+    }
+
+    fn remove(&mut self, value: i128, side: i64) {
+        // Equal tokens can straddle blocks, but the first block that reaches
+        // the token holds one of them, as the count said
+        let token = (value, side);
+        let k = self.blocks.partition_point(|block| block.last() < token);
+        let at = self.blocks[k]
+            .tokens
+            .binary_search(&token)
+            .expect("counted ID is in a block");
+        self.blocks[k].tokens.remove(at);
+
+        if self.blocks[k].tokens.is_empty() {
+            self.area -= self.blocks[k].area;
+            self.blocks.remove(k);
+            if k > 0 {
+                self.rebuild(k - 1);
+            }
+            // The blocks that followed the emptied one now start at `k`
+            self.shift_from(k, -side);
+            return;
+        }
+        self.rebuild(k);
+        if at == 0 && k > 0 {
+            self.rebuild(k - 1);
+        }
+        self.shift_from(k + 1, -side);
+
+        // Fold a block that has shrunk a lot into the next one
+        let size = self.block_size();
+        if k + 1 < self.blocks.len()
+            && self.blocks[k].tokens.len() < size / 2
+            && self.blocks[k].tokens.len() + self.blocks[k + 1].tokens.len() <= 2 * size
+        {
+            let next = self.blocks.remove(k + 1);
+            self.area -= next.area;
+            self.blocks[k].tokens.extend(next.tokens);
+            self.rebuild(k);
+        }
+    }
+
+    // Recomputes block `k` from its tokens, keeping the total area in step
+    fn rebuild(&mut self, k: usize) {
+        let next = self.blocks.get(k + 1).map(|block| block.tokens[0].0);
+        let block = &mut self.blocks[k];
+        self.area -= block.area;
+        block.rebuild(next);
+        self.area += block.area;
+    }
+
+    // Moves the difference at the start of block `k` and every one after it
+    // by `delta`
+    fn shift_from(&mut self, k: usize, delta: i64) {
+        for block in &mut self.blocks[k..] {
+            self.area -= block.area;
+            block.start += delta;
+            block.area = block.area_from(block.start);
+            self.area += block.area;
+        }
+    }
+
+    fn split(&mut self, k: usize) {
+        let block = &mut self.blocks[k];
+        let tokens = block.tokens.split_off(block.tokens.len() / 2);
+        let start = block.start + block.tokens.iter().map(|&(_, side)| side).sum::<i64>();
+        self.blocks.insert(
+            k + 1,
+            Block {
+                tokens,
+                start,
+                ..Block::default()
+            },
+        );
+        self.rebuild(k + 1);
+        self.rebuild(k);
+    }
+}
+
+fn decrement<T: LocationId>(counts: &mut HashMap<T, usize>, id: T) -> bool {
+    match counts.get_mut(&id) {
+        Some(count) => {
+            *count -= 1;
+            if *count == 0 {
+                counts.remove(&id);
+            }
+            true
+        }
+        None => false,
+    }
+}
+
+/// A run of IDs from both lists in order, each +1 for the left list and -1
+/// for the right, so the running total is the difference between the
+/// lists' counting functions.
+#[derive(Debug, Clone, Default)]
+struct Block {
+    tokens: Vec<(i128, i64)>,
+    /// The difference before the first token.
+    start: i64,
+    // The smallest running total within the block, relative to `start`
+    low: i64,
+    // Totals of the gap to the next ID, and of the gap times the running
+    // total, over the tokens whose running total is below each value from
+    // `low` up
+    gaps: Vec<i128>,
+    moments: Vec<i128>,
+    /// This block's share of the area, given `start`.
+    area: i128,
+}
+
+impl Block {
+    fn last(&self) -> (i128, i64) {
+        self.tokens.last().copied().unwrap_or((i128::MIN, i64::MIN))
+    }
+
+    // `next` is the first ID of the following block. The last ID overall
+    // has no gap after it: the difference past it is zero when the lists
+    // are the same length.
+    fn rebuild(&mut self, next: Option<i128>) {
+        let mut total = 0;
+        let mut by_total: Vec<(i64, i128)> = Vec::with_capacity(self.tokens.len());
+        for (i, &(value, side)) in self.tokens.iter().enumerate() {
+            total += side;
+            let following = self.tokens.get(i + 1).map(|&(v, _)| v).or(next);
+            by_total.push((total, following.map_or(0, |v| v - value)));
+        }
+
+        // Each step is one up or down, so the totals cover a short range
+        self.low = by_total.iter().map(|&(total, _)| total).min().unwrap_or(0);
+        let high = by_total.iter().map(|&(total, _)| total).max().unwrap_or(0);
+        let mut gaps = vec![0; (high - self.low + 1) as usize];
+        for &(total, gap) in &by_total {
+            gaps[(total - self.low) as usize] += gap;
+        }
+
+        self.gaps = vec![0];
+        self.moments = vec![0];
+        for (i, gap) in gaps.into_iter().enumerate() {
+            let total = self.low + i as i64;
+            self.gaps.push(self.gaps[i] + gap);
+            self.moments.push(self.moments[i] + gap * total as i128);
+        }
+        self.area = self.area_from(self.start);
+    }
+
+    // Sum of gap * |start + total| over the tokens
+    fn area_from(&self, start: i64) -> i128 {
+        let count = self.gaps.len() - 1;
+        let below = (-start - self.low).clamp(0, count as i64) as usize;
+        let (gaps, moments) = (self.gaps[count], self.moments[count]);
+        let start = start as i128;
+        start * (gaps - 2 * self.gaps[below]) + (moments - 2 * self.moments[below])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ListComparison;
+    use aoc_common::Rng;
+
+    #[test]
+    fn test_example() {
+        let mut lists = IncrementalLists::from_lists(&[3, 4, 2, 1, 3, 3], &[4, 3, 5, 3, 9, 3]);
+        assert_eq!(lists.distance(), Some(11));
+        assert_eq!(lists.similarity(), 31);
+
+        lists.insert_left(9);
+        assert_eq!(lists.distance(), None);
+        assert_eq!(lists.similarity(), 40);
+        assert!(lists.remove_right(3));
+        assert!(!lists.remove_right(7));
+        // 1 2 3 3 3 4 9 against 3 3 4 5 9
+        assert_eq!(lists.left_len(), 7);
+        assert_eq!(lists.right_count(3), 2);
+        assert_eq!(lists.similarity(), 31);
+        lists.insert_right(1);
+        lists.insert_right(2);
+        assert_eq!(lists.distance(), Some(2));
+    }
+
+    #[test]
+    fn test_matches_rebuilding_from_scratch() {
+        let mut rng = Rng::new(7);
+        let mut lists = IncrementalLists::new();
+        let (mut left, mut right): (Vec<i64>, Vec<i64>) = (Vec::new(), Vec::new());
+
+        for step in 0..3000 {
+            // Mostly growing, with small IDs so there are plenty of repeats
+            let id = rng.range(-50..=50);
+            let (list, is_left) = if rng.chance(0.5) {
+                (&mut left, true)
+            } else {
+                (&mut right, false)
+            };
+            if rng.chance(0.35) && !list.is_empty() {
+                let id = list.swap_remove(rng.below(list.len()));
+                assert!(if is_left {
+                    lists.remove_left(id)
+                } else {
+                    lists.remove_right(id)
+                });
+            } else {
+                list.push(id);
+                if is_left {
+                    lists.insert_left(id)
+                } else {
+                    lists.insert_right(id)
+                }
+            }
+
+            let expected = ListComparison::new(left.clone(), right.clone());
+            assert_eq!(lists.similarity(), expected.similarity(), "step {}", step);
+            if expected.is_paired() {
+                assert_eq!(
+                    lists.distance(),
+                    Some(expected.l1_distance()),
+                    "step {}",
+                    step
+                );
+            } else {
+                assert_eq!(lists.distance(), None);
+            }
+        }
+        assert!(lists.blocks.len() > 1);
+    }
+
+    #[test]
+    fn test_many_equal_ids() {
+        // Thousands of one ID on both sides, spread over many blocks, and the
+        // lowest IDs removed so the first blocks empty
+        let left: Vec<i32> = (0..3000).map(|i| if i % 3 == 0 { i } else { 7 }).collect();
+        let right: Vec<i32> = (0..3000).map(|i| if i % 5 == 0 { -i } else { 7 }).collect();
+        let mut lists = IncrementalLists::from_lists(&left, &right);
+        assert!(lists.blocks.len() > 1);
+        for i in 0..600 {
+            assert!(lists.remove_right(-5 * i));
+            assert!(lists.remove_left(7));
+        }
+        for _ in 0..1400 {
+            assert!(lists.remove_right(7));
+            assert!(lists.remove_left(7));
+        }
+
+        let without_sevens = |list: Vec<i32>, sevens: usize| -> Vec<i32> {
+            let kept = list.iter().filter(|&&id| id == 7).count() - sevens;
+            let mut list: Vec<i32> = list.into_iter().filter(|&id| id != 7).collect();
+            list.extend(std::iter::repeat_n(7, kept));
+            list
+        };
+        let right: Vec<i32> = right.into_iter().filter(|&id| id == 7).collect();
+        let expected = ListComparison::new(without_sevens(left, 2000), without_sevens(right, 1400));
+        assert_eq!(lists.distance(), Some(expected.l1_distance()));
+        assert_eq!(lists.similarity(), expected.similarity());
+    }
+
+    #[test]
+    fn test_emptied() {
+        let mut lists = IncrementalLists::from_lists(&[5_u8], &[1]);
+        assert_eq!(lists.distance(), Some(4));
+        assert!(lists.remove_left(5));
+        assert!(lists.remove_right(1));
+        assert_eq!(lists.distance(), Some(0));
+        assert_eq!(lists.similarity(), 0);
+        assert!(lists.blocks.is_empty());
+    }
+}
@@ -0,0 +1,151 @@
+//! Day 1: Historian Hysteria.
+//!
+//! Two columns of location IDs are compared by the distance between their
+//! sorted pairs and by how often each left ID appears on the right.
+//! [`parse_lists`] reads the columns as IDs of any integer type into a
+//! [`ListComparison`], which offers those two metrics and several more.
+//! [`process_lists`] and [`similarity_score`] are the puzzle's two for
+//! `i32` lists. [`IncrementalLists`] keeps both up to date as IDs are
+//! added to and removed from the lists, and [`Columns`] streams inputs of
+//! any length and any number of columns.
+
+mod columns;
+mod compare;
+pub mod generate;
+mod incremental;
+
+pub use columns::{ColumnPair, Columns, Mode, ReadError};
+pub use compare::{DistanceTerm, ListComparison, LocationId, SimilarityTerm};
+pub use incremental::IncrementalLists;
+
+use aoc_common::{Answer, Lint, ParseError, Rng, Solution, Source};
+use std::str::FromStr;
+
+/// The [`Solution`] for day 1.
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    const INPUT: &'static str = include_str!("../01-input.txt");
+    type Input = ListComparison<i64>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lists(input)
+    }
+
+    fn part1(lists: &Self::Input) -> Answer {
+        lists.l1_distance().into()
+    }
+
+    fn part2(lists: &Self::Input) -> Answer {
+        lists.similarity().into()
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+
+    fn lint(input: &str) -> Vec<ParseError> {
+        Lint::run(input, |lint| {
+            let source = lint.source();
+            lint.each(lint.lines(), |line| parse_line::<i64>(&source, line));
+        })
+    }
+}
+
+/// Splits the two columns of location IDs. Every line must hold exactly two
+/// numbers.
+pub fn parse_input(contents: &str) -> Result<(Vec<i32>, Vec<i32>), ParseError> {
+    parse_columns(contents)
+}
+
+/// Reads the two columns as IDs of type `T`, ready to compare.
+pub fn parse_lists<T: LocationId + FromStr>(
+    contents: &str,
+) -> Result<ListComparison<T>, ParseError> {
+    let (left, right) = parse_columns(contents)?;
+    Ok(ListComparison::new(left, right))
+}
+
+fn parse_columns<T: FromStr>(contents: &str) -> Result<(Vec<T>, Vec<T>), ParseError> {
+    let source = Source::new(contents)?;
+    let mut list1 = Vec::new();
+    let mut list2 = Vec::new();
+
+    for line in source.lines() {
+        let (left, right) = parse_line(&source, line)?;
+        list1.push(left);
+        list2.push(right);
+    }
+
+    Ok((list1, list2))
+}
+
+fn parse_line<T: FromStr>(source: &Source, line: &str) -> Result<(T, T), ParseError> {
+    let mut row = Vec::with_capacity(2);
+    columns::parse_row(source, line, 2, &mut row)?;
+    let mut row = row.into_iter();
+    Ok((row.next().unwrap(), row.next().unwrap()))
+}
+
+/// Sum of the distances between the lists' pairs, smallest with smallest.
+/// IDs left over in the longer list aren't paired. Panics if the sum doesn't
+/// fit in an `i32`.
+pub fn process_lists(list1: &[i32], list2: &[i32]) -> i32 {
+    let mut left = list1.to_vec();
+    let mut right = list2.to_vec();
+    left.sort_unstable();
+    right.sort_unstable();
+    let distance: i64 = left
+        .into_iter()
+        .zip(right)
+        .map(|(a, b)| (a as i64 - b as i64).abs())
+        .sum();
+    i32::try_from(distance).expect("distance fits in i32")
+}
+
+/// Sum of each left ID multiplied by the number of times it appears in the
+/// right list, which may be any length. Panics if the sum doesn't fit in an
+/// `i32`.
+pub fn similarity_score(list1: &[i32], list2: &[i32]) -> i32 {
+    let lists = ListComparison::new(list1.to_vec(), list2.to_vec());
+    i32::try_from(lists.similarity()).expect("similarity fits in i32")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::check_examples::<Day01>(concat!(env!("CARGO_MANIFEST_DIR"), "/examples"));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = parse_input("3   4\n4   x\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 5));
+
+        let err = parse_input("3   4\n4\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+
+        let err = parse_input("3   4   5\n").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 9));
+    }
+
+    #[test]
+    fn test_example_data() {
+        let list1 = vec![3, 4, 2, 1, 3, 3];
+        let list2 = vec![4, 3, 5, 3, 9, 3];
+        assert_eq!(process_lists(&list1, &list2), 11);
+        assert_eq!(process_lists(&list1[..2], &list2), 1);
+    }
+
+    #[test]
+    fn test_similarity_score() {
+        let list1 = vec![3, 4, 2, 1, 3, 3];
+        let list2 = vec![4, 3, 5, 3, 9, 3];
+        assert_eq!(similarity_score(&list1, &list2), 31);
+        assert_eq!(similarity_score(&list1[..2], &list2), 13);
+    }
+}
@@ -1,69 +1,64 @@
-use std::collections::HashMap;
+use aoc01::{Columns, Day01, Mode, ReadError};
+use aoc_common::{InputSource, Solution};
 use std::error::Error;
-use std::fs;
 
 fn main() -> Result<(), Box<dyn Error>> {
-    let contents = fs::read_to_string("01-input.txt")?;
-
-    let (list1, list2): (Vec<i32>, Vec<i32>) = contents
-        .lines()
-        .filter_map(|line| {
-            let nums: Result<Vec<i32>, _> =
-                line.split_whitespace().take(2).map(|s| s.parse()).collect();
-
-            nums.ok().filter(|v| v.len() == 2)
-        })
-        .fold((Vec::new(), Vec::new()), |(mut v1, mut v2), nums| {
-            v1.push(nums[0]);
-            v2.push(nums[1]);
-            (v1, v2)
-        });
-
-    let answer = process_lists(&list1, &list2);
-    println!("Answer: {}", answer);
-
-    let answer2 = similarity_score(&list1, &list2);
-    println!("Answer 2: {}", answer2);
-
-    Ok(())
-}
-
-fn process_lists(list1: &[i32], list2: &[i32]) -> i32 {
-    let mut vec1 = list1.to_vec();
-    let mut vec2 = list2.to_vec();
-    vec1.sort();
-    vec2.sort();
-
-    vec1.into_iter().zip(vec2).map(|(a, b)| (a - b).abs()).sum()
-}
-
-fn similarity_score(list1: &[i32], list2: &[i32]) -> i32 {
-    let mut frequency = HashMap::new();
-    for &n in list2 {
-        *frequency.entry(n).or_insert(0) += 1;
+    // `--lenient` skips rows that aren't a full row of numbers, rather than
+    // failing with every one of them, and `--columns N` reads rows of N
+    // numbers rather than two
+    let mut mode = Mode::Strict;
+    let mut width = 2;
+    let mut args = Vec::new();
+    let mut given = std::env::args().skip(1);
+    while let Some(arg) = given.next() {
+        let (flag, inline) = match arg.split_once('=') {
+            Some((flag, value)) => (flag, Some(value.to_string())),
+            None => (arg.as_str(), None),
+        };
+        match flag {
+            "--lenient" if inline.is_none() => mode = Mode::Lenient,
+            "--columns" => {
+                width = inline
+                    .or_else(|| given.next())
+                    .and_then(|n| n.parse().ok())
+                    .filter(|&n| n >= 2)
+                    .ok_or("--columns needs a number of at least 2")?;
+            }
+            _ => args.push(arg),
+        }
     }
+    let reader = InputSource::from_args(args)?.reader(Day01::INPUT)?;
+    let columns = match Columns::<i64>::read(reader, width, mode) {
+        Ok(columns) => columns,
+        Err(ReadError::Rejected(errors)) => {
+            for err in &errors {
+                eprintln!("{}", err.diagnostic());
+            }
+            let err = ReadError::Rejected(errors);
+            return Err(err.to_string().into());
+        }
+        Err(err) => return Err(err.into()),
+    };
 
-    list1
-        .iter()
-        .map(|&n| n * frequency.get(&n).copied().unwrap_or(0))
-        .sum()
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_example_data() {
-        let list1 = vec![3, 4, 2, 1, 3, 3];
-        let list2 = vec![4, 3, 5, 3, 9, 3];
-        assert_eq!(process_lists(&list1, &list2), 11);
+    for err in columns.rejected() {
+        eprintln!("Skipped: {}", err.diagnostic());
     }
 
-    #[test]
-    fn test_similarity_score() {
-        let list1 = vec![3, 4, 2, 1, 3, 3];
-        let list2 = vec![4, 3, 5, 3, 9, 3];
-        assert_eq!(similarity_score(&list1, &list2), 31);
+    let pairs = columns.pairwise();
+    if let [pair] = pairs.as_slice() {
+        println!("Answer: {}", pair.distance);
+        println!("Answer 2: {}", pair.similarity);
+    } else {
+        for pair in pairs {
+            println!(
+                "Columns {} and {}: distance {}, similarity {}",
+                pair.left + 1,
+                pair.right + 1,
+                pair.distance,
+                pair.similarity
+            );
+        }
     }
+
+    Ok(())
 }
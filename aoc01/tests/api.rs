@@ -0,0 +1,43 @@
+use aoc01::{parse_input, parse_lists, process_lists, similarity_score, Columns, Mode};
+
+const EXAMPLE: &str = include_str!("../examples/part1.txt");
+
+#[test]
+fn test_example() {
+    let (left, right) = parse_input(EXAMPLE).unwrap();
+    assert_eq!(process_lists(&left, &right), 11);
+    assert_eq!(similarity_score(&left, &right), 31);
+}
+
+#[test]
+fn test_compare_from_one_parse() {
+    let lists = parse_lists::<u64>(EXAMPLE).unwrap();
+    assert_eq!(lists.l1_distance(), 11);
+    assert_eq!(lists.similarity(), 31);
+    assert_eq!(lists.overlap(), 4);
+    assert_eq!(lists.kendall_tau_distance(), 5);
+
+    // IDs that don't fit the type are rejected where they are
+    let err = parse_lists::<u8>("3   4\n300   1\n").unwrap_err();
+    assert_eq!((err.line, err.column), (2, 1));
+}
+
+#[test]
+fn test_streamed_columns() {
+    let columns = Columns::<i64>::read(EXAMPLE.as_bytes(), 2, Mode::Strict).unwrap();
+    let pairs = columns.pairwise();
+    assert_eq!((pairs[0].distance, pairs[0].similarity), (11, 31));
+
+    // Strict reading names every row it couldn't use
+    let err =
+        Columns::<i64>::read("3 4 5\n4 3 1\n2 5\nx 3 9\n".as_bytes(), 3, Mode::Strict).unwrap_err();
+    assert_eq!(err.lines(), [3, 4]);
+}
+
+#[test]
+fn test_bad_input() {
+    let err = parse_input("3   4\n4\n").unwrap_err();
+    assert_eq!((err.line, err.column), (2, 2));
+}
+
+aoc_common::parse_properties!(aoc01::Day01);
@@ -0,0 +1,28 @@
+//! Random reactor reports.
+
+use aoc_common::Rng;
+
+/// `size` reports of 5 to 8 levels. Each climbs or falls by 1 to 3 per
+/// step, with a good chance of one bad step, so all four kinds of report
+/// (safe, safe with a removal, and neither) turn up.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let mut out = String::new();
+    for _ in 0..size.max(1) {
+        let len = rng.range(5..=8) as usize;
+        let direction = if rng.chance(0.5) { 1 } else { -1 };
+        let bad_step = rng.chance(0.5).then(|| rng.below(len - 1));
+        let mut level = rng.range(30..=70);
+        let mut levels = vec![level];
+        for step in 0..len - 1 {
+            level += match bad_step {
+                Some(bad) if bad == step => rng.range(-4..=4),
+                _ => direction * rng.range(1..=3),
+            };
+            levels.push(level);
+        }
+        let line: Vec<String> = levels.iter().map(i64::to_string).collect();
+        out.push_str(&line.join(" "));
+        out.push('\n');
+    }
+    out
+}
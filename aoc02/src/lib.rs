@@ -0,0 +1,206 @@
+//! Day 2: Red-Nosed Reports.
+//!
+//! A report is safe when its levels all increase or all decrease by one to
+//! three at a time. [`count_safe_reports`] checks reports as they are and
+//! [`count_safe_with_removal`] lets the Problem Dampener drop one level.
+//! Both are [`SafetyPolicy`] rules, which can be changed to any step size,
+//! direction and number of dropped levels. A policy also explains a report,
+//! as the [`Violation`] of each bad step of an unsafe one and the levels
+//! that could be dropped to make it safe.
+
+pub mod generate;
+mod policy;
+mod violation;
+
+pub use policy::{Direction, SafetyPolicy};
+pub use violation::{Rule, Violation};
+
+use aoc_common::{Answer, Lint, ParseError, Part, Rng, Solution, Source};
+
+/// The [`Solution`] for day 2.
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    const INPUT: &'static str = include_str!("../02-input.txt");
+    type Input = Vec<Vec<i16>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(reports: &Self::Input) -> Answer {
+        count_safe_reports(reports).into()
+    }
+
+    fn part2(reports: &Self::Input) -> Answer {
+        count_safe_with_removal(reports).into()
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+
+    fn lint(input: &str) -> Vec<ParseError> {
+        Lint::run(input, |lint| {
+            let source = lint.source();
+            for line in lint.lines() {
+                if let Some(line) = lint.check(report_line(&source, line)) {
+                    lint.each(line.split_whitespace(), |num| source.number::<i16>(num));
+                }
+            }
+        })
+    }
+
+    fn reference(reports: &Self::Input, part: Part) -> Option<Answer> {
+        let dampened = part == Part::Two;
+        let safe = reports
+            .iter()
+            .filter(|report| safe_by_trying(report, dampened))
+            .count();
+        Some(safe.into())
+    }
+}
+
+/// Parses one report per line of whitespace-separated levels.
+pub fn parse_input(input: &str) -> Result<Vec<Vec<i16>>, ParseError> {
+    let source = Source::new(input)?;
+    source
+        .lines()
+        .map(|line| {
+            report_line(&source, line)?
+                .split_whitespace()
+                .map(|num| source.number(num))
+                .collect()
+        })
+        .collect()
+}
+
+/// The line, unless it is blank: every report has at least one level.
+fn report_line<'a>(source: &Source<'a>, line: &'a str) -> Result<&'a str, ParseError> {
+    if line.trim().is_empty() {
+        return Err(source.expected(line, "a report"));
+    }
+    Ok(line)
+}
+
+/// Number of reports that are safe as they are.
+pub fn count_safe_reports(rows: &[Vec<i16>]) -> usize {
+    count_safe(rows, &SafetyPolicy::default())
+}
+
+/// Number of reports that are safe once at most one level is removed.
+pub fn count_safe_with_removal(rows: &[Vec<i16>]) -> usize {
+    count_safe(rows, &SafetyPolicy::dampened())
+}
+
+/// Number of reports that are safe under `policy`.
+pub fn count_safe<T: Copy + Into<i64>>(rows: &[Vec<T>], policy: &SafetyPolicy) -> usize {
+    rows.iter().filter(|row| policy.is_safe(row)).count()
+}
+
+/// Whether the levels strictly increase or decrease by 1 to 3 each step.
+pub fn is_safe_sequence(report: &[i16]) -> bool {
+    SafetyPolicy::default().is_safe(report)
+}
+
+/// Whether the report is safe, or becomes safe without one of its levels.
+pub fn is_safe_with_removal(report: &[i16]) -> bool {
+    SafetyPolicy::dampened().is_safe(report)
+}
+
+// Safety the slow way, dropping each level in turn, for stress runs to
+// check the policy against
+fn safe_by_trying(report: &[i16], dampened: bool) -> bool {
+    let steady = |levels: &[i16]| {
+        let steps: Vec<i64> = levels
+            .windows(2)
+            .map(|pair| pair[1] as i64 - pair[0] as i64)
+            .collect();
+        steps.iter().all(|step| (1..=3).contains(step))
+            || steps.iter().all(|step| (-3..=-1).contains(step))
+    };
+    steady(report)
+        || dampened
+            && (0..report.len()).any(|i| {
+                let mut dropped = report.to_vec();
+                dropped.remove(i);
+                steady(&dropped)
+            })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::check_examples::<Day02>(concat!(env!("CARGO_MANIFEST_DIR"), "/examples"));
+    }
+
+    fn example_reports() -> Vec<Vec<i16>> {
+        vec![
+            vec![7, 6, 4, 2, 1],
+            vec![1, 2, 7, 8, 9],
+            vec![9, 7, 6, 2, 1],
+            vec![1, 3, 2, 4, 5],
+            vec![8, 6, 4, 4, 1],
+            vec![1, 3, 6, 7, 9],
+        ]
+    }
+
+    #[test]
+    fn test_single_sequences() {
+        // Decreasing by 1-3 (safe)
+        assert!(is_safe_sequence(&[7, 6, 4, 2, 1]));
+        // Increasing by 1-3 (safe)
+        assert!(is_safe_sequence(&[1, 3, 6, 7, 9]));
+        // Invalid jump (unsafe)
+        assert!(!is_safe_sequence(&[1, 2, 7, 8, 9]));
+        // Non-monotonic (unsafe)
+        assert!(!is_safe_sequence(&[1, 3, 2, 4, 5]));
+        // Plateau (unsafe)
+        assert!(!is_safe_sequence(&[8, 6, 4, 4, 1]));
+    }
+
+    #[test]
+    fn test_example_input() {
+        let result = count_safe_reports(&example_reports());
+        assert_eq!(result, 2);
+    }
+
+    #[test]
+    fn test_with_removal() {
+        let result = count_safe_with_removal(&example_reports());
+        assert_eq!(result, 4);
+    }
+
+    #[test]
+    fn test_more_reports_than_i16() {
+        let reports = vec![vec![1, 2, 3]; 40_000];
+        assert_eq!(count_safe_reports(&reports), 40_000);
+        assert_eq!(count_safe_with_removal(&reports), 40_000);
+    }
+
+    #[test]
+    fn test_blank_lines() {
+        let err = parse_input("1 2 3\n\n4 5 6\n").unwrap_err();
+        assert_eq!(
+            err.kind,
+            aoc_common::ParseErrorKind::Expected("a report".into())
+        );
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(Day02::lint("1 2 3\n  \n4 x 6\n").len(), 2);
+
+        let padded = format!("{}\n\n", Day02::INPUT);
+        let reports = parse_input(&padded).unwrap();
+        assert_eq!(reports, parse_input(Day02::INPUT).unwrap());
+    }
+
+    #[test]
+    fn test_reference() {
+        let reports = example_reports();
+        assert_eq!(Day02::reference(&reports, Part::One), Some(2.into()));
+        assert_eq!(Day02::reference(&reports, Part::Two), Some(4.into()));
+    }
+}
@@ -0,0 +1,255 @@
+//! Rules for what makes a report safe.
+//!
+//! The puzzle's rules are one policy among many: the step size, the
+//! direction and how many levels the Problem Dampener may drop can all be
+//! changed.
+//!
+//! Dropping levels leaves a chain of kept levels, each a good step from the
+//! last, so a report is safe when its longest chain is at most `k` short of
+//! the whole report. The longest chain ending at a level is one more than
+//! the longest ending at any earlier level a good step below it. Keeping
+//! the longest chain ending at each value so far in a segment tree finds
+//! that in `O(log n)`, so any `k` takes `O(n log n)`.
+
+/// Which way the levels of a safe report go.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Direction {
+    Increasing,
+    Decreasing,
+    /// Either way, as long as the whole report goes the same way.
+    #[default]
+    Either,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SafetyPolicy {
+    /// The smallest and largest change allowed between levels, however the
+    /// report goes.
+    pub min_step: u32,
+    pub max_step: u32,
+    pub direction: Direction,
+    /// How many levels may be dropped to make the report safe.
+    pub removals: usize,
+}
+
+impl Default for SafetyPolicy {
+    /// The puzzle's rules for part one.
+    fn default() -> Self {
+        SafetyPolicy {
+            min_step: 1,
+            max_step: 3,
+            direction: Direction::Either,
+            removals: 0,
+        }
+    }
+}
+
+impl SafetyPolicy {
+    /// The puzzle's rules for part two, with the Problem Dampener.
+    pub fn dampened() -> Self {
+        SafetyPolicy {
+            removals: 1,
+            ..Self::default()
+        }
+    }
+
+    /// Whether the report follows the policy once at most
+    /// [`removals`](Self::removals) of its levels are dropped. Reports of
+    /// one level or none are safe.
+    pub fn is_safe<T: Copy + Into<i64>>(&self, report: &[T]) -> bool {
+        self.min_removals(report) <= self.removals
+    }
+
+    /// The fewest levels that have to be dropped for the report to follow
+    /// the policy, ignoring [`removals`](Self::removals).
+    pub fn min_removals<T: Copy + Into<i64>>(&self, report: &[T]) -> usize {
+        match self.direction {
+            Direction::Increasing => self.removals_going(report, 1),
+            Direction::Decreasing => self.removals_going(report, -1),
+            Direction::Either => self
+                .removals_going(report, 1)
+                .min(self.removals_going(report, -1)),
+        }
+    }
+
+    // Everything but the longest chain of kept levels has to go
+    fn removals_going<T: Copy + Into<i64>>(&self, report: &[T], sign: i128) -> usize {
+        let longest = self.chains_going(report, sign).into_iter().max();
+        report.len() - longest.unwrap_or(0)
+    }
+
+    /// The longest chain of kept levels going the `sign` way that ends at
+    /// each level.
+    pub(crate) fn chains_going<T: Copy + Into<i64>>(&self, report: &[T], sign: i128) -> Vec<usize> {
+        // Going down is going up with the levels negated
+        let levels: Vec<i128> = report
+            .iter()
+            .map(|&level| level.into() as i128 * sign)
+            .collect();
+        let mut values = levels.clone();
+        values.sort_unstable();
+        values.dedup();
+
+        // The longest chain so far ending at each value
+        let mut tree = MaxTree::new(values.len());
+        levels
+            .iter()
+            .map(|&level| {
+                let from = values.partition_point(|&v| v < level - self.max_step as i128);
+                let to = values.partition_point(|&v| v <= level - self.min_step as i128);
+                let chain = tree.max(from, to) + 1;
+                tree.raise(values.binary_search(&level).unwrap(), chain);
+                chain
+            })
+            .collect()
+    }
+}
+
+// A segment tree of the largest value at each index, which only grows
+#[derive(Debug)]
+struct MaxTree {
+    nodes: Vec<usize>,
+    len: usize,
+}
+
+impl MaxTree {
+    fn new(len: usize) -> Self {
+        MaxTree {
+            nodes: vec![0; 2 * len],
+            len,
+        }
+    }
+
+    fn raise(&mut self, index: usize, value: usize) {
+        let mut i = index + self.len;
+        while i > 0 && self.nodes[i] < value {
+            self.nodes[i] = value;
+            i /= 2;
+        }
+    }
+
+    // The largest value in `from..to`
+    fn max(&self, from: usize, to: usize) -> usize {
+        let (mut from, mut to) = (from + self.len, to + self.len);
+        let mut max = 0;
+        while from < to {
+            if from % 2 == 1 {
+                max = max.max(self.nodes[from]);
+                from += 1;
+            }
+            if to % 2 == 1 {
+                to -= 1;
+                max = max.max(self.nodes[to]);
+            }
+            from /= 2;
+            to /= 2;
+        }
+        max
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Rng;
+
+    // Every way of dropping up to `removals` levels
+    fn brute_force(policy: &SafetyPolicy, report: &[i16]) -> bool {
+        let n = report.len();
+        let steps = policy.min_step as i16..=policy.max_step as i16;
+        let fits = |kept: &[i16], sign: i16| {
+            kept.windows(2)
+                .all(|pair| steps.contains(&((pair[1] - pair[0]) * sign)))
+        };
+        (0u32..1 << n).any(|dropped| {
+            let kept: Vec<i16> = (0..n)
+                .filter(|i| dropped & (1 << i) == 0)
+                .map(|i| report[i])
+                .collect();
+            dropped.count_ones() as usize <= policy.removals
+                && match policy.direction {
+                    Direction::Increasing => fits(&kept, 1),
+                    Direction::Decreasing => fits(&kept, -1),
+                    Direction::Either => fits(&kept, 1) || fits(&kept, -1),
+                }
+        })
+    }
+
+    #[test]
+    fn test_puzzle_policies() {
+        let policy = SafetyPolicy::default();
+        assert!(policy.is_safe(&[7, 6, 4, 2, 1]));
+        assert!(!policy.is_safe(&[1, 3, 2, 4, 5]));
+        assert!(SafetyPolicy::dampened().is_safe(&[1, 3, 2, 4, 5]));
+        assert!(SafetyPolicy::dampened().is_safe(&[9, 1, 2, 3]));
+        assert!(!SafetyPolicy::dampened().is_safe(&[1, 2, 7, 8, 9]));
+        assert!(policy.is_safe::<i16>(&[]));
+        assert!(policy.is_safe(&[4]));
+    }
+
+    #[test]
+    fn test_other_policies() {
+        let rising = SafetyPolicy {
+            direction: Direction::Increasing,
+            ..SafetyPolicy::default()
+        };
+        assert!(!rising.is_safe(&[7, 6, 4, 2, 1]));
+        assert!(rising.is_safe(&[1, 3, 6, 7, 9]));
+
+        let flat_ok = SafetyPolicy {
+            min_step: 0,
+            max_step: 10,
+            ..SafetyPolicy::default()
+        };
+        assert!(flat_ok.is_safe(&[8, 6, 4, 4, 1]));
+
+        let lenient = SafetyPolicy {
+            removals: 3,
+            ..SafetyPolicy::default()
+        };
+        assert!(lenient.is_safe(&[1, 50, 2, 60, 3, 70, 4]));
+        assert!(!lenient.is_safe(&[1, 50, 2, 60, 3, 70, 4, 80]));
+    }
+
+    #[test]
+    fn test_matches_brute_force() {
+        let mut rng = Rng::new(2);
+        for _ in 0..3000 {
+            let len = rng.range(0..=9) as usize;
+            let report: Vec<i16> = (0..len).map(|_| rng.range(0..=12) as i16).collect();
+            let policy = SafetyPolicy {
+                min_step: rng.range(0..=2) as u32,
+                max_step: rng.range(2..=4) as u32,
+                direction: *rng.pick(&[
+                    Direction::Increasing,
+                    Direction::Decreasing,
+                    Direction::Either,
+                ]),
+                removals: rng.range(0..=3) as usize,
+            };
+            assert_eq!(
+                policy.is_safe(&report),
+                brute_force(&policy, &report),
+                "{:?} {:?}",
+                policy,
+                report
+            );
+        }
+    }
+
+    #[test]
+    fn test_long_reports() {
+        // A long climb with a wild level every hundred, which is only safe
+        // if all of them can be dropped
+        let report: Vec<i64> = (0..200_000)
+            .map(|i| if i % 100 == 50 { -1 } else { i })
+            .collect();
+        let wild = report.len() / 100;
+        let policy = |removals| SafetyPolicy {
+            removals,
+            ..SafetyPolicy::default()
+        };
+        assert!(policy(wild).is_safe(&report));
+        assert!(!policy(wild - 1).is_safe(&report));
+    }
+}
@@ -0,0 +1,323 @@
+//! Why a report is unsafe, and which levels the Problem Dampener could drop
+//! to fix it.
+//!
+//! Dropping levels leaves a chain of kept levels, so the smallest fixes are
+//! what is left out of each longest chain. Those are found from their last
+//! level back, each level stepping to an earlier one whose longest chain is
+//! one shorter.
+
+use crate::{Direction, SafetyPolicy};
+use std::fmt;
+
+/// A rule a step between two levels can break.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rule {
+    /// The levels are equal.
+    ZeroStep,
+    /// The levels changed by less than the smallest step allowed.
+    StepTooSmall,
+    /// The levels changed by more than the largest step allowed.
+    StepTooLarge,
+    /// The step goes against the way the rest of the report goes.
+    DirectionFlip,
+    /// The step goes the way the policy forbids.
+    WrongDirection,
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Rule::ZeroStep => "zero step",
+            Rule::StepTooSmall => "step too small",
+            Rule::StepTooLarge => "step too large",
+            Rule::DirectionFlip => "direction flip",
+            Rule::WrongDirection => "wrong direction",
+        })
+    }
+}
+
+/// A step between two neighbouring levels that breaks a rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Violation {
+    /// Indices of the two levels, from zero.
+    pub pair: (usize, usize),
+    pub levels: (i64, i64),
+    pub rule: Rule,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} from index {} to {} ({} to {})",
+            self.rule, self.pair.0, self.pair.1, self.levels.0, self.levels.1
+        )
+    }
+}
+
+impl SafetyPolicy {
+    /// Every step of an unsafe report, as it is, that breaks the policy's
+    /// rules, in order. There are none for a report the policy counts as
+    /// safe, even one that is only safe once levels are dropped; [`fixes`]
+    /// says which.
+    ///
+    /// Each step is blamed on one rule, its direction first. When either
+    /// direction is allowed, the report goes the way most of its steps go,
+    /// or its first step goes on a tie.
+    ///
+    /// [`fixes`]: Self::fixes
+    pub fn violations<T: Copy + Into<i64>>(&self, report: &[T]) -> Vec<Violation> {
+        let levels: Vec<i64> = report.iter().map(|&level| level.into()).collect();
+        if self.is_safe(&levels) {
+            return Vec::new();
+        }
+        let sign = match self.direction {
+            Direction::Increasing => 1,
+            Direction::Decreasing => -1,
+            Direction::Either => usual_direction(&levels),
+        };
+
+        levels
+            .windows(2)
+            .enumerate()
+            .filter_map(|(i, pair)| {
+                let step = pair[1] as i128 - pair[0] as i128;
+                let rule = if step == 0 {
+                    (self.min_step > 0).then_some(Rule::ZeroStep)?
+                } else if step.signum() != sign {
+                    match self.direction {
+                        Direction::Either => Rule::DirectionFlip,
+                        _ => Rule::WrongDirection,
+                    }
+                } else if step.abs() < self.min_step as i128 {
+                    Rule::StepTooSmall
+                } else if step.abs() > self.max_step as i128 {
+                    Rule::StepTooLarge
+                } else {
+                    return None;
+                };
+                Some(Violation {
+                    pair: (i, i + 1),
+                    levels: (pair[0], pair[1]),
+                    rule,
+                })
+            })
+            .collect()
+    }
+
+    /// Every smallest set of levels that can be dropped for the report to
+    /// follow the policy's rules, as their indices in order, if the policy
+    /// allows that many [`removals`](Self::removals); none if it doesn't.
+    /// A report that follows the rules as it is has one fix, which drops
+    /// nothing.
+    ///
+    /// There can be as many fixes as ways of picking that many levels, so
+    /// this is for explaining a report rather than for counting safe ones.
+    pub fn fixes<T: Copy + Into<i64>>(&self, report: &[T]) -> Vec<Vec<usize>> {
+        let levels: Vec<i64> = report.iter().map(|&level| level.into()).collect();
+        let needed = self.min_removals(&levels);
+        if needed > self.removals {
+            return Vec::new();
+        }
+        if needed == 0 {
+            return vec![Vec::new()];
+        }
+
+        let signs: &[i128] = match self.direction {
+            Direction::Increasing => &[1],
+            Direction::Decreasing => &[-1],
+            Direction::Either => &[1, -1],
+        };
+        let mut fixes = Vec::new();
+        for &sign in signs {
+            self.fixes_going(&levels, sign, levels.len() - needed, &mut fixes);
+        }
+        fixes.sort_unstable();
+        fixes.dedup();
+        fixes
+    }
+
+    // The levels left out of each chain going the `sign` way that keeps
+    // `kept` levels, the most any chain keeps
+    fn fixes_going(&self, levels: &[i64], sign: i128, kept: usize, fixes: &mut Vec<Vec<usize>>) {
+        let chains = self.chains_going(levels, sign);
+        let good = |a: usize, b: usize| {
+            let step = (levels[b] as i128 - levels[a] as i128) * sign;
+            (self.min_step as i128..=self.max_step as i128).contains(&step)
+        };
+        // The levels whose longest chain is each length, in order
+        let mut ending = vec![Vec::new(); kept + 1];
+        for (i, &chain) in chains.iter().enumerate() {
+            ending[chain].push(i);
+        }
+
+        // Chains are built from their last level back, so each is reversed
+        let mut partial: Vec<Vec<usize>> = ending[kept].iter().map(|&last| vec![last]).collect();
+        while let Some(mut chain) = partial.pop() {
+            let first = chain[chain.len() - 1];
+            if chains[first] == 1 {
+                let mut in_chain = vec![false; levels.len()];
+                for &i in &chain {
+                    in_chain[i] = true;
+                }
+                fixes.push((0..levels.len()).filter(|&i| !in_chain[i]).collect());
+                continue;
+            }
+            let befores: Vec<usize> = ending[chains[first] - 1]
+                .iter()
+                .copied()
+                .take_while(|&i| i < first)
+                .filter(|&i| good(i, first))
+                .collect();
+            // The last way back extends the chain in place, so a chain with
+            // only one way back isn't copied at every level
+            if let Some((&last, others)) = befores.split_last() {
+                for &before in others {
+                    let mut longer = chain.clone();
+                    longer.push(before);
+                    partial.push(longer);
+                }
+                chain.push(last);
+                partial.push(chain);
+            }
+        }
+    }
+}
+
+// 1 or -1 for the way most steps go, or the first does on a tie, and 0 if
+// the levels never change
+fn usual_direction(levels: &[i64]) -> i128 {
+    let signs = levels.windows(2).map(|pair| pair[1].cmp(&pair[0]) as i128);
+    match signs.clone().sum::<i128>().signum() {
+        0 => signs.into_iter().find(|&sign| sign != 0).unwrap_or(0),
+        sign => sign,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Rng;
+
+    fn rules(policy: &SafetyPolicy, report: &[i16]) -> Vec<((usize, usize), Rule)> {
+        policy
+            .violations(report)
+            .iter()
+            .map(|violation| (violation.pair, violation.rule))
+            .collect()
+    }
+
+    #[test]
+    fn test_example_violations() {
+        let policy = SafetyPolicy::default();
+        assert!(rules(&policy, &[7, 6, 4, 2, 1]).is_empty());
+        assert_eq!(
+            rules(&policy, &[1, 2, 7, 8, 9]),
+            [((1, 2), Rule::StepTooLarge)]
+        );
+        assert_eq!(
+            rules(&policy, &[1, 3, 2, 4, 5]),
+            [((1, 2), Rule::DirectionFlip)]
+        );
+        assert_eq!(rules(&policy, &[8, 6, 4, 4, 1]), [((2, 3), Rule::ZeroStep)]);
+        // Most steps go up, so the first one is the flip
+        assert_eq!(
+            rules(&policy, &[5, 1, 2, 3]),
+            [((0, 1), Rule::DirectionFlip)]
+        );
+
+        let rising = SafetyPolicy {
+            direction: Direction::Increasing,
+            min_step: 2,
+            ..SafetyPolicy::default()
+        };
+        assert_eq!(
+            rules(&rising, &[3, 2, 3, 5]),
+            [((0, 1), Rule::WrongDirection), ((1, 2), Rule::StepTooSmall)]
+        );
+        assert_eq!(
+            policy.violations(&[1, 3, 2])[0].to_string(),
+            "direction flip from index 1 to 2 (3 to 2)"
+        );
+    }
+
+    #[test]
+    fn test_safe_once_dampened() {
+        let report = [1, 3, 2, 4, 5];
+        assert_eq!(SafetyPolicy::default().violations(&report).len(), 1);
+        assert!(SafetyPolicy::dampened().violations(&report).is_empty());
+    }
+
+    #[test]
+    fn test_example_fixes() {
+        let policy = SafetyPolicy::dampened();
+        assert_eq!(policy.fixes(&[1, 3, 2, 4, 5]), [[1], [2]]);
+        assert_eq!(policy.fixes(&[8, 6, 4, 4, 1]), [[2], [3]]);
+        assert!(policy.fixes(&[1, 2, 7, 8, 9]).is_empty());
+        assert_eq!(policy.fixes(&[1, 3, 6, 7, 9]), [Vec::<usize>::new()]);
+        assert_eq!(policy.fixes(&[9, 1, 2, 3]), [[0]]);
+
+        let any_two = SafetyPolicy {
+            removals: 2,
+            ..SafetyPolicy::default()
+        };
+        // Only 7 8 9 is left safe
+        assert_eq!(any_two.fixes(&[1, 2, 7, 8, 9]), [[0, 1]]);
+        assert_eq!(any_two.fixes(&[5, 1, 9, 2, 3]), [[0, 2]]);
+        assert!(SafetyPolicy::default().fixes(&[1, 3, 2]).is_empty());
+    }
+
+    #[test]
+    fn test_long_report() {
+        let mut report: Vec<i64> = (0..200_000).collect();
+        report[1234] = -1;
+        assert_eq!(SafetyPolicy::dampened().fixes(&report), [[1234]]);
+    }
+
+    #[test]
+    fn test_matches_brute_force() {
+        let mut rng = Rng::new(25);
+        for _ in 0..3000 {
+            let len = rng.range(0..=8) as usize;
+            let report: Vec<i16> = (0..len).map(|_| rng.range(0..=10) as i16).collect();
+            let policy = SafetyPolicy {
+                min_step: rng.range(0..=2) as u32,
+                max_step: rng.range(2..=4) as u32,
+                direction: *rng.pick(&[
+                    Direction::Increasing,
+                    Direction::Decreasing,
+                    Direction::Either,
+                ]),
+                removals: rng.range(0..=3) as usize,
+            };
+            let as_is = SafetyPolicy {
+                removals: 0,
+                ..policy
+            };
+            assert_eq!(
+                policy.violations(&report).is_empty(),
+                policy.is_safe(&report),
+                "{:?} {:?}",
+                policy,
+                report
+            );
+
+            // Every way of dropping levels that leaves the rules followed,
+            // keeping the smallest
+            let mut fixes: Vec<Vec<usize>> = (0u32..1 << len)
+                .filter(|&dropped| {
+                    let kept: Vec<i16> = (0..len)
+                        .filter(|i| dropped & (1 << i) == 0)
+                        .map(|i| report[i])
+                        .collect();
+                    as_is.min_removals(&kept) == 0
+                })
+                .map(|dropped| (0..len).filter(|i| dropped & (1 << i) != 0).collect())
+                .collect();
+            let fewest = fixes.iter().map(Vec::len).min().unwrap();
+            fixes.retain(|fix| fix.len() == fewest && fewest <= policy.removals);
+            fixes.sort_unstable();
+            assert_eq!(policy.fixes(&report), fixes, "{:?} {:?}", policy, report);
+        }
+    }
+}
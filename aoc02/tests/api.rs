@@ -0,0 +1,66 @@
+use aoc02::{
+    count_safe, count_safe_reports, count_safe_with_removal, is_safe_sequence,
+    is_safe_with_removal, parse_input, Direction, Rule, SafetyPolicy,
+};
+
+const EXAMPLE: &str = include_str!("../examples/part1.txt");
+
+#[test]
+fn test_example() {
+    let reports = parse_input(EXAMPLE).unwrap();
+    assert_eq!(count_safe_reports(&reports), 2);
+    assert_eq!(count_safe_with_removal(&reports), 4);
+}
+
+#[test]
+fn test_single_reports() {
+    assert!(is_safe_sequence(&[7, 6, 4, 2, 1]));
+    assert!(!is_safe_sequence(&[1, 3, 2, 4, 5]));
+    assert!(is_safe_with_removal(&[1, 3, 2, 4, 5]));
+    assert!(!is_safe_with_removal(&[1, 2, 7, 8, 9]));
+}
+
+#[test]
+fn test_policies() {
+    let reports = parse_input(EXAMPLE).unwrap();
+    let falling = SafetyPolicy {
+        direction: Direction::Decreasing,
+        removals: 1,
+        ..SafetyPolicy::default()
+    };
+    // 7 6 4 2 1, and 8 6 4 4 1 without a 4
+    assert_eq!(count_safe(&reports, &falling), 2);
+    let any_two = SafetyPolicy {
+        removals: 2,
+        ..SafetyPolicy::default()
+    };
+    // 1 2 7 8 9 keeps 7 8 9, and 9 7 6 2 1 keeps 9 7 6
+    assert_eq!(count_safe(&reports, &any_two), 6);
+}
+
+#[test]
+fn test_explanations() {
+    let reports = parse_input(EXAMPLE).unwrap();
+    let policy = SafetyPolicy::dampened();
+    let explained: Vec<(Vec<Rule>, Vec<Vec<usize>>)> = reports
+        .iter()
+        .map(|report| {
+            let rules = policy.violations(report).iter().map(|v| v.rule).collect();
+            (rules, policy.fixes(report))
+        })
+        .collect();
+    // Reports 4 and 5 are safe once the Problem Dampener drops a level
+    assert_eq!(
+        explained,
+        [
+            (vec![], vec![vec![]]),
+            (vec![Rule::StepTooLarge], vec![]),
+            (vec![Rule::StepTooLarge], vec![]),
+            (vec![], vec![vec![1], vec![2]]),
+            (vec![], vec![vec![2], vec![3]]),
+            (vec![], vec![vec![]]),
+        ]
+    );
+}
+
+aoc_common::parse_properties!(aoc02::Day02);
@@ -0,0 +1,47 @@
+//! Random corrupted memory.
+
+use aoc_common::Rng;
+
+const NOISE: &[&str] = &[
+    "mul(4*",
+    "mul ( 2 , 4 )",
+    "mul[3,7]",
+    "?(12,34)",
+    "don't",
+    "do(",
+    "from()",
+    "what()",
+    "select()",
+    "%&",
+    "!@^",
+    "+",
+    "]",
+    "'",
+    "<",
+    ">",
+    " ",
+    "mul(",
+];
+
+/// `size` instructions, a mix of real `mul(x,y)`, `do()` and `don't()`
+/// calls and the near misses around them, on lines of about 60 tokens.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let mut out = String::new();
+    for i in 1..=size.max(1) {
+        match rng.below(10) {
+            0..=4 => out.push_str(&format!(
+                "mul({},{})",
+                rng.range(0..=999),
+                rng.range(0..=999)
+            )),
+            5 => out.push_str("do()"),
+            6 => out.push_str("don't()"),
+            _ => out.push_str(rng.pick::<&str>(NOISE)),
+        }
+        if i % 60 == 0 {
+            out.push('\n');
+        }
+    }
+    out.push('\n');
+    out
+}
@@ -0,0 +1,16 @@
+use aoc03::{collect_numbers, sum_multiplications, sum_multiplications_v2};
+
+const EXAMPLE: &str = include_str!("../examples/part1.txt");
+const EXAMPLE_2: &str = include_str!("../examples/part2.txt");
+
+#[test]
+fn test_example() {
+    assert_eq!(
+        collect_numbers(EXAMPLE),
+        vec![(2, 4), (5, 5), (11, 8), (8, 5)]
+    );
+    assert_eq!(sum_multiplications(EXAMPLE), 161);
+    assert_eq!(sum_multiplications_v2(EXAMPLE_2), 48);
+}
+
+aoc_common::parse_properties!(aoc03::Day03);
@@ -0,0 +1,15 @@
+//! Random letter grids.
+
+use aoc_common::Rng;
+
+/// A `size` by `size` grid of the letters in XMAS, which is dense enough
+/// in both words to find plenty of each pattern.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let side = size.max(1);
+    let mut out = String::with_capacity(side * (side + 1));
+    for _ in 0..side {
+        out.extend((0..side).map(|_| *rng.pick(&['X', 'M', 'A', 'S'])));
+        out.push('\n');
+    }
+    out
+}
@@ -0,0 +1,215 @@
+//! Day 4: Ceres Search.
+//!
+//! A word search over a [`Grid`] of letters. [`count_xmas`] finds `XMAS` in
+//! every direction and [`count_masx`] finds two `MAS` crossing in an X.
+
+pub mod generate;
+
+use aoc_common::{Answer, Grid, Lint, ParseError, Point, Rng, Solution};
+
+/// The [`Solution`] for day 4.
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    const INPUT: &'static str = include_str!("../04-input.txt");
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_grid(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        count_xmas(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        count_masx(input).into()
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+
+    fn lint(input: &str) -> Vec<ParseError> {
+        Lint::run(input, |lint| lint.grid(lint.lines(), is_tile))
+    }
+}
+
+/// Parses the word search: uppercase letters, with `.` for letters the
+/// puzzle's diagrams leave out.
+pub fn parse_grid(input: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse(input, |c| is_tile(c).then_some(c))
+}
+
+fn is_tile(c: char) -> bool {
+    c.is_ascii_uppercase() || c == '.'
+}
+
+/// Occurrences of `XMAS` along rows, columns and diagonals, both ways.
+pub fn count_xmas(grid: &Grid<char>) -> usize {
+    [get_rows(grid), get_cols(grid), get_diagonals(grid)]
+        .concat()
+        .into_iter()
+        .map(|slice| slice.matches("XMAS").count() + slice.matches("SAMX").count())
+        .sum()
+}
+
+fn get_rows(grid: &Grid<char>) -> Vec<String> {
+    grid.rows().map(|row| row.iter().collect()).collect()
+}
+
+fn get_cols(grid: &Grid<char>) -> Vec<String> {
+    grid.columns().map(|col| col.collect()).collect()
+}
+
+// Down-right diagonals followed by down-left diagonals
+fn get_diagonals(grid: &Grid<char>) -> Vec<String> {
+    grid.diagonals()
+        .chain(grid.anti_diagonals())
+        .map(|diag| diag.collect())
+        .collect()
+}
+
+/// Number of `A`s at the centre of two diagonal `MAS`, either way round.
+pub fn count_masx(grid: &Grid<char>) -> usize {
+    center_positions(grid)
+        .into_iter()
+        .filter(|center| is_valid_pattern(grid, center))
+        .count()
+}
+
+fn center_positions(grid: &Grid<char>) -> Vec<Point> {
+    grid.iter()
+        .filter(|(_, &c)| c == 'A')
+        .map(|(pos, _)| pos)
+        .collect()
+}
+
+fn is_valid_pattern(grid: &Grid<char>, center: &Point) -> bool {
+    // Corners in order: top-left, top-right, bottom-left, bottom-right
+    let corners = [(-1, -1), (1, -1), (-1, 1), (1, 1)]
+        .map(|(dx, dy)| center.offset(dx, dy).and_then(|pos| grid.get(pos)).copied());
+
+    match corners {
+        [Some(a), Some(b), Some(c), Some(d)] => is_mas(&[a, b, c, d]),
+        _ => false,
+    }
+}
+
+fn is_mas(group: &[char]) -> bool {
+    const PATTERNS: [[char; 4]; 4] = [
+        ['M', 'M', 'S', 'S'],
+        ['M', 'S', 'M', 'S'],
+        ['S', 'M', 'S', 'M'],
+        ['S', 'S', 'M', 'M'],
+    ];
+
+    PATTERNS.iter().any(|p| p == group)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::check_examples::<Day04>(concat!(env!("CARGO_MANIFEST_DIR"), "/examples"));
+    }
+
+    const SAMPLE_INPUT: &str = "\
+MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX";
+
+    const SMALL_INPUT: &str = "\
+..X...
+.SAMX.
+.A..A.
+XMAS.S
+.X....";
+
+    #[test]
+    fn test_get_rows() {
+        let rows = get_rows(&parse_grid(SMALL_INPUT).unwrap());
+
+        assert_eq!(rows[0], "..X...");
+    }
+
+    #[test]
+    fn test_get_cols() {
+        let cols = get_cols(&parse_grid(SMALL_INPUT).unwrap());
+
+        assert_eq!(cols[0], "...X.");
+    }
+
+    #[test]
+    fn get_diagonals_test() {
+        let diags = get_diagonals(&parse_grid(SMALL_INPUT).unwrap());
+
+        assert_eq!(diags.len(), 20);
+        assert_eq!(diags[0], ".S.S.");
+        assert_eq!(diags[6], ".AA.");
+        assert_eq!(diags[15], ".X.AX");
+        assert_eq!(diags[16], ".AS.");
+    }
+
+    #[test]
+    fn test_count_xmas() {
+        assert_eq!(count_xmas(&parse_grid(SAMPLE_INPUT).unwrap()), 18);
+    }
+
+    #[test]
+    fn test_center_positions() {
+        use std::collections::HashSet;
+
+        let grid = parse_grid(SAMPLE_INPUT).unwrap();
+        // (row, col) pairs
+        let expected: HashSet<Point> = vec![
+            (1, 2),
+            (2, 6),
+            (2, 7),
+            (3, 2),
+            (3, 4),
+            (7, 1),
+            (7, 3),
+            (7, 5),
+            (7, 7),
+        ]
+        .into_iter()
+        .map(|(row, col)| Point::new(col, row))
+        .collect();
+
+        let actual: HashSet<_> = center_positions(&grid).into_iter().collect();
+
+        assert!(expected.is_subset(&actual));
+    }
+
+    #[test]
+    fn test_is_valid_pattern() {
+        let grid = parse_grid(SAMPLE_INPUT).unwrap();
+
+        assert!(is_valid_pattern(&grid, &Point::new(2, 1)));
+        assert!(!is_valid_pattern(&grid, &Point::new(1, 2)));
+    }
+
+    #[test]
+    fn test_is_mas() {
+        assert!(is_mas(&['M', 'M', 'S', 'S']));
+        assert!(is_mas(&['M', 'S', 'M', 'S']));
+        assert!(!is_mas(&['M', 'S', 'S', 'M']));
+        assert!(!is_mas(&['.', '.', '.', '.']));
+    }
+
+    #[test]
+    fn test_count_masx() {
+        assert_eq!(count_masx(&parse_grid(SAMPLE_INPUT).unwrap()), 9);
+    }
+}
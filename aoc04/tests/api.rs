@@ -0,0 +1,12 @@
+use aoc04::{count_masx, count_xmas, parse_grid};
+
+const EXAMPLE: &str = include_str!("../examples/part1.txt");
+
+#[test]
+fn test_example() {
+    let grid = parse_grid(EXAMPLE).unwrap();
+    assert_eq!(count_xmas(&grid), 18);
+    assert_eq!(count_masx(&grid), 9);
+}
+
+aoc_common::parse_properties!(aoc04::Day04);
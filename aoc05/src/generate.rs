@@ -0,0 +1,36 @@
+//! Random page ordering rules and updates.
+
+use aoc_common::Rng;
+
+/// Rules ordering 49 two-digit pages, one for every pair as in the real
+/// inputs, then `size` updates of an odd number of pages. About half the
+/// updates are in order.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let mut pages: Vec<i64> = (10..=99).collect();
+    rng.shuffle(&mut pages);
+    pages.truncate(49);
+
+    let mut rules = Vec::new();
+    for (i, &before) in pages.iter().enumerate() {
+        for &after in &pages[i + 1..] {
+            rules.push(format!("{}|{}", before, after));
+        }
+    }
+    rng.shuffle(&mut rules);
+
+    let mut out = rules.join("\n");
+    out.push_str("\n\n");
+    for _ in 0..size.max(1) {
+        let len = 2 * rng.range(2..=11) as usize + 1;
+        let mut positions: Vec<usize> = (0..pages.len()).collect();
+        rng.shuffle(&mut positions);
+        positions.truncate(len);
+        if rng.chance(0.5) {
+            positions.sort();
+        }
+        let update: Vec<String> = positions.iter().map(|&i| pages[i].to_string()).collect();
+        out.push_str(&update.join(","));
+        out.push('\n');
+    }
+    out
+}
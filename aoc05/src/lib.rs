@@ -0,0 +1,323 @@
+//! Day 5: Print Queue.
+//!
+//! Page ordering [`Rule`]s decide which updates are printed in the right
+//! order. [`find_valid_updates_middle_sum`] adds up the middle pages of the
+//! correct updates and [`find_invalid_updates_middle_sum`] those of the
+//! others once they have been reordered.
+
+pub mod generate;
+
+use aoc_common::{Answer, Lint, ParseError, Rng, Solution, Source};
+
+/// The [`Solution`] for day 5.
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    const INPUT: &'static str = include_str!("../05-input.txt");
+    type Input = (Vec<Rule>, Vec<Vec<i32>>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1((rules, updates): &Self::Input) -> Answer {
+        find_valid_updates_middle_sum(rules, updates).into()
+    }
+
+    fn part2((rules, updates): &Self::Input) -> Answer {
+        find_invalid_updates_middle_sum(rules, updates).into()
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+
+    fn lint(input: &str) -> Vec<ParseError> {
+        Lint::run(input, |lint| {
+            let source = lint.source();
+            let (rules, updates) = sections(&source).unwrap_or_else(|err| {
+                lint.report(err);
+                (source.lines().collect(), Vec::new())
+            });
+            lint.each(rules, |line| parse_rule(&source, line));
+            for line in updates {
+                lint.each(line.split(','), |page| source.number::<i32>(page));
+            }
+        })
+    }
+}
+
+/// A page ordering rule: the first page must come before the second.
+pub type Rule = (i32, i32);
+
+/// Parses the rules (`47|53`), a blank line, then one comma-separated
+/// update per line.
+pub fn parse_input(input: &str) -> Result<(Vec<Rule>, Vec<Vec<i32>>), ParseError> {
+    let source = Source::new(input)?;
+
+    // split input into rules and updates, at the empty line
+    let (rules, updates) = sections(&source)?;
+
+    // parse rules into a vector of tuples
+    let rules: Vec<Rule> = rules
+        .into_iter()
+        .map(|line| parse_rule(&source, line))
+        .collect::<Result<_, ParseError>>()?;
+
+    // parse updates into a vector of vectors of i32, split by commas
+    let updates: Vec<Vec<i32>> = updates
+        .into_iter()
+        .map(|line| source.numbers(line, ","))
+        .collect::<Result<_, _>>()?;
+
+    Ok((rules, updates))
+}
+
+/// The lines before and after the first blank line, whatever the line
+/// endings.
+fn sections<'a>(source: &Source<'a>) -> Result<(Vec<&'a str>, Vec<&'a str>), ParseError> {
+    let mut lines: Vec<&str> = source.lines().collect();
+    let blank = lines
+        .iter()
+        .position(|line| line.trim().is_empty())
+        .ok_or_else(|| {
+            source.expected(source.text().trim_end(), "a blank line before the updates")
+        })?;
+    let updates = lines.split_off(blank + 1);
+    lines.pop();
+    Ok((lines, updates))
+}
+
+fn parse_rule(source: &Source, line: &str) -> Result<Rule, ParseError> {
+    let (before, after) = source.split_once(line, "|")?;
+    Ok((source.number(before)?, source.number(after)?))
+}
+
+/// Sum of the middle page of every update that follows the rules.
+pub fn find_valid_updates_middle_sum(rules: &[Rule], updates: &[Vec<i32>]) -> i32 {
+    updates
+        .iter()
+        .filter(|seq| is_valid_update_sequence(seq, rules))
+        .map(|seq| get_middle_number(seq))
+        .sum()
+}
+
+/// Sum of the middle page of every update that breaks the rules, after
+/// putting it in the right order.
+pub fn find_invalid_updates_middle_sum(rules: &[Rule], updates: &[Vec<i32>]) -> i32 {
+    find_invalid_updates(rules, updates)
+        .into_iter()
+        .map(|seq| reorder_invalid_update(rules, &seq))
+        .map(|seq| get_middle_number(&seq))
+        .sum()
+}
+
+fn is_valid_update_sequence(seq: &[i32], rules: &[Rule]) -> bool {
+    seq.windows(2).all(|pair| {
+        let relevant_rules: Vec<_> = rules
+            .iter()
+            .filter(|(_, after)| *after == pair[1])
+            .map(|(before, _)| before)
+            .collect();
+
+        if !relevant_rules.is_empty() {
+            return relevant_rules.contains(&&pair[0]);
+        }
+
+        false
+    })
+}
+
+fn get_middle_number(seq: &[i32]) -> i32 {
+    seq[seq.len() / 2]
+}
+
+fn find_invalid_updates(rules: &[Rule], updates: &[Vec<i32>]) -> Vec<Vec<i32>> {
+    updates
+        .iter()
+        .filter(|seq| !is_valid_update_sequence(seq, rules))
+        .cloned()
+        .collect()
+}
+
+fn reorder_invalid_update(rules: &[Rule], seq: &[i32]) -> Vec<i32> {
+    let mut result = seq.to_vec();
+    let mut made_swap = true;
+
+    // Keep swapping until no swaps are made
+    while made_swap {
+        made_swap = false;
+
+        for i in 0..result.len() - 1 {
+            if rules.contains(&(result[i + 1], result[i])) {
+                result.swap(i, i + 1);
+                made_swap = true;
+            }
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::check_examples::<Day05>(concat!(env!("CARGO_MANIFEST_DIR"), "/examples"));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = parse_input("47|53\n97-13\n\n75,47\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 6));
+
+        let err = parse_input("47|53\n\n75,4x,61\n").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 4));
+
+        let err = parse_input("47|53\n97|13\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 6));
+    }
+
+    #[test]
+    fn test_crlf() {
+        let (rules, updates) = parse_input("47|53\r\n97|13\r\n\r\n75,47\r\n").unwrap();
+        assert_eq!(rules, [(47, 53), (97, 13)]);
+        assert_eq!(updates, [vec![75, 47]]);
+        assert!(Day05::lint("47|53\r\n\r\n75,47\r\n").is_empty());
+    }
+
+    // Example data from the puzzle description
+    fn example_rules() -> Vec<(i32, i32)> {
+        vec![
+            (47, 53),
+            (97, 13),
+            (97, 61),
+            (97, 47),
+            (75, 29),
+            (61, 13),
+            (75, 53),
+            (29, 13),
+            (97, 29),
+            (53, 29),
+            (61, 53),
+            (97, 53),
+            (61, 29),
+            (47, 13),
+            (75, 47),
+            (97, 75),
+            (47, 61),
+            (75, 61),
+            (47, 29),
+            (75, 13),
+            (53, 13),
+        ]
+    }
+
+    fn example_updates() -> Vec<Vec<i32>> {
+        vec![
+            vec![75, 47, 61, 53, 29],
+            vec![97, 61, 53, 29, 13],
+            vec![75, 29, 13],
+            vec![75, 97, 47, 61, 53],
+            vec![61, 13, 29],
+            vec![97, 13, 75, 29, 47],
+        ]
+    }
+
+    #[test]
+    fn test_find_valid_updates_middle_sum() {
+        let rules = example_rules();
+        let updates = example_updates();
+
+        assert_eq!(find_valid_updates_middle_sum(&rules, &updates), 143);
+    }
+
+    #[test]
+    fn test_is_valid_update_sequence() {
+        let rules = example_rules();
+
+        // Valid seq #1
+        assert!(is_valid_update_sequence(&[75, 47, 61, 53, 29], &rules));
+        // Valid seq #2
+        assert!(is_valid_update_sequence(&[97, 61, 53, 29, 13], &rules));
+        // Valid seq #3
+        assert!(is_valid_update_sequence(&[75, 29, 13], &rules));
+        // Invalid seq #4
+        assert!(!is_valid_update_sequence(&[75, 97, 47, 61, 53], &rules));
+        // Invalid seq #5
+        assert!(!is_valid_update_sequence(&[61, 13, 29], &rules));
+        // Invalid seq #6
+        assert!(!is_valid_update_sequence(&[97, 13, 75, 29, 47], &rules));
+    }
+
+    #[test]
+    fn test_get_middle_number() {
+        assert_eq!(get_middle_number(&[75, 47, 61, 53, 29]), 61);
+        assert_eq!(get_middle_number(&[75, 29, 13]), 29);
+    }
+
+    #[test]
+    fn test_find_invalid_updates_middle_sum() {
+        let rules = example_rules();
+        let updates = example_updates();
+
+        assert_eq!(find_invalid_updates_middle_sum(&rules, &updates), 123);
+    }
+
+    #[test]
+    fn test_reorder_invalid_update() {
+        let rules = example_rules();
+
+        assert_eq!(
+            reorder_invalid_update(&rules, &[75, 97, 47, 61, 53]),
+            vec![97, 75, 47, 61, 53]
+        );
+        assert_eq!(
+            reorder_invalid_update(&rules, &[61, 13, 29]),
+            vec![61, 29, 13]
+        );
+        assert_eq!(
+            reorder_invalid_update(&rules, &[97, 13, 75, 29, 47]),
+            vec![97, 75, 47, 29, 13]
+        );
+    }
+
+    #[test]
+    fn test_find_invalid_updates() {
+        let rules = example_rules();
+        let updates = example_updates();
+        let invalid_updates = find_invalid_updates(&rules, &updates);
+
+        assert!(invalid_updates.contains(&vec![75, 97, 47, 61, 53]));
+        assert!(invalid_updates.contains(&vec![61, 13, 29]));
+        assert!(invalid_updates.contains(&vec![97, 13, 75, 29, 47]));
+    }
+
+    mod properties {
+        use super::*;
+        use aoc_common::testing::generated;
+        use aoc_common::testing::proptest::prelude::*;
+
+        proptest! {
+            // Reordering is a bubble sort over every rule, so keep it short
+            #![proptest_config(ProptestConfig::with_cases(32))]
+
+            #[test]
+            fn reordered_updates_follow_the_rules(input in generated::<Day05>(1..10)) {
+                let (rules, updates) = parse_input(&input).unwrap();
+                for update in find_invalid_updates(&rules, &updates) {
+                    let reordered = reorder_invalid_update(&rules, &update);
+                    prop_assert!(is_valid_update_sequence(&reordered, &rules));
+
+                    let (mut before, mut after) = (update, reordered);
+                    before.sort_unstable();
+                    after.sort_unstable();
+                    prop_assert_eq!(before, after);
+                }
+            }
+        }
+    }
+}
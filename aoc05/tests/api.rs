@@ -0,0 +1,20 @@
+use aoc05::{find_invalid_updates_middle_sum, find_valid_updates_middle_sum, parse_input, Rule};
+
+const EXAMPLE: &str = include_str!("../examples/part1.txt");
+
+#[test]
+fn test_example() {
+    let (rules, updates) = parse_input(EXAMPLE).unwrap();
+    assert_eq!(find_valid_updates_middle_sum(&rules, &updates), 143);
+    assert_eq!(find_invalid_updates_middle_sum(&rules, &updates), 123);
+}
+
+#[test]
+fn test_rules_by_hand() {
+    let rules: Vec<Rule> = vec![(1, 2), (1, 3), (2, 3)];
+    let updates = vec![vec![1, 2, 3], vec![3, 2, 1]];
+    assert_eq!(find_valid_updates_middle_sum(&rules, &updates), 2);
+    assert_eq!(find_invalid_updates_middle_sum(&rules, &updates), 2);
+}
+
+aoc_common::parse_properties!(aoc05::Day05);
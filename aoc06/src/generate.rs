@@ -0,0 +1,22 @@
+//! Random lab maps.
+
+use aoc_common::Rng;
+
+/// A `size` by `size` map with an obstruction on about one tile in ten and
+/// the guard somewhere free.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let side = size.max(2);
+    let mut tiles: Vec<Vec<char>> = (0..side)
+        .map(|_| {
+            (0..side)
+                .map(|_| if rng.chance(0.1) { '#' } else { '.' })
+                .collect()
+        })
+        .collect();
+    tiles[rng.below(side)][rng.below(side)] = '^';
+
+    tiles
+        .iter()
+        .map(|row| row.iter().collect::<String>() + "\n")
+        .collect()
+}
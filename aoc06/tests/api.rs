@@ -0,0 +1,27 @@
+use aoc06::{count_guard_positions, count_possible_loop_positions, parse_grid, Day06};
+use aoc_common::Solution;
+
+const EXAMPLE: &str = include_str!("../examples/part1.txt");
+
+#[test]
+fn test_example() {
+    let grid = parse_grid(EXAMPLE).unwrap();
+    assert_eq!(count_guard_positions(&grid), 41);
+    assert_eq!(count_possible_loop_positions(&grid), 6);
+}
+
+#[test]
+fn test_lint() {
+    let input = "..#.\n.x^\n#.^.\n..?.\n";
+    let problems: Vec<(usize, usize)> = Day06::lint(input)
+        .iter()
+        .map(|err| (err.line, err.column))
+        .collect();
+    assert_eq!(problems, [(2, 2), (2, 4), (3, 3), (4, 3)]);
+
+    // The parser stops at the first, and also won't take a second guard
+    assert_eq!(parse_grid(input).unwrap_err().column, 2);
+    assert_eq!(parse_grid("^.\n.^\n").unwrap_err().line, 2);
+}
+
+aoc_common::parse_properties!(aoc06::Day06);
@@ -0,0 +1,38 @@
+//! Random calibration equations.
+
+use aoc_common::Rng;
+
+/// Targets stay below this, as in the real inputs, so they fit the solver's
+/// integers.
+const MAX_TARGET: i64 = 1_000_000_000_000_000;
+
+/// `size` equations of 2 to 12 numbers up to 999. About half the targets
+/// are made from the numbers with random operators, so they can be
+/// calibrated.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let mut out = String::new();
+    for _ in 0..size.max(1) {
+        let count = rng.range(2..=12) as usize;
+        let numbers: Vec<i64> = (0..count).map(|_| rng.range(1..=999)).collect();
+        let made = rng.chance(0.5).then(|| {
+            numbers[1..].iter().try_fold(numbers[0], |total, &n| {
+                let next = match rng.below(3) {
+                    0 => total.checked_add(n)?,
+                    1 => total.checked_mul(n)?,
+                    _ => format!("{}{}", total, n).parse().ok()?,
+                };
+                (next < MAX_TARGET).then_some(next)
+            })
+        });
+        let largest = numbers
+            .iter()
+            .fold(1, |total: i64, &n| total.saturating_mul(n));
+        let target = made
+            .flatten()
+            .unwrap_or_else(|| rng.range(1..=largest.min(MAX_TARGET)));
+
+        let numbers: Vec<String> = numbers.iter().map(i64::to_string).collect();
+        out.push_str(&format!("{}: {}\n", target, numbers.join(" ")));
+    }
+    out
+}
@@ -0,0 +1,300 @@
+//! Day 7: Bridge Repair.
+//!
+//! Each [`Equation`] is a test value and the numbers that might produce it
+//! when combined left to right. [`total_calibration_result`] tries `+` and
+//! `*`, and [`total_calibration_result_concat`] adds `||` concatenation.
+
+pub mod generate;
+
+use aoc_common::{Answer, Lint, ParseError, Rng, Solution, Source};
+
+/// The [`Solution`] for day 7.
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    const INPUT: &'static str = include_str!("../07-input.txt");
+    type Input = Vec<Equation>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        total_calibration_result(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        total_calibration_result_concat(input).into()
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+
+    fn lint(input: &str) -> Vec<ParseError> {
+        Lint::run(input, |lint| {
+            let source = lint.source();
+            lint.each(lint.lines(), |line| parse_equation(&source, line));
+        })
+    }
+}
+
+/// Sum of the test values that `+` and `*` can produce.
+pub fn total_calibration_result(equations: &[Equation]) -> i64 {
+    equations
+        .iter()
+        .filter_map(|eq| is_solvable(eq).then_some(eq.target))
+        .sum()
+}
+
+/// Sum of the test values that `+`, `*` and `||` can produce.
+pub fn total_calibration_result_concat(equations: &[Equation]) -> i64 {
+    equations
+        .iter()
+        .filter_map(|eq| is_solvable_concat(eq).then_some(eq.target))
+        .sum()
+}
+
+/// A test value and the numbers to combine into it.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Equation {
+    target: i64,
+    numbers: Vec<i64>,
+}
+
+impl Equation {
+    pub fn new(target: i64, numbers: Vec<i64>) -> Self {
+        Equation { target, numbers }
+    }
+}
+
+/// Parses one `test: n n ...` equation per line.
+pub fn parse_input(input: &str) -> Result<Vec<Equation>, ParseError> {
+    let source = Source::new(input)?;
+    source
+        .lines()
+        .map(|line| parse_equation(&source, line))
+        .collect()
+}
+
+fn parse_equation(source: &Source, line: &str) -> Result<Equation, ParseError> {
+    let (target, numbers) = source.split_once(line, ":")?;
+    let numbers = numbers
+        .split_whitespace()
+        .map(|s| source.number(s))
+        .collect::<Result<Vec<_>, _>>()?;
+    if numbers.is_empty() {
+        return Err(source.expected(line, "at least one number"));
+    }
+
+    Ok(Equation {
+        target: source.number(target)?,
+        numbers,
+    })
+}
+
+fn is_solvable(equation: &Equation) -> bool {
+    let num_operators = equation.numbers.len() - 1;
+    let combinations = 2_i32.pow(num_operators as u32);
+
+    (0..combinations).any(|i| {
+        let mut result = equation.numbers[0];
+
+        for pos in 0..num_operators {
+            let next_num = equation.numbers[pos + 1];
+            // Use bit at position 'pos' to determine operator
+            let operator = (i & (1 << pos)) >> pos;
+
+            // Early return if result exceeds target
+            if result > equation.target {
+                return false;
+            }
+
+            result = match operator {
+                0 => result.checked_add(next_num),
+                1 => result.checked_mul(next_num),
+                _ => unreachable!(),
+            }
+            .filter(|&val| val <= equation.target)
+            .unwrap_or(0);
+        }
+
+        result == equation.target
+    })
+}
+
+fn is_solvable_concat(equation: &Equation) -> bool {
+    let num_operators = equation.numbers.len() - 1;
+    let combinations = 3_i64.pow(num_operators as u32);
+
+    (0..combinations).any(|i| {
+        let mut result = equation.numbers[0];
+        let mut combo = i;
+
+        for pos in 0..num_operators {
+            let next_num = equation.numbers[pos + 1];
+            // Get rightmost trit (0 = add, 1 = multiply, 2 = concat)
+            let operator = combo % 3;
+            combo /= 3;
+
+            // Early return if result exceeds target
+            if result > equation.target {
+                return false;
+            }
+
+            result = match operator {
+                0 => result.checked_add(next_num),
+                1 => result.checked_mul(next_num),
+                2 => concatenate(result, next_num),
+                _ => unreachable!(),
+            }
+            .filter(|&val| val <= equation.target)
+            .unwrap_or(0);
+        }
+
+        result == equation.target
+    })
+}
+
+fn concatenate(a: i64, b: i64) -> Option<i64> {
+    let b_digits = b.to_string().len() as u32;
+    let multiplier = 10_i64.checked_pow(b_digits)?;
+    a.checked_mul(multiplier)?.checked_add(b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::check_examples::<Day07>(concat!(env!("CARGO_MANIFEST_DIR"), "/examples"));
+    }
+
+    #[test]
+    fn test_parse_equation() {
+        let input = "190: 10 19";
+        let eq = &parse_input(input).unwrap()[0];
+        assert_eq!(eq.target, 190);
+        assert_eq!(eq.numbers, vec![10, 19]);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = parse_input("190: 10 19\n3267 81 40").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 11));
+
+        let err = parse_input("190: 10 1x").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 9));
+
+        let err = parse_input("190:").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 5));
+    }
+
+    #[test]
+    fn test_simple_equation() {
+        // 190: 10 19 -> only * works (10 * 19 = 190)
+        let eq = Equation {
+            target: 190,
+            numbers: vec![10, 19],
+        };
+        assert!(is_solvable(&eq));
+    }
+
+    #[test]
+    fn test_three_number_equation() {
+        // 3267: 81 40 27 -> two solutions
+        let eq = Equation {
+            target: 3267,
+            numbers: vec![81, 40, 27],
+        };
+        assert!(is_solvable(&eq));
+    }
+
+    #[test]
+    fn test_unsolvable_equation() {
+        let eq = Equation {
+            target: 100,
+            numbers: vec![5, 5],
+        };
+        assert!(!is_solvable(&eq));
+    }
+
+    #[test]
+    fn test_multiple_solutions() {
+        // 3267: 81 40 27 from example
+        let eq = Equation {
+            target: 3267,
+            numbers: vec![81, 40, 27],
+        };
+        assert!(is_solvable(&eq));
+    }
+
+    #[test]
+    fn test_concatenate() {
+        assert_eq!(concatenate(12, 345), Some(12345));
+        assert_eq!(concatenate(6, 8), Some(68));
+        assert_eq!(concatenate(15, 6), Some(156));
+    }
+
+    #[test]
+    fn test_concat_equations() {
+        // Test cases from part 2
+        assert!(is_solvable_concat(&Equation {
+            target: 156,
+            numbers: vec![15, 6]
+        }));
+
+        assert!(is_solvable_concat(&Equation {
+            target: 7290,
+            numbers: vec![6, 8, 6, 15]
+        }));
+
+        assert!(is_solvable_concat(&Equation {
+            target: 192,
+            numbers: vec![17, 8, 14]
+        }));
+    }
+
+    #[test]
+    fn test_calibration_sums() {
+        let input = "\
+190: 10 19
+3267: 81 40 27
+292: 11 6 16 20
+156: 15 6
+7290: 6 8 6 15
+192: 17 8 14";
+
+        let equations = parse_input(input).unwrap();
+        assert_eq!(total_calibration_result(&equations), 3749); // Part 1
+        assert_eq!(total_calibration_result_concat(&equations), 11387); // Part 2
+    }
+
+    #[test]
+    fn test_edge_case_equations() {
+        // Test large numbers that might cause overflow issues
+        let large = Equation {
+            target: 999999999,
+            numbers: vec![999, 999, 999],
+        };
+        assert!(is_solvable_concat(&large));
+
+        // Test where concatenation could produce a larger intermediate
+        // result than the target but subsequent operations might fix it
+        let intermediate = Equation {
+            target: 100,
+            numbers: vec![99, 99, 1],
+        };
+        assert!(!is_solvable_concat(&intermediate));
+
+        // Test where only very specific operator combinations work
+        let specific = Equation {
+            target: 1234,
+            numbers: vec![12, 34, 56],
+        };
+        // We should verify this expected result carefully
+        assert!(!is_solvable_concat(&specific));
+    }
+}
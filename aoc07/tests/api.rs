@@ -0,0 +1,20 @@
+use aoc07::{parse_input, total_calibration_result, total_calibration_result_concat, Equation};
+
+const EXAMPLE: &str = include_str!("../examples/part1.txt");
+
+#[test]
+fn test_example() {
+    let equations = parse_input(EXAMPLE).unwrap();
+    assert_eq!(total_calibration_result(&equations), 3749);
+    assert_eq!(total_calibration_result_concat(&equations), 11387);
+}
+
+#[test]
+fn test_equation_by_hand() {
+    let equations = [Equation::new(156, vec![15, 6])];
+    assert_eq!(parse_input("156: 15 6").unwrap(), equations);
+    assert_eq!(total_calibration_result(&equations), 0);
+    assert_eq!(total_calibration_result_concat(&equations), 156);
+}
+
+aoc_common::parse_properties!(aoc07::Day07);
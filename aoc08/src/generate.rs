@@ -0,0 +1,27 @@
+//! Random antenna maps.
+
+use aoc_common::Rng;
+
+const FREQUENCIES: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// A `size` by `size` map with an antenna on about one tile in 25, from a
+/// handful of frequencies so most have several antennas.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let side = size.max(1);
+    let frequencies: Vec<char> = (0..rng.range(1..=8))
+        .map(|_| *rng.pick(FREQUENCIES) as char)
+        .collect();
+
+    let mut out = String::with_capacity(side * (side + 1));
+    for _ in 0..side {
+        for _ in 0..side {
+            out.push(if rng.chance(0.04) {
+                *rng.pick(&frequencies)
+            } else {
+                '.'
+            });
+        }
+        out.push('\n');
+    }
+    out
+}
@@ -0,0 +1,483 @@
+//! Day 8: Resonant Collinearity.
+//!
+//! Pairs of antennas on the same frequency create antinodes in line with
+//! them. [`count_antinodes`] counts those at twice the distance and
+//! [`count_resonant_antinodes`] every point on the line.
+
+pub mod generate;
+
+use aoc_common::{Answer, Grid, Lint, ParseError, Part, Point, Rng, Solution};
+use std::collections::{HashMap, HashSet};
+
+/// The [`Solution`] for day 8.
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    const INPUT: &'static str = include_str!("../08-input.txt");
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_grid(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        count_antinodes(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        count_resonant_antinodes(input).into()
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+
+    fn lint(input: &str) -> Vec<ParseError> {
+        Lint::run(input, |lint| lint.grid(lint.lines(), is_tile))
+    }
+
+    fn reference(grid: &Self::Input, part: Part) -> Option<Answer> {
+        Some(antinodes_by_marking(grid, part == Part::Two).into())
+    }
+}
+
+/// Parses the antenna map. Antennas are letters and digits, with `.` for
+/// empty ground; `#` marks an antinode in the puzzle's diagrams.
+pub fn parse_grid(input: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse(input, |c| is_tile(c).then_some(c))
+}
+
+fn is_tile(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '.' || c == '#'
+}
+
+/// Number of map tiles holding an antinode twice as far from one antenna
+/// of a pair as from the other.
+pub fn count_antinodes(grid: &Grid<char>) -> usize {
+    let antennas = parse_antennas(grid);
+    let is_in_bounds = make_is_in_bounds(grid);
+    let antinodes = find_all_antinodes(&antennas, is_in_bounds);
+    antinodes.len()
+}
+
+/// Number of map tiles in line with at least two antennas of the same
+/// frequency.
+pub fn count_resonant_antinodes(grid: &Grid<char>) -> usize {
+    let antennas = parse_antennas(grid);
+    let is_in_bounds = make_is_in_bounds(grid);
+    let antinodes = find_resonant_antinodes(&antennas, is_in_bounds);
+    antinodes.len()
+}
+
+// Antinodes can fall outside the map, so positions are signed
+type Position = Point<isize>;
+
+type AntennaMap = HashMap<char, Vec<Position>>;
+type Antinodes = HashSet<Position>;
+
+fn parse_antennas(grid: &Grid<char>) -> AntennaMap {
+    let mut map: AntennaMap = HashMap::new();
+    for (pos, &c) in grid.iter() {
+        if c.is_alphanumeric() {
+            map.entry(c).or_default().push(pos.to_signed());
+        }
+    }
+    map
+}
+
+fn pairings(antennas: &AntennaMap, freq: char) -> Vec<(Position, Position)> {
+    let positions = antennas.get(&freq).unwrap();
+    let mut pairs: Vec<(Position, Position)> = Vec::new();
+    for i in 0..positions.len() {
+        for j in i + 1..positions.len() {
+            pairs.push((positions[i], positions[j]));
+        }
+    }
+
+    pairs
+}
+
+fn find_all_antinodes(antennas: &AntennaMap, is_in_bounds: impl Fn(Position) -> bool) -> Antinodes {
+    let mut antinodes: Antinodes = HashSet::new();
+
+    for freq in antennas.keys() {
+        let pairs = pairings(antennas, *freq);
+        for (pos1, pos2) in pairs {
+            for antinode in calculate_antinodes(pos1, pos2) {
+                if is_in_bounds(antinode) {
+                    antinodes.insert(antinode);
+                }
+            }
+        }
+    }
+
+    antinodes
+}
+
+fn find_resonant_antinodes(
+    antennas: &AntennaMap,
+    is_in_bounds: impl Fn(Position) -> bool,
+) -> Antinodes {
+    let mut antinodes: Antinodes = HashSet::new();
+
+    for freq in antennas.keys() {
+        let pairs = pairings(antennas, *freq);
+        for pair in pairs {
+            for antinode in calculate_resonant_antinodes(pair, &is_in_bounds) {
+                antinodes.insert(antinode);
+            }
+        }
+    }
+
+    antinodes
+}
+
+fn calculate_antinodes(pos1: Position, pos2: Position) -> [Position; 2] {
+    let x_diff = (pos1.x - pos2.x).abs();
+    let y_diff = (pos1.y - pos2.y).abs();
+    let x_min = pos1.x.min(pos2.x) - x_diff;
+    let x_max = pos1.x.max(pos2.x) + x_diff;
+    let y_min = pos1.y.min(pos2.y) - y_diff;
+    let y_max = pos1.y.max(pos2.y) + y_diff;
+
+    // x increases while y decreases or vice versa
+    if (pos2.x > pos1.x) != (pos2.y > pos1.y) {
+        [
+            Position { x: x_min, y: y_max },
+            Position { x: x_max, y: y_min },
+        ]
+    } else {
+        // Default case covers:
+        // - Both x and y increase/decrease together
+        // - Vertical lines [pos1.x == pos2.x]
+        // - Horizontal lines [pos1.y == pos2.y]
+        [
+            Position { x: x_min, y: y_min },
+            Position { x: x_max, y: y_max },
+        ]
+    }
+}
+
+fn calculate_resonant_antinodes(
+    pair: (Position, Position),
+    is_in_bounds: impl Fn(Position) -> bool,
+) -> Vec<Position> {
+    let mut antinodes: Antinodes = HashSet::new();
+    let (pos1, pos2) = pair;
+
+    // Calculate direction vector and reduce to unit steps
+    let dx = pos2.x - pos1.x;
+    let dy = pos2.y - pos1.y;
+
+    // Helper to extend line using the pattern
+    let mut extend_line = |start: Position| {
+        let mut current = start;
+
+        while is_in_bounds(current) {
+            antinodes.insert(current);
+            current = Position {
+                x: current.x + dx,
+                y: current.y + dy,
+            };
+        }
+
+        // And the other direction
+        current = Position {
+            x: start.x - dx,
+            y: start.y - dy,
+        };
+
+        while is_in_bounds(current) {
+            antinodes.insert(current);
+            current = Position {
+                x: current.x - dx,
+                y: current.y - dy,
+            };
+        }
+    };
+
+    extend_line(pos1);
+
+    antinodes.into_iter().collect()
+}
+
+// Antinodes the plain way, marking the tiles each ordered pair of antennas
+// reaches in i64 coordinates, for stress runs to check the solution against
+fn antinodes_by_marking(grid: &Grid<char>, resonant: bool) -> usize {
+    let (width, height) = (grid.width() as i64, grid.height() as i64);
+    let mut frequencies: HashMap<char, Vec<(i64, i64)>> = HashMap::new();
+    for (pos, &c) in grid.iter() {
+        if c.is_alphanumeric() {
+            frequencies
+                .entry(c)
+                .or_default()
+                .push((pos.x as i64, pos.y as i64));
+        }
+    }
+
+    let mut marked = vec![false; grid.width() * grid.height()];
+    for antennas in frequencies.values() {
+        for &(ax, ay) in antennas {
+            for &(bx, by) in antennas {
+                let (dx, dy) = (bx - ax, by - ay);
+                if (dx, dy) == (0, 0) {
+                    continue;
+                }
+                // From `a` through `b` and on, or only one step past `b`
+                let (mut x, mut y) = if resonant {
+                    (ax, ay)
+                } else {
+                    (bx + dx, by + dy)
+                };
+                while (0..width).contains(&x) && (0..height).contains(&y) {
+                    marked[(y * width + x) as usize] = true;
+                    if !resonant {
+                        break;
+                    }
+                    (x, y) = (x + dx, y + dy);
+                }
+            }
+        }
+    }
+    marked.iter().filter(|&&marked| marked).count()
+}
+
+fn make_is_in_bounds(grid: &Grid<char>) -> impl Fn(Position) -> bool + '_ {
+    move |pos: Position| pos.to_unsigned().is_some_and(|pos| grid.contains(pos))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::check_examples::<Day08>(concat!(env!("CARGO_MANIFEST_DIR"), "/examples"));
+    }
+
+    #[test]
+    fn test_calculate_antinodes() {
+        let result = calculate_antinodes(Position { x: 1, y: 2 }, Position { x: 2, y: 4 });
+        let expected = [Position { x: 0, y: 0 }, Position { x: 3, y: 6 }];
+        assert!(result.contains(&expected[0]) && result.contains(&expected[1]));
+    }
+
+    #[test]
+    fn test_calculate_antinodes_inverted() {
+        let result = calculate_antinodes(Position { x: 1, y: 4 }, Position { x: 2, y: 2 });
+        let expected = [Position { x: 0, y: 6 }, Position { x: 3, y: 0 }];
+        assert!(result.contains(&expected[0]) && result.contains(&expected[1]));
+
+        let result = calculate_antinodes(Position { x: 2, y: 2 }, Position { x: 1, y: 4 });
+        let expected = [Position { x: 0, y: 6 }, Position { x: 3, y: 0 }];
+        assert!(result.contains(&expected[0]) && result.contains(&expected[1]));
+    }
+
+    #[test]
+    fn test_calculate_antinodes_horizontal() {
+        let result = calculate_antinodes(Position { x: 1, y: 2 }, Position { x: 2, y: 2 });
+        let expected = [Position { x: 0, y: 2 }, Position { x: 3, y: 2 }];
+        assert!(result.contains(&expected[0]) && result.contains(&expected[1]));
+    }
+
+    #[test]
+    fn test_calculate_antinodes_vertical() {
+        let result = calculate_antinodes(Position { x: 1, y: 2 }, Position { x: 1, y: 4 });
+        let expected = [Position { x: 1, y: 0 }, Position { x: 1, y: 6 }];
+        assert!(result.contains(&expected[0]) && result.contains(&expected[1]));
+    }
+
+    #[test]
+    fn test_calulate_antinodes_negative() {
+        let result = calculate_antinodes(Position { x: 0, y: 0 }, Position { x: 2, y: 2 });
+        let expected = [Position { x: -2, y: -2 }, Position { x: 4, y: 4 }];
+        assert!(result.contains(&expected[0]) && result.contains(&expected[1]));
+    }
+
+    const SAMPLE_INPUT: &str = "\
+............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............";
+
+    #[test]
+    fn test_parse_antennas() {
+        let antennas = parse_antennas(&parse_grid(SAMPLE_INPUT).unwrap());
+        assert_eq!(antennas.len(), 2);
+        assert_eq!(antennas.get(&'0').unwrap().len(), 4);
+        assert_eq!(antennas.get(&'A').unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_pairings() {
+        let antennas = parse_antennas(&parse_grid(SAMPLE_INPUT).unwrap());
+        let pairs_0 = pairings(&antennas, '0');
+        assert_eq!(pairs_0.len(), 6);
+        let pairs_a = pairings(&antennas, 'A');
+        assert_eq!(pairs_a.len(), 3);
+    }
+
+    #[test]
+    fn test_sample_input() {
+        // From the problem description
+        assert_eq!(count_antinodes(&parse_grid(SAMPLE_INPUT).unwrap()), 14);
+    }
+
+    #[test]
+    fn test_out_of_bounds() {
+        let input = "\
+..a..
+.....
+..a..";
+        // Antinodes would be out of bounds
+        assert_eq!(count_antinodes(&parse_grid(input).unwrap()), 0);
+    }
+
+    #[test]
+    fn test_different_frequencies() {
+        let input = "\
+.....
+.....
+..a..
+.....
+..A..
+.....
+.....";
+        // Different frequencies should not create antinodes
+        assert_eq!(count_antinodes(&parse_grid(input).unwrap()), 0);
+    }
+
+    #[test]
+    fn test_single_pair() {
+        let input = "\
+.....
+.....
+..a..
+.....
+..a..
+.....
+.....";
+        // One pair should create two antinodes
+        assert_eq!(count_antinodes(&parse_grid(input).unwrap()), 2);
+    }
+
+    #[test]
+    fn test_horizontal_antinodes() {
+        let input = "\
+.......
+..a.a..
+.......";
+        // Antinodes should be created horizontally
+        assert_eq!(count_antinodes(&parse_grid(input).unwrap()), 2);
+    }
+
+    #[test]
+    fn test_diagonal_antinodes() {
+        let input = "\
+.......
+.......
+....a..
+.......
+..a....
+.......
+.......";
+        // Antinodes should be created diagonally
+        assert_eq!(count_antinodes(&parse_grid(input).unwrap()), 2);
+    }
+
+    #[test]
+    fn test_overlapping_antinode() {
+        let input = "\
+..B..
+.....
+..a..
+.....
+..a..
+.....
+..A..";
+        // Antinodes may occur at locations that contain antennas
+        assert_eq!(count_antinodes(&parse_grid(input).unwrap()), 2);
+    }
+
+    #[test]
+    fn test_resonant_antinodes_simple_line() {
+        let input = "\
+.....
+..T..
+..T..
+..T..
+.....";
+        // All three T's are collinear and should be antinodes
+        // Plus two more antinodes at the ends of the line
+        assert_eq!(count_resonant_antinodes(&parse_grid(input).unwrap()), 5);
+    }
+
+    #[test]
+    fn test_resonant_antinodes_diagonal() {
+        let input = "\
+T....
+.T...
+..T..
+.....
+.....";
+        // Three T's are collinear diagonally
+        // Plus two more antinodes extending the line
+        assert_eq!(count_resonant_antinodes(&parse_grid(input).unwrap()), 5);
+    }
+
+    #[test]
+    fn test_resonant_antinodes_multiple_lines() {
+        let input = "\
+T.........
+...T......
+.T........
+..........
+..........
+..........
+..........
+..........
+..........
+..........";
+        // Forms both vertical and horizontal lines through center T
+        // Should create antinodes at all T positions plus the ends
+        // of both lines (being careful not to double-count)
+        assert_eq!(count_resonant_antinodes(&parse_grid(input).unwrap()), 9);
+    }
+
+    #[test]
+    fn test_resonant_sample_input() {
+        // From the problem description
+        assert_eq!(
+            count_resonant_antinodes(&parse_grid(SAMPLE_INPUT).unwrap()),
+            34
+        );
+    }
+
+    #[test]
+    fn test_reference() {
+        let grid = parse_grid(SAMPLE_INPUT).unwrap();
+        assert_eq!(Day08::reference(&grid, Part::One), Some(14.into()));
+        assert_eq!(Day08::reference(&grid, Part::Two), Some(34.into()));
+
+        // Wider than an i8 position can reach
+        let mut rng = Rng::new(1);
+        let grid = parse_grid(&generate::input(&mut rng, 160)).unwrap();
+        assert_eq!(
+            Day08::reference(&grid, Part::One),
+            Some(count_antinodes(&grid).into())
+        );
+        assert_eq!(
+            Day08::reference(&grid, Part::Two),
+            Some(count_resonant_antinodes(&grid).into())
+        );
+    }
+}
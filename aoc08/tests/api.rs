@@ -0,0 +1,12 @@
+use aoc08::{count_antinodes, count_resonant_antinodes, parse_grid};
+
+const EXAMPLE: &str = include_str!("../examples/part1.txt");
+
+#[test]
+fn test_example() {
+    let grid = parse_grid(EXAMPLE).unwrap();
+    assert_eq!(count_antinodes(&grid), 14);
+    assert_eq!(count_resonant_antinodes(&grid), 34);
+}
+
+aoc_common::parse_properties!(aoc08::Day08);
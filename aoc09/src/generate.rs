@@ -0,0 +1,21 @@
+//! Random disk maps.
+
+use aoc_common::Rng;
+
+/// A disk map of `size` digits, alternating files of 1 to 9 blocks with
+/// free spans of 0 to 9. The real inputs have 19,999 digits, about 10,000
+/// files.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let mut out: String = (0..size.max(1))
+        .map(|i| {
+            let blocks = if i % 2 == 0 {
+                rng.range(1..=9)
+            } else {
+                rng.range(0..=9)
+            };
+            char::from(b'0' + blocks as u8)
+        })
+        .collect();
+    out.push('\n');
+    out
+}
@@ -0,0 +1,528 @@
+//! Day 9: Disk Fragmenter.
+//!
+//! The disk map alternates file and free space lengths. [`calculate_checksum`]
+//! compacts it a block at a time and [`calculate_checksum_2`] a whole file at
+//! a time.
+
+pub mod generate;
+
+use aoc_common::{Answer, Lint, ParseError, Part, Rng, Solution, Source};
+
+/// The [`Solution`] for day 9.
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    const INPUT: &'static str = include_str!("../09-input.txt");
+    type Input = Vec<u8>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        calculate_checksum(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        calculate_checksum_2(input).into()
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+
+    fn lint(input: &str) -> Vec<ParseError> {
+        Lint::run(input, |lint| {
+            let source = lint.source();
+            let line = input.trim();
+            for (i, c) in line.char_indices() {
+                if !c.is_ascii_digit() {
+                    lint.report(source.invalid_char(line, i));
+                }
+            }
+        })
+    }
+
+    // Part two's reference would be as slow as the solution
+    fn reference(input: &Self::Input, part: Part) -> Option<Answer> {
+        (part == Part::One).then(|| checksum_by_blocks(input).into())
+    }
+}
+
+/// Parses the disk map, a single line of digits.
+pub fn parse_input(input: &str) -> Result<Vec<u8>, ParseError> {
+    let source = Source::new(input)?;
+    let line = input.trim();
+    line.char_indices()
+        .map(|(i, c)| {
+            c.to_digit(10)
+                .map(|digit| digit as u8)
+                .ok_or_else(|| source.invalid_char(line, i))
+        })
+        .collect()
+}
+
+/// Checksum after moving blocks one at a time from the end of the disk to
+/// the leftmost free space.
+pub fn calculate_checksum(disk_map: &[u8]) -> u64 {
+    let disk = compose_file_blocks(disk_map);
+    let compacted = compact_disk(&disk);
+    calculate_final_checksum(&compacted)
+}
+
+/// Checksum after moving each whole file, highest ID first, to the leftmost
+/// span of free space that fits it.
+pub fn calculate_checksum_2(disk_map: &[u8]) -> u64 {
+    let disk = compose_file_blocks(disk_map);
+    let compacted = compact_disk_files(&disk);
+    calculate_final_checksum(&compacted)
+}
+
+fn compose_file_blocks(digits: &[u8]) -> Vec<i32> {
+    let mut output = Vec::new();
+
+    // Enumerate over the digits in chunks of 2
+    // For each chunk, the first digit is the file size, the second is the free space
+    // make_file with the file_id = chunk_index and file_size = first digit
+    // then fill the free space with -1
+    for (chunk_index, chunk) in digits.chunks(2).enumerate() {
+        let file_size = chunk[0] as usize;
+        let file_id = i32::try_from(chunk_index).expect("file IDs fit in i32");
+        output.extend(make_file(file_id, file_size));
+
+        // Break if there is no chunk[1]
+        if chunk.len() < 2 {
+            break;
+        }
+
+        let free_space = chunk[1] as usize;
+        output.extend(make_file(-1, free_space));
+    }
+
+    output
+}
+
+fn compact_disk(disk: &[i32]) -> Vec<i32> {
+    let mut output = Vec::new();
+    let mut next_free = 0;
+    let mut next_block = disk.len() - 1;
+
+    while next_free <= next_block {
+        // Changed condition here
+        if disk[next_free] != -1 {
+            output.push(disk[next_free]);
+            next_free += 1;
+        } else {
+            while next_block > next_free && disk[next_block] == -1 {
+                next_block -= 1;
+            }
+            if next_block > next_free {
+                output.push(disk[next_block]);
+                next_block -= 1;
+                next_free += 1;
+            } else {
+                break;
+            }
+        }
+    }
+
+    output
+}
+
+fn make_file(file_id: i32, file_size: usize) -> Vec<i32> {
+    std::iter::repeat_n(file_id, file_size).collect()
+}
+
+fn calculate_final_checksum(compacted: &[i32]) -> u64 {
+    compacted
+        .iter()
+        .enumerate()
+        .filter(|(_pos, &digit)| digit >= 0)
+        .map(|(pos, &digit)| pos as u64 * digit as u64)
+        .sum()
+}
+
+fn compact_disk_files(disk: &[i32]) -> Vec<i32> {
+    let mut disk = Disk::new(disk);
+
+    for file_idx in 0..disk.files.len() {
+        let (_id, start, size) = disk.files[file_idx];
+        if let Some(new_pos) = disk.find_free_space(size) {
+            // Only move the file if new_pos <= start
+            if new_pos <= start {
+                disk.move_file(file_idx, new_pos);
+            }
+        }
+    }
+
+    disk.blocks
+}
+
+#[derive(Debug)]
+struct Disk {
+    blocks: Vec<i32>,
+    files: Vec<(i32, usize, usize)>,
+}
+
+impl Disk {
+    fn new(blocks: &[i32]) -> Self {
+        let blocks = blocks.to_vec();
+        let files = Self::find_files(&blocks);
+        Self { blocks, files }
+    }
+
+    // Helper function to find contiguous file blocks,
+    // returns a list of tuples (file_id, start_index, size)
+    fn find_files(blocks: &[i32]) -> Vec<(i32, usize, usize)> {
+        let mut files = Vec::new();
+        let mut i = 0;
+
+        while i < blocks.len() {
+            if blocks[i] != -1 {
+                let file_id = blocks[i];
+                let start = i;
+
+                while i < blocks.len() && blocks[i] == file_id {
+                    i += 1;
+                }
+                files.push((file_id, start, i - start));
+            } else {
+                i += 1;
+            }
+        }
+
+        files.sort_by_key(|&(id, _, _)| -id);
+        files
+    }
+
+    // Find the leftmost span of free space that can fit size blocks
+    fn find_free_space(&self, size: usize) -> Option<usize> {
+        let mut count = 0;
+        let mut start = None;
+
+        for (i, &block) in self.blocks.iter().enumerate() {
+            if block == -1 {
+                if start.is_none() {
+                    start = Some(i);
+                }
+                count += 1;
+                if count >= size {
+                    return start;
+                }
+            } else {
+                count = 0;
+                start = None;
+            }
+        }
+
+        None
+    }
+
+    // Move a file to a new position
+    fn move_file(&mut self, file_idx: usize, new_pos: usize) {
+        let (id, start, size) = self.files[file_idx];
+
+        // Clear the old location
+        for i in start..start + size {
+            self.blocks[i] = -1;
+        }
+
+        // Write to new location
+        for i in new_pos..new_pos + size {
+            self.blocks[i] = id;
+        }
+
+        // Update file record
+        self.files[file_idx].1 = new_pos;
+    }
+}
+
+// Part one from the disk map straight to the checksum, filling each free
+// block with the last block on the disk, for stress runs to check against
+fn checksum_by_blocks(disk_map: &[u8]) -> u64 {
+    let mut blocks: Vec<Option<u64>> = Vec::new();
+    for (i, &length) in disk_map.iter().enumerate() {
+        let id = (i % 2 == 0).then_some(i as u64 / 2);
+        blocks.extend(std::iter::repeat_n(id, length as usize));
+    }
+    let mut i = 0;
+    while i < blocks.len() {
+        if blocks[i].is_none() {
+            blocks.swap_remove(i);
+        } else {
+            i += 1;
+        }
+    }
+    blocks
+        .into_iter()
+        .flatten()
+        .enumerate()
+        .map(|(pos, id)| pos as u64 * id)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::ParseErrorKind;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::check_examples::<Day09>(concat!(env!("CARGO_MANIFEST_DIR"), "/examples"));
+    }
+
+    fn digits(disk_map: &str) -> Vec<u8> {
+        parse_input(disk_map).unwrap()
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = parse_input("12x45\n").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 3));
+        assert_eq!(err.kind, ParseErrorKind::InvalidChar('x'));
+    }
+
+    #[test]
+    fn test_compose_file_blocks_simple() {
+        assert_eq!(
+            compose_file_blocks(&digits("1212")),
+            vec![0, -1, -1, 1, -1, -1]
+        );
+        assert_eq!(
+            compose_file_blocks(&digits("123")),
+            vec![0, -1, -1, 1, 1, 1]
+        );
+    }
+
+    #[test]
+    fn test_compose_file_blocks_medium() {
+        assert_eq!(
+            compose_file_blocks(&digits("12345")),
+            vec![0, -1, -1, 1, 1, 1, -1, -1, -1, -1, 2, 2, 2, 2, 2]
+        );
+    }
+
+    #[test]
+    fn test_compose_file_blocks_large() {
+        assert_eq!(
+            compose_file_blocks(&digits("2333133121414131402")),
+            vec![
+                0, 0, -1, -1, -1, 1, 1, 1, -1, -1, -1, 2, -1, -1, -1, 3, 3, 3, -1, 4, 4, -1, 5, 5,
+                5, 5, -1, 6, 6, 6, 6, -1, 7, 7, 7, -1, 8, 8, 8, 8, 9, 9
+            ]
+        );
+    }
+
+    #[test]
+    fn test_compact_disk_simple() {
+        // input: "1212"
+        let disk = vec![0, -1, -1, 1, -1, -1];
+        assert_eq!(compact_disk(&disk), vec![0, 1]);
+
+        // input: "123"
+        let disk = vec![0, -1, -1, 1, 1, 1];
+        assert_eq!(compact_disk(&disk), vec![0, 1, 1, 1]);
+    }
+
+    #[test]
+    fn test_compact_disk_small() {
+        // input: "12345"
+        let disk = vec![0, -1, -1, 1, 1, 1, -1, -1, -1, -1, 2, 2, 2, 2, 2];
+        assert_eq!(compact_disk(&disk), vec![0, 2, 2, 1, 1, 1, 2, 2, 2]);
+    }
+
+    #[test]
+    fn test_compact_disk_medium() {
+        // input: "2333133121414131402"
+        let disk = vec![
+            // 00...111...2...333.44.5555.6666.777.888899
+            0, 0, -1, -1, -1, 1, 1, 1, -1, -1, -1, 2, -1, -1, -1, 3, 3, 3, -1, 4, 4, -1, 5, 5, 5, 5,
+            -1, 6, 6, 6, 6, -1, 7, 7, 7, -1, 8, 8, 8, 8, 9, 9,
+        ];
+        assert_eq!(
+            compact_disk(&disk),
+            vec![
+                0, 0, 9, 9, 8, 1, 1, 1, 8, 8, 8, 2, 7, 7, 7, 3, 3, 3, 6, 4, 4, 6, 5, 5, 5, 5, 6, 6
+            ]
+        );
+    }
+
+    #[test]
+    // Fails assertion
+    // left: [2, -1, 0, 0, 3, 3, 3, -1, 1, 1, 1, 4, 4, 5, 5, 5, 5, 6, 6, 6, 6, 7, 7, 7, 8, 8, 8, 8, 9, 9, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1]
+    // right: [0, 0, 9, 9, 2, 1, 1, 1, 7, 7, 7, -1, 4, 4, -1, 3, 3, 3, -1, -1, -1, -1, 5, 5, 5, 5, -1, 6, 6, 6, 6, -1, -1, -1, -1, -1, 8, 8, 8, 8, -1, -1]
+    fn test_compact_disk_files() {
+        // input: "2333133121414131402"
+        let disk = vec![
+            // 00...111...2...333.44.5555.6666.777.888899
+            0, 0, -1, -1, -1, 1, 1, 1, -1, -1, -1, 2, -1, -1, -1, 3, 3, 3, -1, 4, 4, -1, 5, 5, 5, 5,
+            -1, 6, 6, 6, 6, -1, 7, 7, 7, -1, 8, 8, 8, 8, 9, 9,
+        ];
+        assert_eq!(
+            compact_disk_files(&disk),
+            vec![
+                0, 0, 9, 9, 2, 1, 1, 1, 7, 7, 7, -1, 4, 4, -1, 3, 3, 3, -1, -1, -1, -1, 5, 5, 5, 5,
+                -1, 6, 6, 6, 6, -1, -1, -1, -1, -1, 8, 8, 8, 8, -1, -1
+            ]
+        );
+    }
+
+    #[test]
+    fn test_disk_find_files_simple() {
+        let blocks = vec![0, -1, -1, 1, 1, 1];
+        assert_eq!(Disk::find_files(&blocks), vec![(1, 3, 3), (0, 0, 1)]);
+    }
+
+    #[test]
+    fn test_disk_find_files_small() {
+        // input: "12345"
+        let blocks = vec![0, -1, -1, 1, 1, 1, -1, -1, -1, -1, 2, 2, 2, 2, 2];
+        assert_eq!(
+            Disk::find_files(&blocks),
+            vec![(2, 10, 5), (1, 3, 3), (0, 0, 1)]
+        );
+    }
+
+    #[test]
+    fn test_disk_find_files_medium() {
+        let blocks = vec![
+            // 00...111...2...333.44.5555.6666.777.888899
+            0, 0, -1, -1, -1, 1, 1, 1, -1, -1, -1, 2, -1, -1, -1, 3, 3, 3, -1, 4, 4, -1, 5, 5, 5, 5,
+            -1, 6, 6, 6, 6, -1, 7, 7, 7, -1, 8, 8, 8, 8, 9, 9,
+        ];
+        assert_eq!(
+            Disk::find_files(&blocks),
+            vec![
+                (9, 40, 2),
+                (8, 36, 4),
+                (7, 32, 3),
+                (6, 27, 4),
+                (5, 22, 4),
+                (4, 19, 2),
+                (3, 15, 3),
+                (2, 11, 1),
+                (1, 5, 3),
+                (0, 0, 2)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_disk_find_free_space_small() {
+        // input: "12345"
+        let disk = Disk::new(&vec![0, -1, -1, 1, 1, 1, -1, -1, -1, -1, 2, 2, 2, 2, 2]);
+
+        assert_eq!(disk.find_free_space(1), Some(1));
+        assert_eq!(disk.find_free_space(2), Some(1));
+        assert_eq!(disk.find_free_space(3), Some(6));
+        assert_eq!(disk.find_free_space(4), Some(6));
+        assert_eq!(disk.find_free_space(5), None);
+    }
+
+    #[test]
+    fn test_disk_find_free_space_medium() {
+        // input: "2333133121414131402"
+        let disk = Disk::new(&vec![
+            0, 0, -1, -1, -1, 1, 1, 1, -1, -1, -1, 2, -1, -1, -1, 3, 3, 3, -1, 4, 4, -1, 5, 5, 5,
+            5, -1, 6, 6, 6, 6, -1, 7, 7, 7, -1, 8, 8, 8, 8, 9, 9,
+        ]);
+
+        assert_eq!(disk.find_free_space(1), Some(2));
+        assert_eq!(disk.find_free_space(2), Some(2));
+        assert_eq!(disk.find_free_space(3), Some(2));
+        assert_eq!(disk.find_free_space(4), None);
+        assert_eq!(disk.find_free_space(5), None);
+    }
+
+    #[test]
+    fn test_calculate_final_checksum() {
+        // 0099811188827773336446555566..............
+        let compacted = vec![
+            0, 0, 9, 9, 8, 1, 1, 1, 8, 8, 8, 2, 7, 7, 7, 3, 3, 3, 6, 4, 4, 6, 5, 5, 5, 5, 6, 6,
+        ];
+        assert_eq!(calculate_final_checksum(&compacted), 1928);
+    }
+
+    #[test]
+    fn test_checksum_single_file() {
+        assert_eq!(calculate_checksum(&digits("5")), 0);
+    }
+
+    #[test]
+    fn test_checksum_alternating_files() {
+        assert_eq!(calculate_checksum(&digits("11111")), 4);
+    }
+
+    #[test]
+    fn test_checksum_simple_disk_map() {
+        assert_eq!(calculate_checksum(&digits("12345")), 60);
+    }
+
+    #[test]
+    fn test_checksum_no_free_space() {
+        assert_eq!(calculate_checksum(&digits("90909")), 513);
+    }
+
+    #[test]
+    fn test_checksum_puzzle_example() {
+        let input = "2333133121414131402";
+        assert_eq!(calculate_checksum(&digits(input)), 1928);
+    }
+
+    #[test]
+    // Fails: attempt to add with overflow (main.rs:86:5, calculate_final_checksum)
+    fn test_checksum_puzzle_example_2() {
+        let input = "2333133121414131402";
+        assert_eq!(calculate_checksum_2(&digits(input)), 2858);
+    }
+
+    mod properties {
+        use super::*;
+        use aoc_common::testing::generated;
+        use aoc_common::testing::proptest::prelude::*;
+        use std::collections::BTreeMap;
+
+        /// Blocks of each file, by ID.
+        fn file_sizes(blocks: &[i32]) -> BTreeMap<i32, usize> {
+            let mut sizes = BTreeMap::new();
+            for &id in blocks.iter().filter(|&&id| id >= 0) {
+                *sizes.entry(id).or_default() += 1;
+            }
+            sizes
+        }
+
+        proptest! {
+            #[test]
+            fn compaction_keeps_every_block(input in generated::<Day09>(1..200)) {
+                let disk = compose_file_blocks(&parse_input(&input).unwrap());
+                let compacted = compact_disk(&disk);
+                prop_assert!(!compacted.contains(&-1));
+                prop_assert_eq!(file_sizes(&compacted), file_sizes(&disk));
+            }
+
+            #[test]
+            fn file_compaction_moves_whole_files_left(input in generated::<Day09>(1..200)) {
+                let disk = compose_file_blocks(&parse_input(&input).unwrap());
+                let compacted = compact_disk_files(&disk);
+                prop_assert_eq!(compacted.len(), disk.len());
+                prop_assert_eq!(file_sizes(&compacted), file_sizes(&disk));
+
+                let before = Disk::new(&disk).files;
+                let after = Disk::new(&compacted).files;
+                // One span per file, so each stayed in one piece
+                prop_assert_eq!(after.len(), before.len());
+                for ((id, from, _), (moved_id, to, _)) in before.into_iter().zip(after) {
+                    prop_assert_eq!(id, moved_id);
+                    prop_assert!(to <= from, "file {} moved right", id);
+                }
+            }
+
+            #[test]
+            fn checksum_matches_reference(input in generated::<Day09>(1..200)) {
+                let disk_map = parse_input(&input).unwrap();
+                prop_assert_eq!(calculate_checksum(&disk_map), checksum_by_blocks(&disk_map));
+            }
+        }
+    }
+}
@@ -0,0 +1,12 @@
+use aoc09::{calculate_checksum, calculate_checksum_2, parse_input};
+
+const EXAMPLE: &str = include_str!("../examples/part1.txt");
+
+#[test]
+fn test_example() {
+    let disk_map = parse_input(EXAMPLE).unwrap();
+    assert_eq!(calculate_checksum(&disk_map), 1928);
+    assert_eq!(calculate_checksum_2(&disk_map), 2858);
+}
+
+aoc_common::parse_properties!(aoc09::Day09);
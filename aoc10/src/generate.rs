@@ -0,0 +1,18 @@
+//! Random topographic maps.
+
+use aoc_common::Rng;
+
+/// A `size` by `size` map of heights that mostly rise one step at a time
+/// along diagonals, with some noise, so there are trails of every length.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let side = size.max(1);
+    let mut out = String::with_capacity(side * (side + 1));
+    for y in 0..side {
+        for x in 0..side {
+            let height = ((x + y) as i64 + rng.range(-1..=1)).rem_euclid(10);
+            out.push(char::from(b'0' + height as u8));
+        }
+        out.push('\n');
+    }
+    out
+}
@@ -0,0 +1,283 @@
+//! Day 10: Hoof It.
+//!
+//! Hiking trails climb a topographic map from height 0 to 9 one step at a
+//! time. [`sum_trailhead_scores`] counts the peaks each trailhead reaches and
+//! [`sum_unique_trailhead_scores`] the distinct trails.
+
+pub mod generate;
+
+use aoc_common::search::{self, ShortestPaths};
+use aoc_common::{Answer, Cell, Color, Frame, Grid, Lint, ParseError, Point, Rng, Solution};
+use std::collections::HashSet;
+
+/// The [`Solution`] for day 10.
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    const INPUT: &'static str = include_str!("../10-input.txt");
+    type Input = Grid<u8>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_grid(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        sum_trailhead_scores(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        sum_unique_trailhead_scores(input).into()
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+
+    fn lint(input: &str) -> Vec<ParseError> {
+        Lint::run(input, |lint| {
+            lint.grid(lint.lines(), |c| c.is_ascii_digit())
+        })
+    }
+}
+
+/// Sum over the trailheads of the number of 9s each can reach.
+pub fn sum_trailhead_scores(grid: &Grid<u8>) -> usize {
+    get_start_positions(grid)
+        .into_iter()
+        .map(|start| find_reachable_nines(grid, start).len())
+        .sum()
+}
+
+/// Sum over the trailheads of the number of distinct trails from each.
+pub fn sum_unique_trailhead_scores(grid: &Grid<u8>) -> usize {
+    // Every trail climbs one step at a time, so all trails to a 9 are
+    // shortest paths to it
+    get_start_positions(grid)
+        .into_iter()
+        .map(|start| {
+            let climbs = climb(grid, [start]);
+            nines(grid, &climbs)
+                .map(|nine| climbs.count_paths(&nine))
+                .sum::<usize>()
+        })
+        .sum()
+}
+
+/// Parses the map, one digit per height.
+pub fn parse_grid(input: &str) -> Result<Grid<u8>, ParseError> {
+    Grid::parse(input, |c| c.to_digit(10).map(|height| height as u8))
+}
+
+/// Tiles on at least one trail from a trailhead to a 9.
+pub fn trail_tiles(grid: &Grid<u8>) -> HashSet<Position> {
+    let climbs = climb(grid, get_start_positions(grid));
+    climbs.on_paths_to(nines(grid, &climbs))
+}
+
+/// The map shaded by height, with the trails picked out in green.
+pub fn frame(grid: &Grid<u8>) -> Frame {
+    let trails = trail_tiles(grid);
+    Frame::from_grid(grid, |pos, &height| {
+        let brightness = 0.3 + 0.07 * height as f64;
+        let color = if trails.contains(&pos) {
+            Color::GREEN
+        } else {
+            Color::BROWN
+        };
+        Cell::new((b'0' + height) as char, color.dim(brightness))
+    })
+}
+
+type Position = Point;
+
+fn get_start_positions(grid: &Grid<u8>) -> HashSet<Position> {
+    // Find all positions with height 0
+    grid.iter()
+        .filter(|(_, &height)| height == 0)
+        .map(|(pos, _)| pos)
+        .collect()
+}
+
+fn get_valid_next_positions(grid: &Grid<u8>, pos: Position) -> Vec<Position> {
+    // Return positions of all adjacent cells with height = current + 1
+    let target_height = grid[pos] + 1;
+
+    grid.neighbors4(pos)
+        .filter(|&next| grid[next] == target_height)
+        .collect()
+}
+
+/// Everywhere reachable climbing from `starts`.
+fn climb(
+    grid: &Grid<u8>,
+    starts: impl IntoIterator<Item = Position>,
+) -> ShortestPaths<Position, usize> {
+    search::bfs(starts, |&pos| get_valid_next_positions(grid, pos))
+}
+
+fn nines<'a>(
+    grid: &'a Grid<u8>,
+    climbs: &'a ShortestPaths<Position, usize>,
+) -> impl Iterator<Item = Position> + 'a {
+    climbs.states().copied().filter(|&pos| grid[pos] == 9)
+}
+
+fn find_reachable_nines(grid: &Grid<u8>, start: Position) -> HashSet<Position> {
+    nines(grid, &climb(grid, [start])).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::check_examples::<Day10>(concat!(env!("CARGO_MANIFEST_DIR"), "/examples"));
+    }
+
+    #[test]
+    fn test_trail_tiles() {
+        let grid = parse_grid("0125\n1234\n8765\n9876").unwrap();
+        let trails = trail_tiles(&grid);
+        // Every tile climbs from the 0 to the 9 except the dead-end 5
+        assert_eq!(trails.len(), 15);
+        assert!(!trails.contains(&Point { x: 3, y: 0 }));
+        assert_eq!(frame(&grid).to_text(), "0125\n1234\n8765\n9876\n");
+    }
+
+    mod sum_trailhead_scores {
+        use super::*;
+
+        #[test]
+        fn simple_vertical_path() {
+            let input = "\
+0
+1
+2
+3
+4
+5
+6
+7
+8
+9";
+            assert_eq!(sum_trailhead_scores(&parse_grid(input).unwrap()), 1); // One trailhead, one path
+        }
+
+        #[test]
+        fn multiple_trailheads() {
+            let input = "\
+1055955
+2555855
+3555755
+4567654
+5558553
+5559552
+5555501";
+            assert_eq!(sum_trailhead_scores(&parse_grid(input).unwrap()), 3); // Two trailheads with scores 1 and 2
+        }
+
+        #[test]
+        fn example_from_puzzle() {
+            let input = "\
+89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732";
+            assert_eq!(sum_trailhead_scores(&parse_grid(input).unwrap()), 36); // Nine trailheads
+        }
+
+        #[test]
+        fn no_valid_paths() {
+            let input = "\
+012
+901
+890";
+            assert_eq!(sum_trailhead_scores(&parse_grid(input).unwrap()), 0); // No valid paths possible
+        }
+    }
+
+    mod sum_unique_trailhead_scores {
+        use super::*;
+
+        #[test]
+        // .....0.
+        // ..4321.
+        // ..5..2.
+        // ..6543.
+        // ..7..4.
+        // ..8765.
+        // ..9....
+        fn single_trailhead_single_peak() {
+            let input = "\
+1111808
+1143211
+1151121
+1165431
+1171141
+1187651
+1191111";
+            assert_eq!(sum_unique_trailhead_scores(&parse_grid(input).unwrap()), 3);
+        }
+
+        #[test]
+        // ..90..9
+        // ...1.98
+        // ...2..7
+        // 6543456
+        // 765.987
+        // 876....
+        // 987....
+        fn single_trailhead_multiple_peaks() {
+            let input = "\
+1190819
+1111198
+1112117
+6543456
+7651987
+8761111
+9871111";
+            assert_eq!(sum_unique_trailhead_scores(&parse_grid(input).unwrap()), 13);
+        }
+
+        #[test]
+        // 012345
+        // 123456
+        // 234567
+        // 345678
+        // 4.6789
+        // 56789.
+        fn single_tailhead_max_paths() {
+            let input = "\
+012345
+123456
+234567
+345678
+416789
+567891";
+            assert_eq!(
+                sum_unique_trailhead_scores(&parse_grid(input).unwrap()),
+                227
+            );
+        }
+
+        #[test]
+        fn many_trailheads_many_peaks() {
+            let input = "\
+89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732";
+            assert_eq!(sum_unique_trailhead_scores(&parse_grid(input).unwrap()), 81);
+        }
+    }
+}
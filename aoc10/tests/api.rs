@@ -0,0 +1,12 @@
+use aoc10::{parse_grid, sum_trailhead_scores, sum_unique_trailhead_scores};
+
+const EXAMPLE: &str = include_str!("../examples/part1.txt");
+
+#[test]
+fn test_example() {
+    let grid = parse_grid(EXAMPLE).unwrap();
+    assert_eq!(sum_trailhead_scores(&grid), 36);
+    assert_eq!(sum_unique_trailhead_scores(&grid), 81);
+}
+
+aoc_common::parse_properties!(aoc10::Day10);
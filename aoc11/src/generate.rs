@@ -0,0 +1,15 @@
+//! Random stones.
+
+use aoc_common::Rng;
+
+/// `size` stones engraved with numbers of up to seven digits, like the
+/// eight in the real inputs.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let stones: Vec<String> = (0..size.max(1))
+        .map(|_| {
+            let digits = rng.range(1..=7) as u32;
+            rng.range(0..=10_i64.pow(digits) - 1).to_string()
+        })
+        .collect();
+    stones.join(" ") + "\n"
+}
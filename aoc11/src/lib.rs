@@ -0,0 +1,226 @@
+//! Day 11: Plutonian Pebbles.
+//!
+//! Stones change each time you blink: 0 becomes 1, an even number of digits
+//! splits in two, and anything else is multiplied by 2024. [`blink_n_times`]
+//! simulates the stones and [`BlinkLengthPredictor`] counts them without
+//! building the list.
+
+pub mod generate;
+
+use aoc_common::{Answer, Lint, ParseError, Rng, Solution, Source};
+use itertools::Itertools;
+use std::collections::{HashMap, HashSet};
+
+/// The [`Solution`] for day 11.
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const INPUT: &'static str = include_str!("../11-input.txt");
+    type Input = Vec<u64>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(stones: &Self::Input) -> Answer {
+        blink_n_times(stones, 25).len().into()
+    }
+
+    fn part2(stones: &Self::Input) -> Answer {
+        let predictor = BlinkLengthPredictor::new();
+        stones
+            .iter()
+            .map(|&num| predictor.predict_length(num, 75))
+            .sum::<usize>()
+            .into()
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+
+    fn lint(input: &str) -> Vec<ParseError> {
+        Lint::run(input, |lint| {
+            let source = lint.source();
+            lint.each(input.split_whitespace(), |stone| {
+                source.number::<u64>(stone)
+            });
+        })
+    }
+}
+
+/// Parses the stones, separated by whitespace.
+pub fn parse_input(input: &str) -> Result<Vec<u64>, ParseError> {
+    let source = Source::new(input)?;
+    input.split_whitespace().map(|s| source.number(s)).collect()
+}
+
+fn _print_transitions(transitions: &StateTransitions) {
+    for (&from, to_states) in transitions.iter().sorted() {
+        println!("{:7} -> {:?}", from, to_states);
+    }
+}
+
+/// The stones after blinking `n` times.
+pub fn blink_n_times(input: &[u64], n: u64) -> Vec<u64> {
+    let mut stones = input.to_vec();
+    for _ in 0..n {
+        stones = blink(&stones);
+    }
+    stones
+}
+
+fn blink(stones: &[u64]) -> Vec<u64> {
+    let mut new_stones = Vec::with_capacity(stones.len() * 2);
+
+    for &stone in stones {
+        if stone == 0 {
+            new_stones.push(1);
+        } else if has_even_digits(stone) {
+            let (left, right) = split_number(stone);
+            new_stones.push(left);
+            new_stones.push(right);
+        } else {
+            new_stones.push(stone * 2024);
+        }
+    }
+
+    new_stones
+}
+
+fn has_even_digits(stone: u64) -> bool {
+    stone.to_string().len().is_multiple_of(2)
+}
+
+fn split_number(n: u64) -> (u64, u64) {
+    let s = n.to_string();
+    let mid = s.len() / 2;
+
+    let left = s[..mid].parse().unwrap();
+    let right = s[mid..].parse().unwrap();
+
+    (left, right)
+}
+
+/// Counts the stones one stone turns into, using the lengths of the small
+/// set of stones every stone eventually breaks down into.
+pub struct BlinkLengthPredictor {
+    // Maps (stone, num_blinks) -> resulting sequence length
+    cached_lengths: HashMap<(u64, u8), usize>,
+    // The set of 54 stable states we discovered
+    stable_states: HashSet<u64>,
+}
+
+type StateTransitions = HashMap<u64, Vec<u64>>;
+
+impl Default for BlinkLengthPredictor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BlinkLengthPredictor {
+    /// Precomputes lengths for up to 75 blinks.
+    pub fn new() -> Self {
+        let stable_states = [
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 20, 24, 26, 28, 32, 36, 40, 48, 56, 57, 60, 67, 72, 77,
+            80, 84, 86, 91, 94, 96, 2024, 2048, 2457, 2608, 2867, 2880, 3277, 3686, 4048, 6032,
+            6072, 8096, 9184, 9456, 10120, 12144, 14168, 16192, 18216, 20482880, 24579456,
+            28676032, 32772608, 36869184,
+        ]
+        .into_iter()
+        .collect();
+
+        let mut predictor = Self {
+            cached_lengths: HashMap::new(),
+            stable_states,
+        };
+        predictor.precalculate_lengths(75);
+        predictor
+    }
+
+    fn build_transition_map(&self) -> StateTransitions {
+        let mut transitions = HashMap::new();
+
+        for &state in &self.stable_states {
+            let next_states = blink(&[state]);
+            // All resulting stones should be stable states
+            debug_assert!(next_states.iter().all(|s| self.stable_states.contains(s)));
+            transitions.insert(state, next_states);
+        }
+
+        transitions
+    }
+
+    fn precalculate_lengths(&mut self, iterations: u8) {
+        let transitions = self.build_transition_map();
+
+        // Start with lengths after 1 blink
+        for (&state, next_states) in &transitions {
+            self.cached_lengths.insert((state, 1), next_states.len());
+        }
+
+        // For each subsequent blink, use previous results
+        for blinks in 2..=iterations {
+            for &state in &self.stable_states {
+                let next_states = &transitions[&state];
+                let total_length: usize = next_states
+                    .iter()
+                    .map(|&s| self.cached_lengths[&(s, blinks - 1)])
+                    .sum();
+                self.cached_lengths.insert((state, blinks), total_length);
+            }
+        }
+    }
+
+    /// Number of stones `stone` becomes after `blinks` blinks, at most 75.
+    pub fn predict_length(&self, stone: u64, blinks: u8) -> usize {
+        // If no blinks left, just return 1 (current stone)
+        if blinks == 0 {
+            return 1;
+        }
+
+        // If stone is in stable states, use cached result
+        if self.stable_states.contains(&stone) {
+            return self.cached_lengths[&(stone, blinks)];
+        }
+
+        // Otherwise, do one blink and recurse
+        let next_stones = blink(&[stone]);
+        next_stones
+            .iter()
+            .map(|&s| self.predict_length(s, blinks - 1))
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::check_examples::<Day11>(concat!(env!("CARGO_MANIFEST_DIR"), "/examples"));
+    }
+
+    #[test]
+    fn test_single_blink() {
+        let input = vec![0, 1, 10, 99, 999];
+        let expected = [1, 2024, 1, 0, 9, 9, 2021976];
+        assert_eq!(blink_n_times(&input, 1).len(), expected.len());
+    }
+
+    #[test]
+    fn test_sequence_growth() {
+        let input = vec![125, 17];
+        // After 6 blinks should have 22 stones
+        assert_eq!(blink_n_times(&input, 6).len(), 22);
+    }
+
+    #[test]
+    fn test_example_25_blinks() {
+        let input = vec![125, 17];
+        assert_eq!(blink_n_times(&input, 25).len(), 55312);
+    }
+}
@@ -0,0 +1,23 @@
+use aoc11::{blink_n_times, parse_input, BlinkLengthPredictor};
+
+const EXAMPLE: &str = include_str!("../examples/part1.txt");
+
+#[test]
+fn test_example() {
+    let stones = parse_input(EXAMPLE).unwrap();
+    assert_eq!(blink_n_times(&stones, 6).len(), 22);
+    assert_eq!(blink_n_times(&stones, 25).len(), 55312);
+}
+
+#[test]
+fn test_predictor_matches_simulation() {
+    let stones = parse_input(EXAMPLE).unwrap();
+    let predictor = BlinkLengthPredictor::new();
+    let predicted: usize = stones
+        .iter()
+        .map(|&stone| predictor.predict_length(stone, 25))
+        .sum();
+    assert_eq!(predicted, 55312);
+}
+
+aoc_common::parse_properties!(aoc11::Day11);
@@ -0,0 +1,24 @@
+//! Random garden maps.
+
+use aoc_common::Rng;
+
+/// A `size` by `size` map where each plot usually continues the plant to
+/// its left or above, so regions grow into irregular shapes with holes.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let side = size.max(1);
+    let mut plots = vec![vec![b'A'; side]; side];
+    for y in 0..side {
+        for x in 0..side {
+            plots[y][x] = match rng.below(10) {
+                0..=3 if x > 0 => plots[y][x - 1],
+                4..=7 if y > 0 => plots[y - 1][x],
+                _ => b'A' + rng.below(26) as u8,
+            };
+        }
+    }
+
+    plots
+        .iter()
+        .map(|row| String::from_utf8_lossy(row).into_owned() + "\n")
+        .collect()
+}
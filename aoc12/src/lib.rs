@@ -0,0 +1,545 @@
+//! Day 12: Garden Groups.
+//!
+//! Plots of the same plant form regions that need fencing.
+//! [`calculate_price`] prices each region by area times perimeter, and by
+//! area times number of sides for the bulk discount.
+
+pub mod generate;
+
+use aoc_common::par::prelude::*;
+use aoc_common::search;
+use aoc_common::{Answer, Cell, Color, Frame, Grid, Lint, ParseError, Point, Rng, Solution};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+/// The [`Solution`] for day 12.
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    const INPUT: &'static str = include_str!("../12-input.txt");
+    type Input = Grid<u8>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(plots: &Self::Input) -> Answer {
+        garden_price(plots).0.into()
+    }
+
+    fn part2(plots: &Self::Input) -> Answer {
+        garden_price(plots).1.into()
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+
+    fn lint(input: &str) -> Vec<ParseError> {
+        Lint::run(input, |lint| {
+            lint.grid(lint.lines(), |c| c.is_ascii_uppercase())
+        })
+    }
+}
+
+/// Fencing prices of every region, by perimeter and by number of sides.
+pub fn calculate_price(input: &str) -> Result<(u64, u64), ParseError> {
+    Ok(garden_price(&parse_input(input)?))
+}
+
+/// Like [`calculate_price`], for an already parsed map.
+pub fn garden_price(plots: &Grid<u8>) -> (u64, u64) {
+    let grid = Garden::new(plots.clone());
+    let regions = find_all_regions(&grid);
+
+    let result = regions
+        .par_iter()
+        .map(|region| {
+            let area = region.len() as u64;
+            let perimeter = grid.calculate_perimeter(region) as u64;
+            area * perimeter
+        })
+        .sum();
+
+    let result_2 = regions
+        .par_iter()
+        .map(|region| {
+            let area = region.len() as u64;
+            let sides = grid.calculate_sides(region) as u64;
+            area * sides
+        })
+        .sum();
+
+    (result, result_2)
+}
+
+/// Parses the garden map, one uppercase letter per plot.
+pub fn parse_input(input: &str) -> Result<Grid<u8>, ParseError> {
+    Grid::parse(input, |c| c.is_ascii_uppercase().then_some(c as u8))
+}
+
+/// The map with each region in its own colour.
+pub fn frame(plots: &Grid<u8>) -> Frame {
+    let mut regions = find_all_regions(&Garden::new(plots.clone()));
+    // Regions are found in parallel, so order them for stable colours
+    regions.sort_by_key(|region| region.iter().map(|pos| (pos.y, pos.x)).min());
+
+    let mut frame = Frame::from_grid(plots, |_, &plant| Cell::new(plant as char, Color::WHITE));
+    for (i, region) in regions.iter().enumerate() {
+        frame.tint(region.iter().copied(), Color::distinct(i));
+    }
+    frame
+}
+
+type Position = Point;
+
+struct Garden {
+    plots: Grid<u8>,
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+enum EdgeType {
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+struct Edge {
+    start: Position,
+    edge_type: EdgeType,
+    is_inner: bool,
+}
+
+impl Garden {
+    fn new(plots: Grid<u8>) -> Self {
+        Self { plots }
+    }
+
+    fn get_neighbors(&self, pos: Position) -> Vec<Position> {
+        self.plots.neighbors4(pos).collect()
+    }
+
+    fn get_cell(&self, pos: Position) -> u8 {
+        self.plots[pos]
+    }
+
+    fn calculate_perimeter(&self, region: &FxHashSet<Position>) -> usize {
+        let mut perimeter = 0;
+        for &pos in region {
+            let neighbors = self.get_neighbors(pos);
+
+            // Grid boundaries = 4 - neighbors.len()
+            perimeter += 4 - neighbors.len();
+
+            // Check all 4 cardinal directions
+            for neighbor in &neighbors {
+                // Edge of grid or different type = perimeter edge
+                if !region.contains(neighbor) {
+                    perimeter += 1;
+                }
+            }
+        }
+        perimeter
+    }
+
+    fn calculate_sides(&self, region: &FxHashSet<Position>) -> usize {
+        let edges = self.collect_edges(region);
+
+        // Group edges by edge_type and inner/outer state using a HashMap
+        let mut edge_groups: FxHashMap<(EdgeType, bool), Vec<Edge>> = FxHashMap::default();
+        for edge in edges {
+            edge_groups
+                .entry((edge.edge_type, edge.is_inner))
+                .or_default()
+                .push(edge);
+        }
+
+        // Helper function to count sides in a group of edges
+        fn count_sides(edges: &[Edge]) -> usize {
+            if edges.is_empty() {
+                return 0;
+            }
+
+            // Determine if we're dealing with horizontal or vertical edges
+            let is_horizontal = matches!(edges[0].edge_type, EdgeType::Top | EdgeType::Bottom);
+
+            // Group edges by their fixed coordinate (y for horizontal, x for vertical)
+            let by_line: FxHashMap<usize, Vec<&Edge>> =
+                edges.iter().fold(FxHashMap::default(), |mut acc, edge| {
+                    let key = if is_horizontal {
+                        edge.start.y
+                    } else {
+                        edge.start.x
+                    };
+                    acc.entry(key).or_default().push(edge);
+                    acc
+                });
+
+            by_line
+                .values()
+                .map(|line_edges| {
+                    let mut sorted_edges = line_edges.to_vec();
+                    // Sort by the varying coordinate (x for horizontal, y for vertical)
+                    sorted_edges.sort_by_key(|e| if is_horizontal { e.start.x } else { e.start.y });
+
+                    // Count segments using windows
+                    1 + sorted_edges
+                        .windows(2)
+                        .filter(|window| {
+                            let [e1, e2] = window else { unreachable!() };
+                            let (pos1, pos2) = if is_horizontal {
+                                (e1.start.x, e2.start.x)
+                            } else {
+                                (e1.start.y, e2.start.y)
+                            };
+
+                            pos1.abs_diff(pos2) > 1
+                                || (is_horizontal && e1.start.y != e2.start.y)
+                                || (!is_horizontal && e1.start.x != e2.start.x)
+                        })
+                        .count()
+                })
+                .sum()
+        }
+
+        // Calculate sides for all edge groups and sum them
+        edge_groups.values().map(|edges| count_sides(edges)).sum()
+    }
+
+    fn collect_edges(&self, region: &FxHashSet<Position>) -> FxHashSet<Edge> {
+        type PosCompare = fn(&Position, &Position) -> bool;
+
+        let edge_checks: [(EdgeType, PosCompare); 4] = [
+            (EdgeType::Top, |n, p| n.y < p.y),
+            (EdgeType::Bottom, |n, p| n.y > p.y),
+            (EdgeType::Left, |n, p| n.x < p.x),
+            (EdgeType::Right, |n, p| n.x > p.x),
+        ];
+
+        region
+            .iter()
+            .flat_map(|&pos| {
+                let neighbors = self.get_neighbors(pos);
+
+                edge_checks.iter().filter_map(move |&(edge_type, check)| {
+                    // If no neighbor in this direction is part of the region
+                    if !neighbors
+                        .iter()
+                        .any(|n| check(n, &pos) && region.contains(n))
+                    {
+                        Some(Edge {
+                            start: pos,
+                            edge_type,
+                            // But if there is a neighbor in this direction (even outside region)
+                            is_inner: neighbors.iter().any(|n| check(n, &pos)),
+                        })
+                    } else {
+                        None
+                    }
+                })
+            })
+            .collect()
+    }
+}
+
+fn find_region(grid: &Garden, start: Position) -> FxHashSet<Position> {
+    let value = grid.get_cell(start);
+    let region = search::bfs([start], move |&pos| {
+        grid.plots
+            .neighbors4(pos)
+            .filter(move |&neighbor| grid.get_cell(neighbor) == value)
+    });
+    region.states().copied().collect()
+}
+
+// Regions in reading order of their first plot. Finding them is a flood
+// fill over one visited set, so it stays on one thread and only the pricing
+// is split up
+fn find_all_regions(grid: &Garden) -> Vec<FxHashSet<Position>> {
+    let mut visited: FxHashSet<Position> = FxHashSet::default();
+    let mut regions = Vec::new();
+    for pos in grid.plots.positions() {
+        if !visited.contains(&pos) {
+            let region = find_region(grid, pos);
+            visited.extend(&region);
+            regions.push(region);
+        }
+    }
+    regions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::par;
+
+    const EXAMPLE: &str = include_str!("../examples/part1.txt");
+
+    #[test]
+    fn test_examples() {
+        aoc_common::check_examples::<Day12>(concat!(env!("CARGO_MANIFEST_DIR"), "/examples"));
+    }
+
+    #[test]
+    fn test_frame() {
+        let plots = parse_input("AAB\nABB\nAAB").unwrap();
+        let frame = frame(&plots);
+        let color = |x, y| frame.get(Point { x, y }).unwrap().color;
+        assert_eq!(color(0, 0), color(1, 2));
+        assert_ne!(color(0, 0), color(2, 0));
+        assert_eq!(frame.to_text(), "AAB\nABB\nAAB\n");
+    }
+
+    #[test]
+    fn test_any_thread_count() {
+        let plots = parse_input(EXAMPLE).unwrap();
+        let expected = garden_price(&plots);
+        assert_eq!(expected, (1930, 1206));
+        for threads in [1, 2, 5] {
+            assert_eq!(
+                par::with_threads(threads, || garden_price(&plots)),
+                expected
+            );
+        }
+    }
+
+    mod collect_edges {
+        use super::*;
+
+        #[test]
+        fn simple_square() {
+            let grid = Garden::new(
+                parse_input(
+                    "\
+AAAA
+AAAA
+AAAA
+AAAA",
+                )
+                .unwrap(),
+            );
+            let region = find_region(&grid, Position { x: 0, y: 0 });
+            assert_eq!(grid.collect_edges(&region).len(), 16);
+        }
+    }
+
+    mod calculate_sides {
+        use super::*;
+
+        #[test]
+        fn simple_square() {
+            let grid = Garden::new(
+                parse_input(
+                    "\
+AAAA
+AAAA
+AAAA
+AAAA",
+                )
+                .unwrap(),
+            );
+            let region = find_region(&grid, Position { x: 0, y: 0 });
+            assert_eq!(grid.calculate_sides(&region), 4); // Just 4 sides for a square
+        }
+
+        #[test]
+        fn e_shaped_region() {
+            let grid = Garden::new(
+                parse_input(
+                    "\
+EEEEE
+EXXXX
+EEEEE
+EXXXX
+EEEEE",
+                )
+                .unwrap(),
+            );
+            let regions = find_all_regions(&grid);
+            // Find the E region (should be the largest)
+            let e_region = regions.iter().max_by_key(|r| r.len()).unwrap();
+            assert_eq!(grid.calculate_sides(e_region), 12);
+        }
+
+        #[test]
+        fn region_with_hole() {
+            let grid = Garden::new(
+                parse_input(
+                    "\
+AAAAAA
+AAABBA
+AAABBA
+ABBAAA
+ABBAAA
+AAAAAA",
+                )
+                .unwrap(),
+            );
+            let regions = find_all_regions(&grid);
+            // A region should have 12 sides (4 outer + 8 inner)
+            let a_region = regions.iter().max_by_key(|r| r.len()).unwrap();
+            assert_eq!(grid.calculate_sides(a_region), 12);
+        }
+    }
+
+    mod calculate_perimeter {
+        use super::*;
+
+        #[test]
+        fn simple_square() {
+            let grid = Garden::new(
+                parse_input(
+                    "\
+AAAA
+AAAA
+AAAA
+AAAA",
+                )
+                .unwrap(),
+            );
+            let region = find_region(&grid, Position { x: 0, y: 0 });
+            assert_eq!(grid.calculate_perimeter(&region), 16);
+        }
+
+        #[test]
+        fn multiple_regions() {
+            let grid = Garden::new(
+                parse_input(
+                    "\
+AAAA
+BBCD
+BBCC
+EEEC",
+                )
+                .unwrap(),
+            );
+            let regions = find_all_regions(&grid);
+            assert_eq!(grid.calculate_perimeter(&regions[0]), 10); // A
+            assert_eq!(grid.calculate_perimeter(&regions[1]), 8); // B
+            assert_eq!(grid.calculate_perimeter(&regions[2]), 10); // C
+            assert_eq!(grid.calculate_perimeter(&regions[3]), 4); // D
+            assert_eq!(grid.calculate_perimeter(&regions[4]), 8); // E
+        }
+
+        #[test]
+        fn region_with_holes() {
+            let grid = Garden::new(
+                parse_input(
+                    "\
+OOOOO
+OXOXO
+OOOOO
+OXOXO
+OOOOO",
+                )
+                .unwrap(),
+            );
+            let regions = find_all_regions(&grid);
+            assert_eq!(grid.calculate_perimeter(&regions[0]), 36); // O
+            assert_eq!(grid.calculate_perimeter(&regions[1]), 4); // X1
+            assert_eq!(grid.calculate_perimeter(&regions[2]), 4); // X2
+            assert_eq!(grid.calculate_perimeter(&regions[3]), 4); // X3
+            assert_eq!(grid.calculate_perimeter(&regions[4]), 4); // X4
+        }
+    }
+
+    mod find_regions {
+        use super::*;
+
+        #[test]
+        fn simple_square() {
+            let grid = Garden::new(
+                parse_input(
+                    "\
+AAAA
+AAAA
+AAAA
+AAAA",
+                )
+                .unwrap(),
+            );
+            let regions = find_all_regions(&grid);
+            assert_eq!(regions.len(), 1);
+            assert_eq!(regions[0].len(), 16);
+        }
+
+        #[test]
+        fn test_find_region() {
+            let grid = Garden::new(
+                parse_input(
+                    "\
+AAAA
+BBCD
+BBCC
+EEEC",
+                )
+                .unwrap(),
+            );
+
+            // Starting from (0,0), should find all 'A's
+            let region = find_region(&grid, Position { x: 0, y: 0 });
+            assert_eq!(region.len(), 4);
+            assert!(region.contains(&Position { x: 0, y: 0 }));
+            assert!(region.contains(&Position { x: 1, y: 0 }));
+            assert!(region.contains(&Position { x: 2, y: 0 }));
+            assert!(region.contains(&Position { x: 3, y: 0 }));
+        }
+
+        #[test]
+        fn test_find_all_regions() {
+            let grid = Garden::new(
+                parse_input(
+                    "\
+AAAA
+BBCD
+BBCC
+EEEC",
+                )
+                .unwrap(),
+            );
+
+            let regions = find_all_regions(&grid);
+            assert_eq!(regions.len(), 5); // A, B, C, D, and E regions
+        }
+    }
+
+    mod calculate_price {
+        use super::*;
+
+        #[test]
+        fn simple_square() {
+            let input = "\
+AAAA
+AAAA
+AAAA
+AAAA";
+            let (result, _) = calculate_price(input).unwrap();
+            assert_eq!(result, 256);
+        }
+
+        #[test]
+        fn example_from_prompt() {
+            let input = "\
+AAAA
+BBCD
+BBCC
+EEEC";
+            let (result, _) = calculate_price(input).unwrap();
+            assert_eq!(result, 140);
+        }
+
+        #[test]
+        fn region_with_holes() {
+            let input = "\
+OOOOO
+OXOXO
+OOOOO
+OXOXO
+OOOOO";
+            let (result, _) = calculate_price(input).unwrap();
+            assert_eq!(result, 772);
+        }
+    }
+}
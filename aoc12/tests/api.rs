@@ -0,0 +1,17 @@
+use aoc12::{calculate_price, garden_price, parse_input};
+
+const EXAMPLE: &str = include_str!("../examples/part1.txt");
+
+#[test]
+fn test_example() {
+    assert_eq!(calculate_price(EXAMPLE).unwrap(), (1930, 1206));
+    assert_eq!(garden_price(&parse_input(EXAMPLE).unwrap()), (1930, 1206));
+}
+
+#[test]
+fn test_bad_input() {
+    let err = calculate_price("AAB\nAaB\n").unwrap_err();
+    assert_eq!((err.line, err.column), (2, 2));
+}
+
+aoc_common::parse_properties!(aoc12::Day12);
@@ -0,0 +1,28 @@
+//! Random claw machines.
+
+use aoc_common::Rng;
+
+/// `size` machines with buttons moving 10 to 99 each way. About half the
+/// prizes can be won, being a combination of the buttons of at most 100
+/// presses each.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let mut machines = Vec::new();
+    for _ in 0..size.max(1) {
+        let a = (rng.range(10..=99), rng.range(10..=99));
+        let b = (rng.range(10..=99), rng.range(10..=99));
+        let prize = if rng.chance(0.5) {
+            let (presses_a, presses_b) = (rng.range(0..=100), rng.range(0..=100));
+            (
+                presses_a * a.0 + presses_b * b.0,
+                presses_a * a.1 + presses_b * b.1,
+            )
+        } else {
+            (rng.range(1000..=20_000), rng.range(1000..=20_000))
+        };
+        machines.push(format!(
+            "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n",
+            a.0, a.1, b.0, b.1, prize.0, prize.1
+        ));
+    }
+    machines.join("\n")
+}
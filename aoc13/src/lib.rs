@@ -0,0 +1,324 @@
+//! Day 13: Claw Contraption.
+//!
+//! Each [`Machine`] has two buttons that move the claw and a prize to reach.
+//! [`calculate_tokens`] finds the cheapest presses for every winnable prize,
+//! and [`augment_machines`] moves the prizes for part two.
+
+pub mod generate;
+
+use aoc_common::par::prelude::*;
+use aoc_common::{Answer, Lint, ParseError, Rng, Solution, Source};
+
+/// The [`Solution`] for day 13.
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    const INPUT: &'static str = include_str!("../13-input.txt");
+    type Input = Vec<Machine>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        calculate_tokens(input).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        calculate_tokens(&augment_machines(input)).into()
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+
+    fn lint(input: &str) -> Vec<ParseError> {
+        Lint::run(input, |lint| {
+            for pair in parse_pairs(lint.source()) {
+                lint.check(pair);
+            }
+        })
+    }
+}
+
+// The lines describing a machine, by prefix and the sign before each number
+const LINES: [(&str, char); 3] = [("Button A: ", '+'), ("Button B: ", '+'), ("Prize: ", '=')];
+
+/// Parses machines of three lines each, separated by blank lines.
+pub fn parse_input(input: &str) -> Result<Vec<Machine>, ParseError> {
+    let source = Source::new(input)?;
+    let pairs = parse_pairs(source).collect::<Result<Vec<_>, _>>()?;
+    Ok(pairs
+        .chunks(3)
+        .map(|pairs| Machine::new(pairs[0], pairs[1], pairs[2]))
+        .collect())
+}
+
+// Every line's pair in turn, then an error if the last machine is cut short
+fn parse_pairs(source: Source<'_>) -> impl Iterator<Item = Result<(i64, i64), ParseError>> + '_ {
+    let lines: Vec<&str> = source
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .collect();
+    let missing = match lines.len() % LINES.len() {
+        0 => None,
+        next => Some(source.expected(
+            source.text().trim_end(),
+            format!("a line starting {:?}", LINES[next].0),
+        )),
+    };
+
+    lines
+        .into_iter()
+        .zip(LINES.iter().cycle())
+        .map(move |(line, &(prefix, sign))| parse_pair(&source, line, prefix, sign))
+        .chain(missing.map(Err))
+}
+
+/// Parses a line like `Button A: X+94, Y+34` or `Prize: X=8400, Y=5400`.
+fn parse_pair(
+    source: &Source,
+    line: &str,
+    prefix: &str,
+    sign: char,
+) -> Result<(i64, i64), ParseError> {
+    let rest = source.strip_prefix(line, prefix)?;
+    let rest = source.strip_prefix(rest, &format!("X{}", sign))?;
+    let (x, rest) = source.split_once(rest, ", ")?;
+    let y = source.strip_prefix(rest, &format!("Y{}", sign))?;
+    Ok((source.number(x)?, source.number(y)?))
+}
+
+/// Fewest tokens to win every winnable prize, at 3 for button A and 1 for
+/// button B.
+pub fn calculate_tokens(machines: &[Machine]) -> i64 {
+    machines
+        .par_iter()
+        .map(|machine| {
+            let (a, b) = machine.solve();
+            3 * a + b
+        })
+        .sum()
+}
+
+/// The machines with 10000000000000 added to both prize coordinates.
+pub fn augment_machines(machines: &[Machine]) -> Vec<Machine> {
+    machines
+        .iter()
+        .map(|machine| Machine {
+            prize: (
+                machine.prize.0 + 10_000_000_000_000,
+                machine.prize.1 + 10_000_000_000_000,
+            ),
+            ..*machine
+        })
+        .collect()
+}
+
+/// A claw machine: how far each button moves the claw, and where the prize
+/// is.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Machine {
+    button_a: (i64, i64),
+    button_b: (i64, i64),
+    prize: (i64, i64),
+}
+
+impl Machine {
+    pub fn new(button_a: (i64, i64), button_b: (i64, i64), prize: (i64, i64)) -> Self {
+        Machine {
+            button_a,
+            button_b,
+            prize,
+        }
+    }
+
+    /// Presses of buttons A and B that reach the prize, or `(0, 0)` if no
+    /// whole number of presses does.
+    pub fn solve(&self) -> (i64, i64) {
+        let (x, y) = self.prize;
+        let (ax, ay) = self.button_a;
+        let (bx, by) = self.button_b;
+
+        // Calculate determinant of the coefficient matrix
+        let det = ay * bx - ax * by;
+        if det == 0 {
+            return (0, 0);
+        }
+
+        // Calculate a using derived formula
+        let numerator = y * bx - x * by;
+        let a = numerator as f64 / det as f64;
+
+        // Check if a is a positive integer
+        if a.fract() != 0.0 || a < 0.0 {
+            return (0, 0);
+        }
+
+        let a = a as i64;
+
+        // Now try and solve to get b
+        if let Some(b) = self.try_solve_with_a(a) {
+            (a, b)
+        } else {
+            (0, 0)
+        }
+    }
+
+    fn try_solve_with_a(&self, a: i64) -> Option<i64> {
+        let (x, y) = self.prize;
+        let (ax, ay) = self.button_a;
+        let (bx, by) = self.button_b;
+
+        let b = (x - a * ax) / bx;
+        if b < 0 {
+            return None;
+        }
+
+        if a * ay + b * by != y {
+            return None;
+        }
+
+        Some(b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::check_examples::<Day13>(concat!(env!("CARGO_MANIFEST_DIR"), "/examples"));
+    }
+
+    const MACHINE_1: Machine = Machine {
+        button_a: (94, 34),
+        button_b: (22, 67),
+        prize: (8400, 5400),
+    };
+
+    const MACHINE_2: Machine = Machine {
+        button_a: (26, 66),
+        button_b: (67, 21),
+        prize: (12748, 12176),
+    };
+
+    const MACHINE_3: Machine = Machine {
+        button_a: (17, 86),
+        button_b: (84, 37),
+        prize: (7870, 6450),
+    };
+
+    const MACHINE_4: Machine = Machine {
+        button_a: (69, 23),
+        button_b: (27, 71),
+        prize: (18641, 10279),
+    };
+
+    mod parse_input {
+        use super::*;
+
+        #[test]
+        fn machines() {
+            let input = "\
+Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176
+";
+            assert_eq!(parse_input(input).unwrap(), vec![MACHINE_1, MACHINE_2]);
+        }
+
+        #[test]
+        fn errors() {
+            let err = parse_input("Button A: X+94, Y+34\nButton B: X+22 Y+67\n").unwrap_err();
+            assert_eq!((err.line, err.column), (2, 20));
+
+            let err = parse_input("Button A: X+94, Y+34\nButton B: X+22, Y+67\n").unwrap_err();
+            assert_eq!((err.line, err.column), (2, 21));
+
+            let err = parse_input("Button A: X+94, Y=34\n").unwrap_err();
+            assert_eq!((err.line, err.column), (1, 17));
+        }
+    }
+
+    mod solve_machine {
+        use super::*;
+
+        #[test]
+        fn simple_machine() {
+            let machine = Machine {
+                button_a: (1, 2),
+                button_b: (3, 1),
+                prize: (7, 4),
+            };
+            assert_eq!(machine.solve(), (1, 2));
+        }
+
+        #[test]
+        fn example_machines() {
+            assert_eq!(MACHINE_1.solve(), (80, 40));
+            assert_eq!(MACHINE_3.solve(), (38, 86));
+        }
+
+        #[test]
+        fn unsolvable_machines() {
+            assert_eq!(MACHINE_2.solve(), (0, 0));
+            assert_eq!(MACHINE_4.solve(), (0, 0));
+        }
+    }
+
+    mod calculate_tokens {
+        use super::*;
+
+        #[test]
+        fn example_machines() {
+            let machines = vec![MACHINE_1, MACHINE_2, MACHINE_3, MACHINE_4];
+            assert_eq!(calculate_tokens(&machines), 480);
+        }
+    }
+
+    mod properties {
+        use super::*;
+        use aoc_common::testing::generated;
+        use aoc_common::testing::proptest::prelude::*;
+
+        fn reaches_prize(machine: &Machine, (a, b): (i64, i64)) -> bool {
+            let (ax, ay) = machine.button_a;
+            let (bx, by) = machine.button_b;
+            (a * ax + b * bx, a * ay + b * by) == machine.prize
+        }
+
+        proptest! {
+            #[test]
+            fn solutions_reach_the_prize(input in generated::<Day13>(1..20)) {
+                let machines = parse_input(&input).unwrap();
+                for machine in machines.iter().chain(&augment_machines(&machines)) {
+                    let presses = machine.solve();
+                    prop_assert!(presses == (0, 0) || reaches_prize(machine, presses));
+                }
+            }
+
+            #[test]
+            fn reachable_prizes_are_found(
+                button_a in (1..100_i64, 1..100_i64),
+                button_b in (1..100_i64, 1..100_i64),
+                presses in (0..200_i64, 0..200_i64),
+            ) {
+                let prize = (
+                    presses.0 * button_a.0 + presses.1 * button_b.0,
+                    presses.0 * button_a.1 + presses.1 * button_b.1,
+                );
+                let machine = Machine::new(button_a, button_b, prize);
+                // Buttons that move in the same direction have many answers
+                prop_assume!(button_a.0 * button_b.1 != button_a.1 * button_b.0);
+                prop_assert_eq!(machine.solve(), presses);
+            }
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use aoc13::{augment_machines, calculate_tokens, parse_input, Machine};
+
+const EXAMPLE: &str = include_str!("../examples/part1.txt");
+
+#[test]
+fn test_example() {
+    let machines = parse_input(EXAMPLE).unwrap();
+    assert_eq!(calculate_tokens(&machines), 480);
+    assert!(calculate_tokens(&augment_machines(&machines)) > 0);
+}
+
+#[test]
+fn test_machine_by_hand() {
+    let machine = Machine::new((94, 34), (22, 67), (8400, 5400));
+    assert_eq!(machine.solve(), (80, 40));
+    assert_eq!(
+        Machine::new((26, 66), (67, 21), (12748, 12176)).solve(),
+        (0, 0)
+    );
+}
+
+aoc_common::parse_properties!(aoc13::Day13);
@@ -0,0 +1,20 @@
+//! Random robots.
+
+use crate::{HEIGHT, WIDTH};
+use aoc_common::Rng;
+
+/// `size` robots anywhere in the space, each with a velocity of up to 99
+/// tiles a second either way.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let mut out = String::new();
+    for _ in 0..size.max(1) {
+        out.push_str(&format!(
+            "p={},{} v={},{}\n",
+            rng.range(0..=WIDTH as i64 - 1),
+            rng.range(0..=HEIGHT as i64 - 1),
+            rng.range(-99..=99),
+            rng.range(-99..=99)
+        ));
+    }
+    out
+}
@@ -0,0 +1,364 @@
+//! Day 14: Restroom Redoubt.
+//!
+//! Robots patrol a room in straight lines, wrapping around its edges.
+//! [`safety_factor`] multiplies the robot counts in each quadrant after a
+//! given time, and [`find_pattern_time`] finds when they form a picture.
+
+pub mod generate;
+
+use aoc_common::{Answer, Cell, Color, Frame, Lint, ParseError, Point, Rng, Solution, Source};
+
+/// Size of the real bathroom; the examples use a smaller 11x7 room.
+pub const WIDTH: i32 = 101;
+pub const HEIGHT: i32 = 103;
+
+/// The [`Solution`] for day 14.
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    const INPUT: &'static str = include_str!("../14-input.txt");
+    type Input = Vec<Robot>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        safety_factor(input, WIDTH, HEIGHT, 100).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        find_pattern_time(input, WIDTH, HEIGHT).into()
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+
+    fn lint(input: &str) -> Vec<ParseError> {
+        Lint::run(input, |lint| {
+            let source = lint.source();
+            lint.each(lint.lines(), |line| parse_robot(&source, line));
+        })
+    }
+}
+
+/// Parses one robot per line, as in `p=0,4 v=3,-3`.
+pub fn parse_input(input: &str) -> Result<Vec<Robot>, ParseError> {
+    let source = Source::new(input)?;
+    source
+        .lines()
+        .map(|line| parse_robot(&source, line))
+        .collect()
+}
+
+fn parse_robot(source: &Source, line: &str) -> Result<Robot, ParseError> {
+    let (position, velocity) = source.split_once(line, " ")?;
+    let (px, py) = source.split_once(source.strip_prefix(position, "p=")?, ",")?;
+    let (vx, vy) = source.split_once(source.strip_prefix(velocity, "v=")?, ",")?;
+
+    Ok(Robot::new(
+        source.number(px)?,
+        source.number(py)?,
+        source.number(vx)?,
+        source.number(vy)?,
+    ))
+}
+
+/// The room after `seconds`, with the robots drawn as green `#`s.
+pub fn frame(robots: &[Robot], width: i32, height: i32, seconds: i32) -> Frame {
+    let mut frame = Frame::new(
+        width as usize,
+        height as usize,
+        Cell::new('.', Color::DARK_GREY),
+    );
+    frame.overlay(
+        robots
+            .iter()
+            .filter_map(|robot| robot.position_at(seconds, width, height).to_unsigned()),
+        Cell::new('#', Color::GREEN),
+    );
+    frame
+}
+
+/// Parses `input` and returns its [`safety_factor`].
+pub fn calculate_safety_factor(
+    input: &str,
+    width: i32,
+    height: i32,
+    seconds: i32,
+) -> Result<u64, ParseError> {
+    Ok(safety_factor(&parse_input(input)?, width, height, seconds))
+}
+
+/// Product of the number of robots in each quadrant after `seconds`. Robots
+/// on the middle row or column don't count.
+pub fn safety_factor(robots: &[Robot], width: i32, height: i32, seconds: i32) -> u64 {
+    let positions: Vec<Position> = robots
+        .iter()
+        .map(|robot| robot.position_at(seconds, width, height))
+        .collect();
+    count_quadrants(&positions, width, height).iter().product()
+}
+
+pub type Position = Point<i32>;
+pub type Velocity = Point<i32>;
+
+/// A robot's starting position and velocity per second.
+#[derive(Debug, Clone, Copy)]
+pub struct Robot {
+    p: Position,
+    v: Velocity,
+}
+
+impl Robot {
+    pub fn new(px: i32, py: i32, vx: i32, vy: i32) -> Self {
+        Self {
+            p: Position { x: px, y: py },
+            v: Velocity { x: vx, y: vy },
+        }
+    }
+
+    /// Where the robot is after `seconds` in a room of the given size.
+    pub fn position_at(&self, seconds: i32, width: i32, height: i32) -> Position {
+        // Calculate total movement
+        let dx = self.v.x * seconds;
+        let dy = self.v.y * seconds;
+
+        // Add to starting pos and convert to positive
+        let mut x = (self.p.x + dx) % width;
+        if x < 0 {
+            x += width;
+        }
+
+        let mut y = (self.p.y + dy) % height;
+        if y < 0 {
+            y += height;
+        }
+
+        Position { x, y }
+    }
+}
+
+fn count_quadrants(positions: &[Position], width: i32, height: i32) -> [u64; 4] {
+    let mid_x = width / 2;
+    let mid_y = height / 2;
+
+    // Use iterator methods instead of explicit loop
+    positions.iter().fold([0; 4], |mut counts, pos| {
+        let quadrant = match (pos.x.cmp(&mid_x), pos.y.cmp(&mid_y)) {
+            (std::cmp::Ordering::Less, std::cmp::Ordering::Less) => 0,
+            (std::cmp::Ordering::Greater, std::cmp::Ordering::Less) => 1,
+            (std::cmp::Ordering::Less, std::cmp::Ordering::Greater) => 2,
+            (std::cmp::Ordering::Greater, std::cmp::Ordering::Greater) => 3,
+            _ => return counts, // Handle points exactly on the lines
+        };
+        counts[quadrant] += 1;
+        counts
+    })
+}
+
+/// The time at which the robots are most clustered, which is when they
+/// form a picture. Columns and rows are taken separately, each repeating
+/// with the room's width or height, and combined with the Chinese
+/// remainder theorem.
+pub fn find_pattern_time(robots: &[Robot], width: i32, height: i32) -> i32 {
+    // Calculate x-entropy for all positions in width cycle
+    let x_entropies: Vec<f64> = (0..width)
+        .map(|t| {
+            measure_entropy(
+                &robots
+                    .iter()
+                    .map(|r| r.position_at(t, width, height).x)
+                    .collect::<Vec<_>>(),
+            )
+        })
+        .collect();
+
+    let best_x_time = best_time(&x_entropies);
+
+    // Calculate y-entropy for all positions in height cycle
+    let y_entropies: Vec<f64> = (0..height)
+        .map(|t| {
+            measure_entropy(
+                &robots
+                    .iter()
+                    .map(|r| r.position_at(t, width, height).y)
+                    .collect::<Vec<_>>(),
+            )
+        })
+        .collect();
+
+    let best_y_time = best_time(&y_entropies);
+
+    chinese_reindeer(best_x_time, best_y_time, width, height)
+}
+
+fn best_time(entropies: &[f64]) -> i32 {
+    entropies
+        .iter()
+        .enumerate()
+        .min_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap())
+        .map(|(t, _)| t as i32)
+        .unwrap()
+}
+
+fn measure_entropy(positions: &[i32]) -> f64 {
+    // Count frequencies of coordinates
+    let mut counts = vec![0; 256];
+
+    for &pos in positions {
+        counts[pos as usize] += 1;
+    }
+
+    // Calculate entropy - lower means more orderly
+    counts
+        .iter()
+        .filter(|&&count| count > 0)
+        .map(|&count| {
+            let p = count as f64 / positions.len() as f64;
+            -p * p.ln()
+        })
+        .sum()
+}
+
+// Chinese Remainder Theorem
+fn chinese_reindeer(a1: i32, a2: i32, n1: i32, n2: i32) -> i32 {
+    // Extended Euclidean Algorithm to find Bézout's coefficients
+    fn extended_gcd(a: i32, b: i32) -> (i32, i32, i32) {
+        if b == 0 {
+            (a, 1, 0)
+        } else {
+            let (gcd, x1, y1) = extended_gcd(b, a % b);
+            (gcd, y1, x1 - (a / b) * y1)
+        }
+    }
+
+    // Find modular multiplicative inverse
+    let (_, m1, m2) = extended_gcd(n1, n2);
+
+    // Calculate result using Chinese Remainder Theorem formula
+    let mut result = a1 * m2 * n2 + a2 * m1 * n1;
+    let n = n1 * n2;
+
+    // Normalize result to smallest positive number
+    result = ((result % n) + n) % n;
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::check_examples::<Day14>(concat!(env!("CARGO_MANIFEST_DIR"), "/examples"));
+    }
+
+    #[test]
+    fn test_frame() {
+        let robots = [Robot::new(2, 4, 2, -3)];
+        assert_eq!(
+            frame(&robots, 11, 7, 5).to_text().lines().nth(3),
+            Some(".#.........")
+        );
+    }
+
+    const EXAMPLE_INPUT: &str = "\
+p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3";
+
+    mod position_at {
+        use super::*;
+
+        #[test]
+        fn at_0_seconds() {
+            let robot = Robot::new(2, 4, 2, -3);
+            assert_eq!(robot.position_at(0, 11, 7), Position { x: 2, y: 4 });
+        }
+
+        #[test]
+        fn at_1_second() {
+            let robot = Robot::new(2, 4, 2, -3);
+            assert_eq!(robot.position_at(1, 11, 7), Position { x: 4, y: 1 });
+        }
+
+        #[test]
+        fn at_2_seconds() {
+            let robot = Robot::new(2, 4, 2, -3);
+            assert_eq!(robot.position_at(2, 11, 7), Position { x: 6, y: 5 });
+        }
+
+        #[test]
+        fn at_3_seconds() {
+            let robot = Robot::new(2, 4, 2, -3);
+            assert_eq!(robot.position_at(3, 11, 7), Position { x: 8, y: 2 });
+        }
+
+        #[test]
+        fn at_4_seconds() {
+            let robot = Robot::new(2, 4, 2, -3);
+            assert_eq!(robot.position_at(4, 11, 7), Position { x: 10, y: 6 });
+        }
+
+        #[test]
+        fn at_5_seconds() {
+            let robot = Robot::new(2, 4, 2, -3);
+            assert_eq!(robot.position_at(5, 11, 7), Position { x: 1, y: 3 });
+        }
+    }
+
+    #[test]
+    fn test_parse_input() {
+        let robots = parse_input(EXAMPLE_INPUT).unwrap();
+        assert_eq!(robots.len(), 12);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = parse_input("p=0,4 v=3,-3\np=6,3 v=-1\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 11));
+
+        let err = parse_input("p=0,4 w=3,-3\n").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 7));
+    }
+
+    #[test]
+    fn test_count_quadrants() {
+        // Create a set of final positions that matches the example's final state
+        let positions = vec![
+            Position { x: 6, y: 0 },
+            Position { x: 6, y: 0 },
+            Position { x: 9, y: 0 },
+            Position { x: 0, y: 2 },
+            Position { x: 1, y: 3 },
+            Position { x: 2, y: 3 },
+            Position { x: 5, y: 4 },
+            Position { x: 3, y: 5 },
+            Position { x: 4, y: 5 },
+            Position { x: 4, y: 5 },
+            Position { x: 1, y: 6 },
+            Position { x: 6, y: 6 },
+        ];
+        assert_eq!(count_quadrants(&positions, 11, 7), [1, 3, 4, 1]);
+    }
+
+    #[test]
+    fn test_calculate_safety_factor() {
+        assert_eq!(
+            calculate_safety_factor(EXAMPLE_INPUT, 11, 7, 100).unwrap(),
+            12
+        );
+    }
+}
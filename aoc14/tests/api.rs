@@ -0,0 +1,21 @@
+use aoc14::{parse_input, safety_factor, Position, Robot};
+
+const EXAMPLE: &str = include_str!("../examples/part1.txt");
+
+// The example room is smaller than the real one
+const WIDTH: i32 = 11;
+const HEIGHT: i32 = 7;
+
+#[test]
+fn test_example() {
+    let robots = parse_input(EXAMPLE).unwrap();
+    assert_eq!(safety_factor(&robots, WIDTH, HEIGHT, 100), 12);
+}
+
+#[test]
+fn test_robot_wraps() {
+    let robot = Robot::new(2, 4, 2, -3);
+    assert_eq!(robot.position_at(5, WIDTH, HEIGHT), Position { x: 1, y: 3 });
+}
+
+aoc_common::parse_properties!(aoc14::Day14);
@@ -0,0 +1,34 @@
+//! Random warehouses.
+
+use aoc_common::Rng;
+
+/// A `size` by `size` walled warehouse, a quarter boxes and a tenth walls
+/// inside, followed by `size * size` moves on lines of 70.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let side = size.max(3);
+    let mut tiles = vec![vec!['#'; side]; side];
+    for row in &mut tiles[1..side - 1] {
+        for tile in &mut row[1..side - 1] {
+            *tile = match rng.below(20) {
+                0..=4 => 'O',
+                5 | 6 => '#',
+                _ => '.',
+            };
+        }
+    }
+    tiles[rng.range(1..=side as i64 - 2) as usize][rng.range(1..=side as i64 - 2) as usize] = '@';
+
+    let mut out: String = tiles
+        .iter()
+        .map(|row| row.iter().collect::<String>() + "\n")
+        .collect();
+    out.push('\n');
+    let moves: Vec<char> = (0..side * side)
+        .map(|_| *rng.pick(&['^', 'v', '<', '>']))
+        .collect();
+    for line in moves.chunks(70) {
+        out.extend(line);
+        out.push('\n');
+    }
+    out
+}
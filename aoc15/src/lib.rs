@@ -0,0 +1,886 @@
+//! Day 15: Warehouse Woes.
+//!
+//! A robot pushes boxes around a [`Warehouse`] following a list of
+//! [`Move`]s. Only part two's widened warehouse, where each box is two tiles
+//! wide, is modelled.
+
+pub mod generate;
+
+use aoc_common::{
+    Answer, Cell, Color, Direction, Frame, Grid, Lint, NoTrace, ParseError, ParseErrorKind, Point,
+    Rng, Solution, Source, Tracer,
+};
+use serde::Serialize;
+use std::collections::{HashSet, VecDeque};
+use std::fmt;
+
+/// The [`Solution`] for day 15.
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    const INPUT: &'static str = include_str!("../15-input.txt");
+    type Input = (Warehouse, Vec<Move>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(_input: &Self::Input) -> Answer {
+        // Only the widened warehouse from part two is modelled
+        Answer::Unsolved
+    }
+
+    fn part2((warehouse, moves): &Self::Input) -> Answer {
+        let mut warehouse = warehouse.clone();
+        warehouse.make_moves(moves);
+        warehouse.sum_boxes_gps_coord().into()
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+
+    fn lint(input: &str) -> Vec<ParseError> {
+        Lint::run(input, |lint| {
+            let source = lint.source();
+            let mut lines = lint.lines();
+            let map: Vec<&str> = lines.by_ref().take_while(|line| !line.is_empty()).collect();
+            lint.grid(map.iter().copied(), |c| matches!(c, '#' | 'O' | '@' | '.'));
+            lint.exactly_one(map.iter().copied(), '@', "robot '@' in the map");
+            for line in lines {
+                for (i, c) in line.char_indices() {
+                    if !matches!(c, '^' | 'v' | '<' | '>') {
+                        lint.report(source.invalid_char(line, i));
+                    }
+                }
+            }
+        })
+    }
+}
+
+type Position = Point;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+struct Box {
+    left: Position,
+    right: Position,
+}
+
+impl Box {
+    fn new(left: Position, right: Position) -> Self {
+        assert_eq!(left.y, right.y, "Box positions must be on same row");
+        assert_eq!(
+            left.x + 1,
+            right.x,
+            "Box positions must be horizontally adjacent"
+        );
+        Self { left, right }
+    }
+
+    fn gps_coord(&self) -> usize {
+        self.left.x + self.left.y * 100
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+enum Tile {
+    Empty,
+    Wall,
+    BoxLeft,
+    BoxRight,
+    Robot,
+}
+
+impl Tile {
+    fn cell(self) -> Cell {
+        match self {
+            Tile::Empty => Cell::new('.', Color::DARK_GREY),
+            Tile::Wall => Cell::new('#', Color::GREY),
+            Tile::BoxLeft => Cell::new('[', Color::BROWN),
+            Tile::BoxRight => Cell::new(']', Color::BROWN),
+            Tile::Robot => Cell::new('@', Color::RED),
+        }
+    }
+}
+
+impl fmt::Display for Tile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.cell().glyph)
+    }
+}
+
+/// One step of the robot, from `^`, `v`, `<` or `>`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize)]
+pub enum Move {
+    #[serde(rename = "^")]
+    Up,
+    #[serde(rename = "v")]
+    Down,
+    #[serde(rename = "<")]
+    Left,
+    #[serde(rename = ">")]
+    Right,
+}
+
+/// What one move did: where the robot ended up and the left halves of the
+/// boxes it pushed, where they ended up.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct RobotStep {
+    #[serde(rename = "move")]
+    pub direction: Move,
+    pub robot: Point,
+    pub pushed: Vec<Point>,
+    pub blocked: bool,
+}
+
+impl Move {
+    fn direction(&self) -> Direction {
+        match self {
+            Move::Left => Direction::West,
+            Move::Right => Direction::East,
+            Move::Up => Direction::North,
+            Move::Down => Direction::South,
+        }
+    }
+
+    fn deltas(&self) -> (isize, isize) {
+        self.direction().delta()
+    }
+}
+
+/// The walls, boxes and robot of a widened warehouse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Warehouse {
+    width: usize,
+    height: usize,
+    walls: HashSet<Position>,
+    boxes: HashSet<Box>,
+    robot: Position,
+}
+
+impl Warehouse {
+    fn new(grid: &Grid<Tile>) -> Self {
+        let mut warehouse = Self {
+            width: grid.width(),
+            height: grid.height(),
+            walls: HashSet::new(),
+            boxes: HashSet::new(),
+            robot: Position::default(),
+        };
+
+        for (pos, tile) in grid.iter() {
+            match tile {
+                Tile::Wall => {
+                    warehouse.walls.insert(pos);
+                }
+                Tile::BoxLeft => {
+                    warehouse
+                        .boxes
+                        .insert(Box::new(pos, Position::new(pos.x + 1, pos.y)));
+                }
+                Tile::Robot => {
+                    warehouse.robot = pos;
+                }
+                _ => {}
+            }
+        }
+
+        warehouse
+    }
+
+    fn grid(&self) -> Grid<Tile> {
+        let mut grid = Grid::new(self.width, self.height, Tile::Empty);
+
+        // Add walls
+        for &wall in &self.walls {
+            grid[wall] = Tile::Wall;
+        }
+
+        // Add boxes (both left and right sides)
+        for bx in &self.boxes {
+            grid[bx.left] = Tile::BoxLeft;
+            grid[bx.right] = Tile::BoxRight;
+        }
+
+        // Add robot
+        grid[self.robot] = Tile::Robot;
+
+        grid
+    }
+
+    /// The warehouse drawn as in the puzzle, with `[]` for boxes.
+    pub fn display(&self) -> String {
+        self.grid().to_string()
+    }
+
+    /// The warehouse drawn in colour.
+    pub fn frame(&self) -> Frame {
+        Frame::from_grid(&self.grid(), |_, tile| tile.cell())
+    }
+
+    /// Sum of each box's GPS coordinate, 100 times its distance from the top
+    /// plus its distance from the left.
+    pub fn sum_boxes_gps_coord(&self) -> usize {
+        self.boxes.iter().map(|b| b.gps_coord()).sum()
+    }
+
+    fn get_tile(&self, pos: &Position) -> Tile {
+        if self.walls.contains(pos) {
+            Tile::Wall
+        } else if self.boxes.iter().any(|b| b.left == *pos) {
+            Tile::BoxLeft
+        } else if self.boxes.iter().any(|b| b.right == *pos) {
+            Tile::BoxRight
+        } else if self.robot == *pos {
+            Tile::Robot
+        } else {
+            Tile::Empty
+        }
+    }
+
+    fn is_in_bounds(&self, pos: &Position) -> bool {
+        pos.x < self.width && pos.y < self.height
+    }
+
+    fn find_box_at_pos(&self, pos: &Position) -> Option<Box> {
+        self.boxes
+            .iter()
+            .find(|bx| bx.left == *pos || bx.right == *pos)
+            .copied()
+    }
+
+    /// Moves the robot, pushing any boxes in the way. Moves blocked by a wall
+    /// do nothing.
+    pub fn make_moves(&mut self, moves: &[Move]) {
+        self.make_moves_traced(moves, &mut NoTrace);
+    }
+
+    /// Makes the moves as [`make_moves`](Self::make_moves) does, reporting
+    /// each one.
+    pub fn make_moves_traced(&mut self, moves: &[Move], tracer: &mut impl Tracer<RobotStep>) {
+        for &dir in moves {
+            let pushed = self.try_move(dir);
+            tracer.record(&RobotStep {
+                direction: dir,
+                robot: self.robot,
+                blocked: pushed.is_none(),
+                pushed: pushed
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|bx| bx.left)
+                    .collect(),
+            });
+        }
+    }
+
+    /// Moves the robot one step, returning the boxes it pushed in their new
+    /// places, or `None` if the move was blocked.
+    fn try_move(&mut self, dir: Move) -> Option<Vec<Box>> {
+        let (dx, dy) = dir.deltas();
+        let next_pos = self.robot.offset(dx, dy)?;
+
+        let visited = if dy != 0 {
+            // For vertical movement, use explore_vertical_push
+            self.explore_vertical_push(next_pos, dy)?
+        } else {
+            // For horizontal movement, scan linearly
+            let boxes = self.explore_horizontal_push(next_pos, dx);
+            if boxes.is_empty() && !matches!(self.get_tile(&next_pos), Tile::Empty) {
+                return None;
+            }
+            boxes
+        };
+
+        // If we get here, movement is possible
+        let sorted_boxes = self.sort_boxes(&visited, dir);
+        let pushed = sorted_boxes
+            .into_iter()
+            .map(|bx| self.move_box(bx, dx, dy))
+            .collect();
+        self.robot = next_pos;
+        Some(pushed)
+    }
+
+    fn explore_vertical_push(&self, start: Position, dy: isize) -> Option<HashSet<Box>> {
+        let mut queue = VecDeque::new();
+        let mut visited = HashSet::new();
+        let mut blocked = false;
+
+        queue.push_back(start);
+
+        while let Some(pos) = queue.pop_front() {
+            if !self.is_in_bounds(&pos) {
+                blocked = true;
+                continue;
+            }
+
+            match self.get_tile(&pos) {
+                Tile::Wall => {
+                    blocked = true;
+                }
+                Tile::BoxLeft | Tile::BoxRight => {
+                    let bx = self.find_box_at_pos(&pos).unwrap();
+
+                    if visited.insert(bx) {
+                        for half in [bx.left, bx.right] {
+                            match half.offset(0, dy) {
+                                Some(next) => queue.push_back(next),
+                                None => blocked = true,
+                            }
+                        }
+                    }
+                }
+                Tile::Empty => {}
+                Tile::Robot => panic!("Robot found at {:?}", pos),
+            }
+        }
+
+        if blocked {
+            None
+        } else {
+            Some(visited)
+        }
+    }
+
+    fn explore_horizontal_push(&self, start: Position, dx: isize) -> HashSet<Box> {
+        let mut visited = HashSet::new();
+        let mut scan_pos = start;
+
+        // First check if we can move at all
+        if !matches!(self.get_tile(&scan_pos), Tile::BoxLeft | Tile::BoxRight) {
+            return visited;
+        }
+
+        // Then scan for boxes until we hit a wall or empty space
+        loop {
+            if !self.is_in_bounds(&scan_pos) {
+                return HashSet::new();
+            }
+
+            match self.get_tile(&scan_pos) {
+                Tile::Wall => return HashSet::new(),
+                Tile::Empty => break,
+                Tile::BoxLeft | Tile::BoxRight => {
+                    let Some(bx) = self.find_box_at_pos(&scan_pos) else {
+                        return HashSet::new();
+                    };
+
+                    visited.insert(bx);
+
+                    let Some(next_scan) = scan_pos.offset(dx, 0) else {
+                        return HashSet::new();
+                    };
+                    scan_pos = next_scan;
+                }
+                Tile::Robot => panic!("Robot found at {:?}", scan_pos),
+            }
+        }
+
+        visited
+    }
+
+    fn sort_boxes(&self, boxes: &HashSet<Box>, dir: Move) -> Vec<Box> {
+        let mut sorted_boxes = boxes.iter().cloned().collect::<Vec<_>>();
+        let ascending = matches!(dir, Move::Up | Move::Left);
+        sorted_boxes.sort_by(|a, b| {
+            let key_a = match dir {
+                Move::Up | Move::Down => a.left.y,
+                Move::Left | Move::Right => a.left.x,
+            };
+            let key_b = match dir {
+                Move::Up | Move::Down => b.left.y,
+                Move::Left | Move::Right => b.left.x,
+            };
+            if ascending {
+                key_a.cmp(&key_b)
+            } else {
+                key_b.cmp(&key_a)
+            }
+        });
+        sorted_boxes
+    }
+
+    fn move_box(&mut self, bx: Box, dx: isize, dy: isize) -> Box {
+        self.boxes.remove(&bx);
+
+        let new_left = bx.left.offset(dx, dy).expect("Box pushed off the map");
+        let new_right = bx.right.offset(dx, dy).expect("Box pushed off the map");
+        let new_box = Box::new(new_left, new_right);
+        self.boxes.insert(new_box);
+        new_box
+    }
+}
+
+/// Parses the warehouse map, widened as in part two, and the moves that
+/// follow it after a blank line.
+pub fn parse_input(input: &str) -> Result<(Warehouse, Vec<Move>), ParseError> {
+    let source = Source::new(input)?;
+    let mut lines = source.lines();
+
+    // Parse grid
+    let mut grid: Vec<Vec<Tile>> = Vec::new();
+    let mut robot = false;
+    for line in lines.by_ref().take_while(|line| !line.is_empty()) {
+        let row: Vec<Tile> = line
+            .char_indices()
+            .map(|(i, c)| match c {
+                '#' => Ok(vec![Tile::Wall, Tile::Wall]),
+                'O' => Ok(vec![Tile::BoxLeft, Tile::BoxRight]),
+                '@' => Ok(vec![Tile::Robot, Tile::Empty]),
+                '.' => Ok(vec![Tile::Empty, Tile::Empty]),
+                _ => Err(source.invalid_char(line, i)),
+            })
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .flatten()
+            .collect();
+
+        if grid.first().is_some_and(|first| first.len() != row.len()) {
+            return Err(source.expected(line, "a row as wide as the first"));
+        }
+        for (i, _) in line.match_indices('@') {
+            if robot {
+                return Err(source.error(
+                    &line[i..],
+                    ParseErrorKind::Expected("only one robot '@' in the map".to_string()),
+                ));
+            }
+            robot = true;
+        }
+        grid.push(row);
+    }
+
+    if grid.is_empty() {
+        return Err(ParseError::empty());
+    }
+    if !robot {
+        return Err(source.expected(input.trim_end(), "a robot '@' in the map"));
+    }
+
+    // Parse moves
+    let moves: Vec<Move> = lines
+        .flat_map(|line| line.char_indices().map(move |(i, c)| (line, i, c)))
+        .map(|(line, i, c)| match c {
+            '^' => Ok(Move::Up),
+            'v' => Ok(Move::Down),
+            '<' => Ok(Move::Left),
+            '>' => Ok(Move::Right),
+            _ => Err(source.invalid_char(line, i)),
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok((Warehouse::new(&Grid::from_rows(grid)), moves))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::check_examples::<Day15>(concat!(env!("CARGO_MANIFEST_DIR"), "/examples"));
+    }
+
+    #[test]
+    fn test_frame() {
+        let (warehouse, _moves) = parse_input(SMALL_EXAMPLE).unwrap();
+        assert_eq!(warehouse.frame().to_text(), warehouse.display() + "\n");
+    }
+
+    const SMALL_EXAMPLE: &str = "\
+#######
+#...#.#
+#.....#
+#..OO@#
+#..O..#
+#.....#
+#######
+
+<vv<<^^<<^^";
+
+    const LARGE_EXAMPLE: &str = "\
+##########
+#..O..O.O#
+#......O.#
+#.OO..O.O#
+#..O@..O.#
+#O#..O...#
+#O..O..O.#
+#.OO.O.OO#
+#....O...#
+##########
+
+<vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
+vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
+><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
+<<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
+^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
+^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
+>^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
+<><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
+^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
+v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^";
+
+    const FINAL_GRID: &str = "\
+####################
+##[].......[].[][]##
+##[]...........[].##
+##[]........[][][]##
+##[]......[]....[]##
+##..##......[]....##
+##..[]............##
+##..@......[].[][]##
+##......[][]..[]..##
+####################";
+
+    mod parse_input {
+        use super::*;
+
+        #[test]
+        fn parse_moves() {
+            let (_warehouse, moves) = parse_input(SMALL_EXAMPLE).unwrap();
+            assert_eq!(
+                moves,
+                vec![
+                    Move::Left,
+                    Move::Down,
+                    Move::Down,
+                    Move::Left,
+                    Move::Left,
+                    Move::Up,
+                    Move::Up,
+                    Move::Left,
+                    Move::Left,
+                    Move::Up,
+                    Move::Up,
+                ]
+            );
+        }
+
+        #[test]
+        fn small_example() {
+            const EXPECTED: &str = "\
+##############
+##......##..##
+##..........##
+##....[][]@.##
+##....[]....##
+##..........##
+##############";
+
+            let (warehouse, _moves) = parse_input(SMALL_EXAMPLE).unwrap();
+            let display = warehouse.display();
+            assert_eq!(display, EXPECTED);
+        }
+
+        #[test]
+        fn large_example() {
+            const EXPECTED: &str = "\
+####################
+##....[]....[]..[]##
+##............[]..##
+##..[][]....[]..[]##
+##....[]@.....[]..##
+##[]##....[]......##
+##[]....[]....[]..##
+##..[][]..[]..[][]##
+##........[]......##
+####################";
+
+            let (warehouse, _moves) = parse_input(LARGE_EXAMPLE).unwrap();
+            let display = warehouse.display();
+            assert_eq!(display, EXPECTED);
+        }
+
+        #[test]
+        fn errors() {
+            let err = parse_input("####\n#.x#\n####\n\n<v\n").unwrap_err();
+            assert_eq!((err.line, err.column), (2, 3));
+            assert_eq!(err.kind, ParseErrorKind::InvalidChar('x'));
+
+            let err = parse_input("####\n#@.#\n####\n\n<v\n>?<\n").unwrap_err();
+            assert_eq!((err.line, err.column), (6, 2));
+
+            let err = parse_input("####\n#..#\n####\n\n<v\n").unwrap_err();
+            assert_eq!(
+                err.kind,
+                ParseErrorKind::Expected("a robot '@' in the map".into())
+            );
+        }
+    }
+
+    mod warehouse {
+        use super::*;
+
+        #[test]
+        fn place_robot() {
+            let (warehouse, _moves) = parse_input(SMALL_EXAMPLE).unwrap();
+            assert_eq!(warehouse.robot, Position { x: 10, y: 3 });
+        }
+
+        #[test]
+        fn place_walls() {
+            let (warehouse, _moves) = parse_input(SMALL_EXAMPLE).unwrap();
+            assert!(warehouse.walls.is_superset(&HashSet::from([
+                Position { x: 8, y: 1 },
+                Position { x: 9, y: 1 }
+            ])));
+        }
+
+        #[test]
+        fn place_boxes() {
+            let (warehouse, _moves) = parse_input(SMALL_EXAMPLE).unwrap();
+            assert_eq!(
+                warehouse.boxes,
+                HashSet::from([
+                    Box::new(Position { x: 6, y: 3 }, Position { x: 7, y: 3 }),
+                    Box::new(Position { x: 8, y: 3 }, Position { x: 9, y: 3 }),
+                    Box::new(Position { x: 6, y: 4 }, Position { x: 7, y: 4 }),
+                ])
+            );
+        }
+
+        #[test]
+        fn warehouse_to_grid() {
+            let input = Grid::from_rows(vec![
+                vec![Tile::Wall, Tile::Wall, Tile::Wall],
+                vec![Tile::Wall, Tile::BoxLeft, Tile::Robot],
+                vec![Tile::Wall, Tile::Wall, Tile::Wall],
+            ]);
+            let warehouse = Warehouse::new(&input);
+            let output = warehouse.grid();
+            assert_eq!(input, output);
+        }
+    }
+
+    mod sorted_boxes {
+        use super::*;
+
+        #[test]
+        fn vertical_stack_down() {
+            let boxes = HashSet::from([
+                Box::new(Position { x: 10, y: 4 }, Position { x: 11, y: 4 }),
+                Box::new(Position { x: 10, y: 3 }, Position { x: 11, y: 3 }),
+                Box::new(Position { x: 10, y: 5 }, Position { x: 11, y: 5 }),
+            ]);
+            let warehouse = Warehouse {
+                width: 20,
+                height: 20,
+                walls: HashSet::new(),
+                boxes: HashSet::new(),
+                robot: Position { x: 0, y: 0 },
+            };
+            let sorted = warehouse.sort_boxes(&boxes, Move::Down);
+            assert_eq!(
+                sorted,
+                vec![
+                    Box::new(Position { x: 10, y: 5 }, Position { x: 11, y: 5 }),
+                    Box::new(Position { x: 10, y: 4 }, Position { x: 11, y: 4 }),
+                    Box::new(Position { x: 10, y: 3 }, Position { x: 11, y: 3 }),
+                ]
+            );
+        }
+
+        #[test]
+        fn vertical_stack_up() {
+            let boxes = HashSet::from([
+                Box::new(Position { x: 10, y: 4 }, Position { x: 11, y: 4 }),
+                Box::new(Position { x: 10, y: 3 }, Position { x: 11, y: 3 }),
+                Box::new(Position { x: 10, y: 5 }, Position { x: 11, y: 5 }),
+            ]);
+            let warehouse = Warehouse {
+                width: 20,
+                height: 20,
+                walls: HashSet::new(),
+                boxes: HashSet::new(),
+                robot: Position { x: 0, y: 0 },
+            };
+            let sorted = warehouse.sort_boxes(&boxes, Move::Up);
+            assert_eq!(
+                sorted,
+                vec![
+                    Box::new(Position { x: 10, y: 3 }, Position { x: 11, y: 3 }),
+                    Box::new(Position { x: 10, y: 4 }, Position { x: 11, y: 4 }),
+                    Box::new(Position { x: 10, y: 5 }, Position { x: 11, y: 5 }),
+                ]
+            );
+        }
+    }
+
+    mod try_move {
+        use super::*;
+
+        #[test]
+        fn move_left() {
+            const EXPECTED: &str = "\
+##############
+##......##..##
+##..........##
+##...[][]@..##
+##....[]....##
+##..........##
+##############";
+
+            let (mut warehouse, _moves) = parse_input(SMALL_EXAMPLE).unwrap();
+            warehouse.try_move(Move::Left);
+            let display = warehouse.display();
+            assert_eq!(display, EXPECTED);
+        }
+
+        #[test]
+        fn move_left_accumulate() {
+            const INPUT: &str = "\
+##########
+#.O.OO.O@#
+##########
+
+";
+
+            const EXPECTED: &str = "\
+####################
+##[][][][]@.......##
+####################";
+
+            let (mut warehouse, _moves) = parse_input(INPUT).unwrap();
+            warehouse.try_move(Move::Left);
+            warehouse.try_move(Move::Left);
+            warehouse.try_move(Move::Left);
+            warehouse.try_move(Move::Left);
+            warehouse.try_move(Move::Left);
+            warehouse.try_move(Move::Left);
+            warehouse.try_move(Move::Left);
+            let display = warehouse.display();
+            assert_eq!(display, EXPECTED);
+        }
+
+        #[test]
+        fn multi_move() {
+            const EXPECTED: &str = "\
+##############
+##......##..##
+##...[][]...##
+##....[]....##
+##.....@....##
+##..........##
+##############";
+
+            let (mut warehouse, _moves) = parse_input(SMALL_EXAMPLE).unwrap();
+            warehouse.try_move(Move::Left);
+            warehouse.try_move(Move::Down);
+            warehouse.try_move(Move::Down);
+            warehouse.try_move(Move::Left);
+            warehouse.try_move(Move::Left);
+            warehouse.try_move(Move::Up);
+            warehouse.try_move(Move::Up);
+            let display = warehouse.display();
+            assert_eq!(display, EXPECTED);
+        }
+    }
+
+    mod make_moves {
+        use super::*;
+
+        #[test]
+        fn small_example() {
+            const EXPECTED: &str = "\
+##############
+##...[].##..##
+##...@.[]...##
+##....[]....##
+##..........##
+##..........##
+##############";
+
+            let (mut warehouse, moves) = parse_input(SMALL_EXAMPLE).unwrap();
+            warehouse.make_moves(&moves);
+            let display = warehouse.display();
+            assert_eq!(display, EXPECTED);
+            assert_eq!(warehouse.sum_boxes_gps_coord(), 105 + 207 + 306);
+        }
+
+        #[test]
+        fn large_example() {
+            let (mut warehouse, moves) = parse_input(LARGE_EXAMPLE).unwrap();
+            warehouse.make_moves(&moves);
+            let display = warehouse.display();
+            assert_eq!(display, FINAL_GRID);
+            assert_eq!(warehouse.sum_boxes_gps_coord(), 9021);
+        }
+
+        #[test]
+        fn traced() {
+            let (mut warehouse, moves) = parse_input(SMALL_EXAMPLE).unwrap();
+            let mut steps = Vec::new();
+            warehouse.make_moves_traced(&moves, &mut steps);
+            assert_eq!(steps.len(), moves.len());
+            assert_eq!(
+                steps[0],
+                RobotStep {
+                    direction: Move::Left,
+                    robot: Position { x: 9, y: 3 },
+                    pushed: vec![Position { x: 5, y: 3 }, Position { x: 7, y: 3 }],
+                    blocked: false,
+                }
+            );
+            assert_eq!(steps.last().unwrap().robot, warehouse.robot);
+            assert!(steps
+                .iter()
+                .any(|step| step.blocked && step.pushed.is_empty()));
+        }
+    }
+
+    mod gps_coords {
+        use super::*;
+
+        #[test]
+        fn single_box() {
+            let box1 = Box::new(Position { x: 5, y: 1 }, Position { x: 6, y: 1 });
+            assert_eq!(box1.gps_coord(), 105);
+        }
+
+        #[test]
+        fn small_grid() {
+            let (warehouse, _moves) = parse_input(SMALL_EXAMPLE).unwrap();
+            assert_eq!(warehouse.sum_boxes_gps_coord(), 306 + 308 + 406);
+        }
+    }
+
+    mod properties {
+        use super::*;
+        use aoc_common::testing::generated;
+        use aoc_common::testing::proptest::prelude::*;
+
+        /// Every box and the robot on its own free tiles, inside the map.
+        fn check_layout(warehouse: &Warehouse) -> Result<(), TestCaseError> {
+            let mut occupied = HashSet::from([warehouse.robot]);
+            for bx in &warehouse.boxes {
+                for half in [bx.left, bx.right] {
+                    prop_assert!(warehouse.is_in_bounds(&half));
+                    prop_assert!(
+                        !warehouse.walls.contains(&half),
+                        "box in a wall at {:?}",
+                        half
+                    );
+                    prop_assert!(occupied.insert(half), "overlap at {:?}", half);
+                }
+            }
+            prop_assert!(!warehouse.walls.contains(&warehouse.robot));
+            Ok(())
+        }
+
+        proptest! {
+            #[test]
+            fn moves_never_overlap_boxes_and_walls(input in generated::<Day15>(3..12)) {
+                let (mut warehouse, moves) = parse_input(&input).unwrap();
+                let boxes = warehouse.boxes.len();
+                check_layout(&warehouse)?;
+                for dir in moves {
+                    warehouse.try_move(dir);
+                    check_layout(&warehouse)?;
+                }
+                prop_assert_eq!(warehouse.boxes.len(), boxes);
+            }
+        }
+    }
+}
@@ -0,0 +1,15 @@
+use aoc15::parse_input;
+
+const EXAMPLE: &str = include_str!("../examples/part2.txt");
+
+#[test]
+fn test_example() {
+    let (mut warehouse, moves) = parse_input(EXAMPLE).unwrap();
+    warehouse.make_moves(&moves);
+    assert_eq!(warehouse.sum_boxes_gps_coord(), 9021);
+    assert!(warehouse
+        .display()
+        .starts_with("####################\n##[]...."));
+}
+
+aoc_common::parse_properties!(aoc15::Day15);
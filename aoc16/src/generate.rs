@@ -0,0 +1,51 @@
+//! Random reindeer mazes.
+
+use aoc_common::Rng;
+
+/// A maze about `size` tiles square, dug as a spanning tree of corridors
+/// with some extra walls knocked out so there are loops and tied best
+/// paths. The start is in the bottom left and the end in the top right, as
+/// in the real inputs.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    // Corridors run along odd rows and columns inside the border
+    let side = size.max(5) | 1;
+    let mut tiles = vec![vec!['#'; side]; side];
+    let mut stack: Vec<(usize, usize)> = vec![(1, side - 2)];
+    tiles[side - 2][1] = '.';
+    while let Some(&(x, y)) = stack.last() {
+        let mut next: Vec<(usize, usize)> = [(0, -2), (0, 2), (-2, 0), (2, 0)]
+            .iter()
+            .filter_map(|&(dx, dy): &(isize, isize)| {
+                let (nx, ny) = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+                (nx > 0 && ny > 0 && nx < side - 1 && ny < side - 1 && tiles[ny][nx] == '#')
+                    .then_some((nx, ny))
+            })
+            .collect();
+        if next.is_empty() {
+            stack.pop();
+            continue;
+        }
+        rng.shuffle(&mut next);
+        let (nx, ny) = next[0];
+        tiles[(y + ny) / 2][(x + nx) / 2] = '.';
+        tiles[ny][nx] = '.';
+        stack.push((nx, ny));
+    }
+
+    for (y, row) in tiles.iter_mut().enumerate().take(side - 1).skip(1) {
+        for (x, tile) in row.iter_mut().enumerate().take(side - 1).skip(1) {
+            // A wall between two corridors, in line
+            let between = (x % 2 == 0) != (y % 2 == 0);
+            if between && *tile == '#' && rng.chance(0.1) {
+                *tile = '.';
+            }
+        }
+    }
+    tiles[side - 2][1] = 'S';
+    tiles[1][side - 2] = 'E';
+
+    tiles
+        .iter()
+        .map(|row| row.iter().collect::<String>() + "\n")
+        .collect()
+}
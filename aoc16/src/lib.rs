@@ -0,0 +1,303 @@
+//! Day 16: Reindeer Maze.
+//!
+//! A reindeer races from `S` to `E`, paying 1 point per step and 1000 per
+//! quarter turn. [`Maze::find_shortest_path`] finds the lowest score and
+//! [`Maze::count_optimal_path_tiles`] the tiles on any best path.
+
+pub mod generate;
+
+use aoc_common::search;
+use aoc_common::{
+    Answer, Cell, Color, Direction, Frame, Grid, Lint, ParseError, ParseErrorKind, Point, Rng,
+    Solution, Source,
+};
+use std::collections::HashMap;
+
+/// The [`Solution`] for day 16.
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    const INPUT: &'static str = include_str!("../16-input.txt");
+    type Input = Maze;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Maze::new(input)
+    }
+
+    fn part1(maze: &Self::Input) -> Answer {
+        maze.find_shortest_path()
+            .map_or(Answer::Unsolved, Answer::from)
+    }
+
+    fn part2(maze: &Self::Input) -> Answer {
+        maze.count_optimal_path_tiles().into()
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+
+    fn lint(input: &str) -> Vec<ParseError> {
+        Lint::run(input, |lint| {
+            lint.grid(lint.lines(), is_tile);
+            lint.exactly_one(lint.lines(), 'S', "'S' tile");
+            lint.exactly_one(lint.lines(), 'E', "'E' tile");
+        })
+    }
+}
+
+fn parse_input(input: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse(input, |c| is_tile(c).then_some(c))
+}
+
+fn is_tile(c: char) -> bool {
+    matches!(c, '#' | '.' | 'S' | 'E')
+}
+
+pub type Position = Point;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+struct State {
+    pos: Position,
+    dir: Direction,
+}
+
+impl State {
+    fn next_states(&self, maze: &Maze) -> Vec<(State, usize)> {
+        let mut states = Vec::new();
+
+        // Add turns (cost 1000)
+        states.push((
+            State {
+                pos: self.pos,
+                dir: self.dir.turn_left(),
+            },
+            1000,
+        ));
+        states.push((
+            State {
+                pos: self.pos,
+                dir: self.dir.turn_right(),
+            },
+            1000,
+        ));
+
+        // Add forward move (cost 1)
+        if let Some(next_pos) = self.pos.step(self.dir).filter(|&pos| !maze.is_wall(pos)) {
+            states.push((
+                State {
+                    pos: next_pos,
+                    dir: self.dir,
+                },
+                1,
+            ));
+        }
+
+        states
+    }
+}
+
+/// Tiles on at least one best path.
+pub type VisitedMap = HashMap<Position, bool>;
+
+/// A maze of walls `#` with a start and an end, entered facing east.
+#[derive(Debug, Clone)]
+pub struct Maze {
+    start: Position,
+    end: Position,
+    grid: Grid<char>,
+}
+
+impl Maze {
+    /// Parses a maze, which must have a start tile `S` and an end tile `E`.
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let source = Source::new(input)?;
+        let grid = parse_input(input)?;
+        let find = |tile: char| {
+            if let Some((i, _)) = input.match_indices(tile).nth(1) {
+                return Err(source.error(
+                    &input[i..],
+                    ParseErrorKind::Expected(format!("only one {:?} tile", tile)),
+                ));
+            }
+            grid.position(|&c| c == tile)
+                .ok_or_else(|| source.expected(input.trim_end(), format!("a {:?} tile", tile)))
+        };
+        let start = find('S')?;
+        let end = find('E')?;
+
+        Ok(Self { start, end, grid })
+    }
+
+    fn is_wall(&self, pos: Position) -> bool {
+        self.grid.get(pos).is_none_or(|&c| c == '#')
+    }
+
+    fn start_state(&self) -> State {
+        State {
+            pos: self.start,
+            dir: Direction::East,
+        }
+    }
+
+    /// The lowest score from start to end and the tiles on every path with that
+    /// score, or `None` if the end can't be reached.
+    pub fn find_all_optimal_paths(&self) -> Option<(usize, VisitedMap)> {
+        let paths = search::dijkstra([self.start_state()], |state| state.next_states(self));
+
+        // The end can be reached facing any way, maybe more than one of them
+        // at the best score
+        let ends: Vec<(State, usize)> = Direction::ALL
+            .into_iter()
+            .map(|dir| State { pos: self.end, dir })
+            .filter_map(|state| Some((state, paths.cost(&state)?)))
+            .collect();
+        let optimal_cost = ends.iter().map(|&(_, cost)| cost).min()?;
+        let best_ends = ends
+            .into_iter()
+            .filter(|&(_, cost)| cost == optimal_cost)
+            .map(|(state, _)| state);
+
+        let optimal_paths = paths
+            .on_paths_to(best_ends)
+            .into_iter()
+            .map(|state| (state.pos, true))
+            .collect();
+
+        Some((optimal_cost, optimal_paths))
+    }
+
+    /// The maze with every tile on a best path marked `O`.
+    pub fn frame(&self) -> Frame {
+        let mut frame = Frame::from_grid(&self.grid, |_, &c| match c {
+            '#' => Cell::new('#', Color::GREY),
+            _ => Cell::new('.', Color::DARK_GREY),
+        });
+        if let Some((_, paths)) = self.find_all_optimal_paths() {
+            frame.overlay(paths.into_keys(), Cell::new('O', Color::GREEN));
+        }
+        frame
+            .set(self.start, Cell::new('S', Color::RED))
+            .set(self.end, Cell::new('E', Color::RED));
+        frame
+    }
+
+    /// Number of tiles on at least one best path.
+    pub fn count_optimal_path_tiles(&self) -> usize {
+        if let Some((_, optimal_paths)) = self.find_all_optimal_paths() {
+            optimal_paths.len()
+        } else {
+            0
+        }
+    }
+
+    /// The lowest score from start to end, or `None` if the end can't be
+    /// reached.
+    pub fn find_shortest_path(&self) -> Option<usize> {
+        // Every step towards the end costs at least 1
+        let (_, cost) = search::astar(
+            [self.start_state()],
+            |state| state.next_states(self),
+            |state| state.pos.manhattan(self.end),
+            |state| state.pos == self.end,
+        )?;
+        Some(cost)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::check_examples::<Day16>(concat!(env!("CARGO_MANIFEST_DIR"), "/examples"));
+    }
+
+    #[test]
+    fn test_frame() {
+        let maze = Maze::new(FIRST_EXAMPLE).unwrap();
+        let text = maze.frame().to_text();
+        // The start and end are drawn over their path tiles
+        assert_eq!(text.matches('O').count() + 2, 45);
+        assert!(text.contains("#S..#"));
+    }
+
+    const FIRST_EXAMPLE: &str = "\
+###############
+#.......#....E#
+#.#.###.#.###.#
+#.....#.#...#.#
+#.###.#####.#.#
+#.#.#.......#.#
+#.#.#####.###.#
+#...........#.#
+###.#.#####.#.#
+#...#.....#.#.#
+#.#.#.###.#.#.#
+#.....#...#.#.#
+#.###.#.#.#.#.#
+#S..#.....#...#
+###############";
+
+    const SECOND_EXAMPLE: &str = "\
+#################
+#...#...#...#..E#
+#.#.#.#.#.#.#.#.#
+#.#.#.#...#...#.#
+#.#.#.#.###.#.#.#
+#...#.#.#.....#.#
+#.#.#.#.#.#####.#
+#.#...#.#.#.....#
+#.#.#####.#.###.#
+#.#.#.......#...#
+#.#.###.#####.###
+#.#.#...#.....#.#
+#.#.#.#####.###.#
+#.#.#.........#.#
+#.#.#.#########.#
+#S#.............#
+#################";
+
+    #[test]
+    fn test_parse_input() {
+        let input = "S.#\nE.#";
+        let maze = Maze::new(input).unwrap();
+        assert_eq!(maze.grid.height(), 2);
+        assert_eq!(maze.grid.width(), 3);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = Maze::new("S.#\nE.x").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+
+        let err = Maze::new("S.#\n..#").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 4));
+    }
+
+    #[test]
+    fn test_simple_maze() {
+        let maze = Maze::new(FIRST_EXAMPLE).unwrap();
+        assert_eq!(maze.find_shortest_path(), Some(7036));
+    }
+
+    #[test]
+    fn test_second_example() {
+        let maze = Maze::new(SECOND_EXAMPLE).unwrap();
+        assert_eq!(maze.find_shortest_path(), Some(11048));
+    }
+
+    #[test]
+    fn test_optimal_path_tiles() {
+        let maze = Maze::new(FIRST_EXAMPLE).unwrap();
+        assert_eq!(maze.count_optimal_path_tiles(), 45);
+    }
+
+    #[test]
+    fn test_second_example_optimal_path_tiles() {
+        let maze = Maze::new(SECOND_EXAMPLE).unwrap();
+        assert_eq!(maze.count_optimal_path_tiles(), 64);
+    }
+}
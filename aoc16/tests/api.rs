@@ -0,0 +1,18 @@
+use aoc16::Maze;
+
+const EXAMPLE: &str = include_str!("../examples/part1.txt");
+
+#[test]
+fn test_example() {
+    let maze = Maze::new(EXAMPLE).unwrap();
+    assert_eq!(maze.find_shortest_path(), Some(7036));
+    assert_eq!(maze.count_optimal_path_tiles(), 45);
+}
+
+#[test]
+fn test_bad_input() {
+    assert!(Maze::new("####\n#S.#\n####\n").is_err());
+    assert!(Maze::new("#####\n#SES#\n#####\n").is_err());
+}
+
+aoc_common::parse_properties!(aoc16::Day16);
@@ -0,0 +1,25 @@
+//! Random programs.
+
+use aoc_common::Rng;
+
+/// A program shaped like the real inputs, a loop that hashes the low bits
+/// of A, outputs one value and shifts A right three bits until it is zero,
+/// with A set so it outputs `size` values, up to 20.
+pub fn input(rng: &mut Rng, size: usize) -> String {
+    let outputs = size.clamp(1, 20) as u32;
+    let a = rng.range(8_i64.pow(outputs - 1)..=8_i64.pow(outputs) - 1);
+
+    // B = A % 8 ^ k, C = A >> B, then mix B with C in some order
+    let mut body = vec![[2, 4], [1, rng.range(0..=7)], [7, 5]];
+    let mut mix = vec![[4, rng.range(0..=7)], [1, rng.range(0..=7)], [0, 3]];
+    rng.shuffle(&mut mix);
+    body.extend(mix);
+    body.extend([[5, 5], [3, 0]]);
+
+    let program: Vec<String> = body.iter().flatten().map(i64::to_string).collect();
+    format!(
+        "Register A: {}\nRegister B: 0\nRegister C: 0\n\nProgram: {}\n",
+        a,
+        program.join(",")
+    )
+}
@@ -0,0 +1,651 @@
+//! Day 17: Chronospatial Computer.
+//!
+//! A 3-bit [`Computer`] with three registers runs a [`Program`] and collects
+//! its output.
+
+pub mod generate;
+
+use aoc_common::{
+    Answer, Lint, NoTrace, ParseError, ParseErrorKind, Rng, Solution, Source, Tracer,
+};
+use serde::Serialize;
+
+/// The [`Solution`] for day 17.
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    const INPUT: &'static str = include_str!("../17-input.txt");
+    type Input = (Registers, Program);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1((registers, program): &Self::Input) -> Answer {
+        let mut computer = Computer::new(registers.clone(), program.clone());
+        computer.run();
+        computer.print().into()
+    }
+
+    fn part2(_input: &Self::Input) -> Answer {
+        Answer::Unsolved
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        Some(generate::input(rng, size))
+    }
+
+    fn lint(input: &str) -> Vec<ParseError> {
+        Lint::run(input, |lint| {
+            let source = lint.source();
+            let mut next = 0;
+            for line in lint.lines().filter(|line| !line.trim().is_empty()) {
+                if next == FIELDS.len() {
+                    break;
+                }
+                // A line for a later field means the ones before it are
+                // missing; any other line is taken as the next field, broken
+                let Some(at) = FIELDS[next..]
+                    .iter()
+                    .position(|prefix| line.starts_with(prefix))
+                else {
+                    lint.check(source.strip_prefix(line, FIELDS[next]));
+                    next += 1;
+                    continue;
+                };
+                for missing in &FIELDS[next..next + at] {
+                    lint.check(source.strip_prefix(line, missing));
+                }
+                next += at;
+
+                let field = &line[FIELDS[next].len()..];
+                if next == FIELDS.len() - 1 {
+                    check_program(&source, field, |err| lint.report(err));
+                } else {
+                    lint.check(source.number::<u64>(field));
+                }
+                next += 1;
+            }
+            for missing in &FIELDS[next..] {
+                lint.report(source.expected(input.trim_end(), format!("{:?}", missing)));
+            }
+        })
+    }
+}
+
+// The lines of an input, in order
+const FIELDS: [&str; 4] = ["Register A: ", "Register B: ", "Register C: ", "Program: "];
+
+/// The A, B and C registers.
+#[derive(Debug, Clone)]
+pub struct Registers {
+    a: u64,
+    b: u64,
+    c: u64,
+}
+
+impl Registers {
+    pub fn new(a: u64, b: u64, c: u64) -> Self {
+        Self { a, b, c }
+    }
+}
+
+/// Instructions as 3-bit numbers, alternating opcode and operand.
+#[derive(Debug, Clone)]
+pub struct Program {
+    instructions: Vec<u8>,
+}
+
+impl Program {
+    pub fn new(instructions: Vec<u8>) -> Self {
+        Self { instructions }
+    }
+}
+
+/// The eight instructions, named as in the puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Opcode {
+    Adv = 0, // Division instruction for A
+    Bxl = 1, // XOR with literal for B
+    Bst = 2, // Set B from combo operand
+    Jnz = 3, // Jump if A is not zero
+    Bxc = 4, // XOR B and C registers
+    Out = 5, // Output combo operand value
+    Bdv = 6, // Division instruction for B
+    Cdv = 7, // Division instruction for C
+}
+
+impl TryFrom<u8> for Opcode {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Opcode::Adv),
+            1 => Ok(Opcode::Bxl),
+            2 => Ok(Opcode::Bst),
+            3 => Ok(Opcode::Jnz),
+            4 => Ok(Opcode::Bxc),
+            5 => Ok(Opcode::Out),
+            6 => Ok(Opcode::Bdv),
+            7 => Ok(Opcode::Cdv),
+            _ => Err(format!("Invalid opcode: {}", value)),
+        }
+    }
+}
+
+impl Opcode {
+    /// Whether the operand is a combo operand, which can't be 7.
+    fn takes_combo(self) -> bool {
+        matches!(
+            self,
+            Opcode::Adv | Opcode::Bst | Opcode::Out | Opcode::Bdv | Opcode::Cdv
+        )
+    }
+}
+
+/// One executed instruction, with the registers after it and the value it
+/// output, if any.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Step {
+    pub pointer: usize,
+    pub opcode: Opcode,
+    pub operand: u8,
+    pub a: u64,
+    pub b: u64,
+    pub c: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output: Option<u64>,
+}
+
+/// A running program and the values it has output so far.
+pub struct Computer {
+    registers: Registers,
+    program: Program,
+    pointer: usize,
+    output: Vec<u64>,
+}
+
+impl Computer {
+    pub fn new(registers: Registers, program: Program) -> Self {
+        Self {
+            registers,
+            program,
+            pointer: 0,
+            output: Vec::new(),
+        }
+    }
+
+    fn get_combo_value(&self, operand: u8) -> u64 {
+        match operand {
+            0..=3 => operand as u64,
+            4 => self.registers.a,
+            5 => self.registers.b,
+            6 => self.registers.c,
+            _ => panic!("Invalid combo operand: {}", operand),
+        }
+    }
+
+    fn step(&mut self, tracer: &mut impl Tracer<Step>) -> bool {
+        // Halt past the end, or where an opcode has no operand
+        if self.pointer + 1 >= self.program.instructions.len() {
+            return false;
+        }
+
+        let opcode =
+            Opcode::try_from(self.program.instructions[self.pointer]).expect("Valid opcode");
+        let operand = self.program.instructions[self.pointer + 1];
+        // An odd jump target reads operands as opcodes, which can give a
+        // combo operand of 7 that parsing couldn't rule out
+        if opcode.takes_combo() && operand == 7 {
+            return false;
+        }
+
+        let (pointer, outputs) = (self.pointer, self.output.len());
+        self.execute(opcode, operand);
+        tracer.record(&Step {
+            pointer,
+            opcode,
+            operand,
+            a: self.registers.a,
+            b: self.registers.b,
+            c: self.registers.c,
+            output: self.output.get(outputs).copied(),
+        });
+
+        // Advance instruction pointer (except for JNZ when A != 0)
+        if !(matches!(opcode, Opcode::Jnz) && self.registers.a != 0) {
+            self.pointer += 2;
+        }
+
+        true
+    }
+
+    /// Runs until the instruction pointer moves past the end of the program,
+    /// or reaches an instruction with the invalid combo operand 7.
+    pub fn run(&mut self) {
+        self.run_traced(&mut NoTrace);
+    }
+
+    /// Runs as [`run`](Self::run) does, reporting every instruction.
+    pub fn run_traced(&mut self, tracer: &mut impl Tracer<Step>) {
+        while self.step(tracer) {}
+    }
+
+    fn execute(&mut self, opcode: Opcode, operand: u8) {
+        match opcode {
+            Opcode::Adv => {
+                let combo = self.get_combo_value(operand);
+                self.registers.a = shift_right(self.registers.a, combo);
+            }
+            Opcode::Bxl => {
+                self.registers.b ^= operand as u64;
+            }
+            Opcode::Bst => {
+                let combo = self.get_combo_value(operand);
+                self.registers.b = combo % 8;
+            }
+            Opcode::Jnz => {
+                if self.registers.a != 0 {
+                    self.pointer = operand as usize;
+                }
+            }
+            Opcode::Bxc => {
+                self.registers.b ^= self.registers.c;
+            }
+            Opcode::Out => {
+                let combo = self.get_combo_value(operand);
+                self.output.push(combo % 8);
+            }
+            Opcode::Bdv => {
+                let combo = self.get_combo_value(operand);
+                self.registers.b = shift_right(self.registers.a, combo);
+            }
+            Opcode::Cdv => {
+                let combo = self.get_combo_value(operand);
+                self.registers.c = shift_right(self.registers.a, combo);
+            }
+        }
+    }
+
+    /// The output so far, joined with commas.
+    pub fn print(&self) -> String {
+        self.output
+            .iter()
+            .map(|n| n.to_string())
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+}
+
+/// `value` divided by 2 to the power `bits`, which is 0 once the divisor is
+/// wider than the value.
+fn shift_right(value: u64, bits: u64) -> u64 {
+    u32::try_from(bits)
+        .ok()
+        .and_then(|bits| value.checked_shr(bits))
+        .unwrap_or(0)
+}
+
+/// Parses the three `Register X: n` lines and the `Program: ` line of
+/// comma-separated 3-bit numbers.
+pub fn parse_input(input: &str) -> Result<(Registers, Program), ParseError> {
+    let source = Source::new(input)?;
+    let mut lines = source.lines().filter(|line| !line.trim().is_empty());
+    let mut field = |prefix: &str| {
+        let line = lines
+            .next()
+            .ok_or_else(|| source.expected(input.trim_end(), format!("{:?}", prefix)))?;
+        source.strip_prefix(line, prefix)
+    };
+
+    let a = source.number(field("Register A: ")?)?;
+    let b = source.number(field("Register B: ")?)?;
+    let c = source.number(field("Register C: ")?)?;
+
+    let mut first = None;
+    let program = check_program(&source, field("Program: ")?, |err| {
+        first.get_or_insert(err);
+    });
+    if let Some(err) = first {
+        return Err(err);
+    }
+
+    Ok((Registers::new(a, b, c), Program::new(program)))
+}
+
+/// Parses the program's values, which pair up as opcode and operand,
+/// reporting every problem to `report`. A combo operand must be 0 to 6. Odd
+/// jump targets are allowed, and [`Computer::run`] halts if one leads to an
+/// instruction it can't run.
+fn check_program(source: &Source, field: &str, mut report: impl FnMut(ParseError)) -> Vec<u8> {
+    let values: Vec<&str> = field.split(',').collect();
+    let mut program = Vec::with_capacity(values.len());
+    for pair in values.chunks(2) {
+        let opcode = parse_instruction(source, pair[0]).map_err(&mut report).ok();
+        let Some(&operand_text) = pair.get(1) else {
+            report(source.expected(field.trim_end(), "an operand after the last opcode"));
+            break;
+        };
+        let operand = parse_instruction(source, operand_text)
+            .map_err(&mut report)
+            .ok();
+
+        if let (Some(opcode), Some(operand)) = (opcode, operand) {
+            let opcode = Opcode::try_from(opcode).expect("3-bit opcode");
+            if opcode.takes_combo() && operand == 7 {
+                report(source.error(
+                    operand_text.trim(),
+                    ParseErrorKind::Expected("a combo operand from 0 to 6".to_string()),
+                ));
+            }
+        }
+        program.extend(opcode.into_iter().chain(operand));
+    }
+    program
+}
+
+fn parse_instruction(source: &Source, value: &str) -> Result<u8, ParseError> {
+    match source.number(value)? {
+        value @ 0..=7 => Ok(value),
+        _ => Err(source.error(
+            value.trim(),
+            ParseErrorKind::Expected("a 3-bit number".to_string()),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        aoc_common::check_examples::<Day17>(concat!(env!("CARGO_MANIFEST_DIR"), "/examples"));
+    }
+
+    const INPUT: &str = "\
+Register A: 729
+Register B: 0
+Register C: 0
+
+Program: 0,1,5,4,3,0";
+
+    // New helper functions
+    fn comp(a: u64, b: u64, c: u64, program: Vec<u8>) -> Computer {
+        Computer::new(Registers::new(a, b, c), Program::new(program))
+    }
+
+    fn assert_registers(computer: &Computer, a: u64, b: u64, c: u64) {
+        assert_eq!(
+            (
+                computer.registers.a,
+                computer.registers.b,
+                computer.registers.c
+            ),
+            (a, b, c),
+            "Expected registers (a:{}, b:{}, c:{}), got (a:{}, b:{}, c:{})",
+            a,
+            b,
+            c,
+            computer.registers.a,
+            computer.registers.b,
+            computer.registers.c
+        );
+    }
+
+    #[test]
+    fn test_parse_input() {
+        let (registers, program) = parse_input(INPUT).unwrap();
+        assert_eq!(registers.a, 729);
+        assert_eq!(registers.b, 0);
+        assert_eq!(registers.c, 0);
+        assert_eq!(program.instructions, vec![0, 1, 5, 4, 3, 0]);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = parse_input("Register A: 729\nRegister C: 0\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+
+        let err = parse_input("Register A: 7x9\n").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 13));
+
+        let err =
+            parse_input("Register A: 729\nRegister B: 0\nRegister C: 0\n\nProgram: 0,1,8,4\n")
+                .unwrap_err();
+        assert_eq!((err.line, err.column), (5, 14));
+        assert_eq!(err.kind, ParseErrorKind::Expected("a 3-bit number".into()));
+    }
+
+    #[test]
+    fn test_lint() {
+        let problems = |input: &str| -> Vec<(usize, usize)> {
+            Day17::lint(input)
+                .iter()
+                .map(|err| (err.line, err.column))
+                .collect()
+        };
+        // The missing line doesn't hide the bad opcode after it
+        assert_eq!(
+            problems("Register A: 1\nRegister C: 0\n\nProgram: 9,1,2,7,0\n"),
+            [(2, 1), (4, 10), (4, 16), (4, 19)]
+        );
+        assert_eq!(
+            problems("Register A: 1\nRegister B: x\nRegister X: 0\nProgram: 2,7\n"),
+            [(2, 13), (3, 1), (4, 12)]
+        );
+        assert_eq!(
+            problems("Register A: 1\nRegister B: 0\n"),
+            [(2, 14), (2, 14)]
+        );
+        assert!(problems(INPUT).is_empty());
+    }
+
+    #[test]
+    fn test_programs_that_cannot_run() {
+        let parse = |program: &str| {
+            let input = format!(
+                "Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: {}\n",
+                program
+            );
+            parse_input(&input)
+                .map(|_| ())
+                .map_err(|err| (err.column, err.kind))
+        };
+        let expected = |what: &str| ParseErrorKind::Expected(what.to_string());
+        assert_eq!(
+            parse("0,1,2"),
+            Err((15, expected("an operand after the last opcode")))
+        );
+        assert_eq!(
+            parse("2,7"),
+            Err((12, expected("a combo operand from 0 to 6")))
+        );
+        assert_eq!(parse("1,7,4,7,3,1"), Ok(()));
+        assert_eq!(parse("1,7,4,7,3,2"), Ok(()));
+    }
+
+    #[test]
+    fn test_odd_jump_target() {
+        // Jumps to 3, running bxl's operand 5 as out 4
+        let mut computer = comp(1, 0, 0, vec![3, 3, 1, 5, 4, 5]);
+        computer.run();
+        assert_eq!(computer.print(), "1");
+
+        // Jumps to 5, where 2,7 would be bst with combo operand 7
+        let mut computer = comp(1, 0, 0, vec![5, 4, 3, 5, 0, 2, 7, 0]);
+        computer.run();
+        assert_eq!(computer.print(), "1");
+    }
+
+    #[test]
+    fn test_adv() {
+        let mut computer = comp(100, 0, 0, vec![0, 1]);
+        computer.run();
+        assert_registers(&computer, 50, 0, 0); // 100 / 2^1 = 50
+
+        let mut computer = comp(100, 0, 0, vec![0, 2]);
+        computer.run();
+        assert_registers(&computer, 25, 0, 0); // 100 / 2^2 = 25
+
+        let mut computer = comp(100, 0, 0, vec![0, 3]);
+        computer.run();
+        assert_registers(&computer, 12, 0, 0); // 100 / 2^3 = 12
+
+        let mut computer = comp(100, 0, 4, vec![0, 6]);
+        computer.run();
+        assert_registers(&computer, 6, 0, 4); // 100 / 2^4 = 6
+    }
+
+    #[test]
+    fn test_bxl() {
+        let mut computer = comp(0, 5, 0, vec![1, 3]);
+        computer.run();
+        assert_registers(&computer, 0, 6, 0); // 0101 ^ 0011 = 0110 (6)
+
+        let mut computer = comp(0, 0, 0, vec![1, 3]);
+        computer.run();
+        assert_registers(&computer, 0, 3, 0); // 0000 ^ 0011 = 0011 (3)
+
+        let mut computer = comp(0, 29, 0, vec![1, 7]);
+        computer.run();
+        assert_registers(&computer, 0, 26, 0); // 11101 ^ 00111 = 11
+    }
+
+    #[test]
+    fn test_bst() {
+        let mut computer = comp(0, 0, 0, vec![2, 2]);
+        computer.run();
+        assert_registers(&computer, 0, 2, 0); // 2 % 8 = 2
+
+        let mut computer = comp(10, 0, 0, vec![2, 4]);
+        computer.run();
+        assert_registers(&computer, 10, 2, 0); // 10 % 8 = 2
+
+        let mut computer = comp(0, 0, 27, vec![2, 6]);
+        computer.run();
+        assert_registers(&computer, 0, 3, 27); // 27 % 8 = 3
+
+        let mut computer = comp(0, 0, 9, vec![2, 6]);
+        computer.run();
+        assert_registers(&computer, 0, 1, 9); // 27 % 8 = 3
+    }
+
+    #[test]
+    fn test_jnz() {
+        let mut computer = comp(0, 0, 0, vec![3, 4, 2, 2, 1, 3]);
+        computer.run();
+        // JNZ: noop; BST: set B to 2; BXL: set B to 2 ^ 3 = 1
+        assert_registers(&computer, 0, 1, 0);
+
+        let mut computer = comp(2, 0, 0, vec![3, 4, 2, 2, 1, 3]);
+        computer.run();
+        // JNZ: jump to BXL(4); BXL: set B to 0 ^ 3 = 3
+        assert_registers(&computer, 2, 3, 0);
+
+        let mut computer = comp(2, 0, 0, vec![3, 2, 2, 2, 1, 3]);
+        computer.run();
+        // JNZ: jump to BST(2); BST: set B to 2; BXL: set B to 2 ^ 3 = 1
+        assert_registers(&computer, 2, 1, 0);
+    }
+
+    #[test]
+    fn test_bxc() {
+        let mut computer = comp(0, 0, 0, vec![4, 2]);
+        computer.run();
+        assert_registers(&computer, 0, 0, 0); // 0000 ^ 0000 = 0000 (0)
+
+        let mut computer = comp(0, 8, 0, vec![4, 2]);
+        computer.run();
+        assert_registers(&computer, 0, 8, 0); // 1000 ^ 0000 = 1000 (8)
+
+        let mut computer = comp(0, 0, 8, vec![4, 2]);
+        computer.run();
+        assert_registers(&computer, 0, 8, 8); // 0000 ^ 1000 = 1000 (8)
+
+        let mut computer = comp(0, 8, 8, vec![4, 2]);
+        computer.run();
+        assert_registers(&computer, 0, 0, 8); // 1000 ^ 1000 = 0000 (0)
+
+        let mut computer = comp(0, 2024, 43690, vec![4, 0]);
+        computer.run();
+        assert_registers(&computer, 0, 44354, 43690);
+    }
+
+    #[test]
+    fn test_out() {
+        let mut computer = comp(0, 0, 0, vec![5, 2]);
+        computer.run();
+        assert_eq!(computer.output, vec![2]);
+
+        let mut computer = comp(13, 0, 0, vec![5, 2, 5, 4]);
+        computer.run();
+        assert_eq!(computer.output, vec![2, 5]); // 2 % 8 = 2; 13 % 8 = 5
+
+        let mut computer = comp(10, 0, 0, vec![5, 0, 5, 1, 5, 4]);
+        computer.run();
+        assert_eq!(computer.output, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_bdv() {
+        let mut computer = comp(100, 0, 0, vec![6, 2]);
+        computer.run();
+        assert_registers(&computer, 100, 25, 0); // 100 / 2^2 = 25
+    }
+
+    #[test]
+    fn test_cdv() {
+        let mut computer = comp(100, 0, 0, vec![7, 2]);
+        computer.run();
+        assert_registers(&computer, 100, 0, 25); // 100 / 2^2 = 25
+    }
+
+    #[test]
+    fn test_combinations() {
+        let mut computer = comp(2024, 0, 0, vec![0, 1, 5, 4]);
+        computer.run();
+        assert_eq!(computer.registers.a, 1012);
+        assert_eq!(computer.output, vec![4]);
+
+        let mut computer = comp(2024, 0, 0, vec![0, 1, 5, 4, 3, 0]);
+        computer.run();
+        assert_eq!(computer.output, vec![4, 2, 5, 6, 7, 7, 7, 7, 3, 1, 0]);
+        assert_eq!(computer.registers.a, 0);
+
+        let (registers, program) = parse_input(INPUT).unwrap();
+        let mut computer = Computer::new(registers, program);
+        computer.run();
+        assert_eq!(computer.print(), "4,6,3,5,6,3,5,2,1,0");
+    }
+
+    #[test]
+    fn test_trace() {
+        let mut computer = comp(2024, 0, 0, vec![0, 1, 5, 4, 3, 0]);
+        let mut steps = Vec::new();
+        computer.run_traced(&mut steps);
+
+        let step = |pointer, opcode, operand, a, output| Step {
+            pointer,
+            opcode,
+            operand,
+            a,
+            b: 0,
+            c: 0,
+            output,
+        };
+        assert_eq!(
+            steps[..4],
+            [
+                step(0, Opcode::Adv, 1, 1012, None),
+                step(2, Opcode::Out, 4, 1012, Some(4)),
+                step(4, Opcode::Jnz, 0, 1012, None),
+                step(0, Opcode::Adv, 1, 506, None),
+            ]
+        );
+        let outputs: Vec<u64> = steps.iter().filter_map(|step| step.output).collect();
+        assert_eq!(outputs, computer.output);
+    }
+}
@@ -0,0 +1,23 @@
+use aoc17::{parse_input, Computer, Program, Registers};
+
+const EXAMPLE: &str = include_str!("../examples/part1.txt");
+
+#[test]
+fn test_example() {
+    let (registers, program) = parse_input(EXAMPLE).unwrap();
+    let mut computer = Computer::new(registers, program);
+    computer.run();
+    assert_eq!(computer.print(), "4,6,3,5,6,3,5,2,1,0");
+}
+
+#[test]
+fn test_program_by_hand() {
+    let mut computer = Computer::new(
+        Registers::new(10, 0, 0),
+        Program::new(vec![5, 0, 5, 1, 5, 4]),
+    );
+    computer.run();
+    assert_eq!(computer.print(), "0,1,2");
+}
+
+aoc_common::parse_properties!(aoc17::Day17);